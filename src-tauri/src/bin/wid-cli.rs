@@ -0,0 +1,683 @@
+//! wid 命令行工具
+//!
+//! 与桌面应用共用同一套解析和搜索模块，便于在脚本和 CI 中使用。
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use wid_lib::modules::exporter::{ExportFormat, ExportTable};
+use wid_lib::modules::id_resolver::IdResolver;
+use wid_lib::modules::log_annotator::LogAnnotator;
+use wid_lib::modules::profiles::ProfileStore;
+use wid_lib::modules::hierarchy::{load_hierarchy, HierarchyNode, ProjectHierarchy};
+use wid_lib::modules::inheritance::{EffectiveValue, InheritanceRule};
+use wid_lib::modules::bank_inclusion::{BankContents, BankObject};
+use wid_lib::modules::event_report::ActionInfo;
+use wid_lib::modules::graph::GraphFormat;
+use wid_lib::modules::media_report::{parse_size, BankMemory, DuplicateMedia};
+use wid_lib::modules::object_details::ObjectReference;
+use wid_lib::modules::{
+    find_dependency_paths, find_object_banks, get_bank_inclusions, get_bus_routing, get_dependency_graph,
+    get_event_report, get_media_report, get_object_details, search_bank_directory, search_wwise_project, validate_wwise_directory,
+};
+
+#[derive(Parser)]
+#[command(name = "wid-cli", version, about = "Wwise ID 查询工具命令行版本")]
+struct Cli {
+    /// Wwise 工程目录（包含 .wproj 文件）
+    #[arg(long, global = true)]
+    project: Option<String>,
+
+    /// Bank 目录（包含 SoundbanksInfo 或单独的 Bank 元数据文件）；省略时根据工程的 .wid.toml 或 .wproj 自动定位
+    #[arg(long, global = true)]
+    banks: Option<String>,
+
+    /// 使用桌面应用中保存的配置；--project / --banks 优先
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 在工程目录和 Bank 目录中搜索 ID
+    Search {
+        /// 要搜索的 ID 字符串，支持 0x 十六进制、有符号 int32、不带花括号或连字符的 GUID
+        #[arg(allow_hyphen_values = true)]
+        id: String,
+
+        /// ID 类型，逗号分隔: GUID, ShortID, MediaID
+        #[arg(long, value_delimiter = ',', default_value = "GUID,ShortID,MediaID")]
+        types: Vec<String>,
+
+        /// 匹配方式: auto（数字 ID 精确匹配，GUID 子串匹配）、exact、prefix、contains、regex
+        #[arg(long = "match", default_value = "auto")]
+        match_mode: String,
+
+        /// 输出文件，省略时写入标准输出
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 输出格式: csv, json, markdown, xlsx；省略时根据输出文件扩展名推断，标准输出默认为 markdown
+        #[arg(long)]
+        format: Option<String>,
+    },
+
+    /// 为日志中的 ID 追加解析出的名称和类型
+    Annotate {
+        /// 日志文件，省略时从标准输入读取
+        input: Option<String>,
+
+        /// 输出文件，省略时写入标准输出
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 日志格式: generic（所有 ID）或 wwise（Wwise 错误消息）
+        #[arg(long, default_value = "generic")]
+        format: String,
+
+        /// 自定义正则表达式，必须包含命名分组 (?P<id>...)，可重复指定
+        #[arg(long = "pattern")]
+        patterns: Vec<String>,
+    },
+
+    /// 显示对象考虑继承后的属性和引用，以及值来自哪个上级对象
+    Details {
+        /// 对象的 GUID
+        guid: String,
+
+        /// 输出文件，省略时写入标准输出
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 输出格式: csv, json, markdown, xlsx；省略时根据输出文件扩展名推断，标准输出默认为 markdown
+        #[arg(long)]
+        format: Option<String>,
+    },
+
+    /// 列出 Event 的动作及其目标、作用范围、延迟和淡入淡出；没有工程目录时使用 SoundbanksInfo
+    Event {
+        /// Event 的 GUID、ShortID 或名称
+        #[arg(allow_hyphen_values = true)]
+        event: String,
+
+        /// 输出文件，省略时写入标准输出
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 输出格式: csv, json, markdown, xlsx；省略时根据输出文件扩展名推断，标准输出默认为 markdown
+        #[arg(long)]
+        format: Option<String>,
+    },
+
+    /// 打印工程层级；指定 GUID 时打印该对象的子树
+    Tree {
+        /// 子树的根对象 GUID，省略时打印所有分类
+        guid: Option<String>,
+
+        /// 最多展开的层数
+        #[arg(long)]
+        depth: Option<usize>,
+    },
+
+    /// 导出总线路由图：对象的有效 OutputBus、辅助发送和总线的上级总线
+    Routing {
+        /// 只保留经过该总线的对象，可以是总线的 GUID、ShortID 或名称
+        #[arg(long)]
+        through: Option<String>,
+
+        /// 输出文件，省略时写入标准输出
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 输出格式: json, dot, mermaid；省略时根据输出文件扩展名推断，标准输出默认为 dot
+        #[arg(long)]
+        format: Option<String>,
+    },
+
+    /// 导出工程的依赖图；同时指定 --from 和 --to 时打印两者之间的依赖路径
+    Deps {
+        /// 路径的起点，可以是 GUID、ShortID 或名称
+        #[arg(long, requires = "to")]
+        from: Option<String>,
+
+        /// 路径的终点，可以是 GUID、ShortID、MediaID 或名称
+        #[arg(long, requires = "from")]
+        to: Option<String>,
+
+        /// 输出文件，省略时写入标准输出
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 输出格式: json, graphml, dot, mermaid；省略时根据输出文件扩展名推断，标准输出默认为 dot
+        #[arg(long)]
+        format: Option<String>,
+    },
+
+    /// 离线计算每个 SoundBank 将包含的 Event、层级结构和媒体；有 Bank 目录时与 SoundbanksInfo 比较
+    Inclusions {
+        /// 只查询该对象将进入哪些 SoundBank，可以是 GUID、ShortID、MediaID 或名称
+        #[arg(long)]
+        object: Option<String>,
+
+        /// 输出文件，省略时写入标准输出
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 输出格式: csv, json, markdown, xlsx；省略时根据输出文件扩展名推断，标准输出默认为 markdown
+        #[arg(long)]
+        format: Option<String>,
+    },
+
+    /// 报告写入多个 SoundBank 的媒体及浪费的字节数；--banks 可以是平台目录或包含多个平台的 GeneratedSoundBanks 目录
+    Media {
+        /// 每个 SoundBank 的媒体内存预算，如 8MB、512KB
+        #[arg(long, value_parser = parse_size)]
+        budget: Option<u64>,
+
+        /// 输出每个 SoundBank 的内存统计，而不是重复媒体
+        #[arg(long)]
+        memory: bool,
+
+        /// 输出文件，省略时写入标准输出
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 输出格式: csv, json, markdown, xlsx；省略时根据输出文件扩展名推断，标准输出默认为 markdown
+        #[arg(long)]
+        format: Option<String>,
+    },
+}
+
+/// details 子命令输出的一行
+#[derive(Serialize)]
+struct EffectiveRow {
+    name: String,
+    value: String,
+    rule: InheritanceRule,
+    source: String,
+    source_path: String,
+    inherited: bool,
+    is_default: bool,
+    override_flag: String,
+    contributions: Vec<String>,
+}
+
+impl From<&EffectiveValue> for EffectiveRow {
+    fn from(value: &EffectiveValue) -> Self {
+        EffectiveRow {
+            name: value.name.clone(),
+            value: value.value.clone(),
+            rule: value.rule,
+            source: value.source.name.clone(),
+            source_path: value.source.path.clone(),
+            inherited: value.inherited,
+            is_default: value.is_default,
+            override_flag: value.override_flag.clone(),
+            contributions: value
+                .contributions
+                .iter()
+                .map(|c| format!("{}: {}", c.source.name, c.value))
+                .collect(),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("错误: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(mut cli: Cli) -> Result<(), String> {
+    if let Some(name) = &cli.profile {
+        let store = ProfileStore::load_default()?;
+        let profile = store.get(name)?;
+        let non_empty = |path: &str| Some(path.to_string()).filter(|p| !p.is_empty());
+        cli.project = cli.project.or_else(|| non_empty(&profile.wwise.proj_path));
+        cli.banks = cli.banks.or_else(|| non_empty(&profile.bank.dir_path));
+    }
+
+    if let (Some(project), None) = (&cli.project, &cli.banks) {
+        let info = validate_wwise_directory(project.clone())?;
+        if let Some(team) = &info.team_config {
+            eprintln!("使用团队配置: {}", team.path);
+            if !team.config.lint.is_empty() {
+                let rules: Vec<String> = team
+                    .config
+                    .lint
+                    .iter()
+                    .map(|(rule, level)| format!("{}={}", rule, level.as_str()))
+                    .collect();
+                eprintln!("检查规则: {}", rules.join(", "));
+            }
+        }
+        if let Some(banks) = info.bank_directory {
+            eprintln!("使用 Bank 目录: {}", banks);
+            cli.banks = Some(banks);
+        }
+    }
+
+    match cli.command {
+        Command::Search { id, types, match_mode, output, format } => {
+            if cli.project.is_none() && cli.banks.is_none() {
+                return Err("请通过 --project、--banks 或 --profile 指定搜索目录".to_string());
+            }
+
+            let mut responses = Vec::new();
+            if let Some(project) = &cli.project {
+                responses.push(search_wwise_project(project.clone(), id.clone(), types.clone(), Some(match_mode.clone()))?);
+            }
+            if let Some(banks) = &cli.banks {
+                responses.push(search_bank_directory(banks.clone(), id.clone(), types.clone(), Some(match_mode.clone()))?);
+            }
+
+            if let Some(response) = responses.first() {
+                let descriptions: Vec<&str> = response
+                    .interpretations
+                    .iter()
+                    .map(|i| i.description.as_str())
+                    .collect();
+                eprintln!("输入解析为: {}", descriptions.join("; "));
+            }
+            for scan in responses.iter().filter_map(|r| r.scan.as_ref()) {
+                eprintln!("已扫描 {} 个文件，其中 {} 个 .wwu 文件", scan.files_visited, scan.files_matched);
+            }
+            let results: Vec<_> = responses.into_iter().flat_map(|r| r.results).collect();
+
+            let table = ExportTable::from_serializable(&results)?;
+            write_table(&table, output.as_deref(), format.as_deref())?;
+
+            eprintln!("共找到 {} 条结果", results.len());
+            Ok(())
+        }
+        Command::Annotate { input, output, format, patterns } => {
+            let resolver = IdResolver::load(cli.project.as_deref(), cli.banks.as_deref())?;
+            let annotator = LogAnnotator::new(resolver, &format, &patterns)?;
+
+            let text = read_input(input.as_deref())?;
+            let annotated = annotator.annotate(&text);
+            write_output(output.as_deref(), &annotated.text)?;
+
+            eprintln!(
+                "已处理 {} 行，注释 {} 行，共 {} 处",
+                annotated.stats.lines, annotated.stats.annotated_lines, annotated.stats.annotations
+            );
+            Ok(())
+        }
+        Command::Details { guid, output, format } => {
+            let project = cli.project.ok_or("请通过 --project 或 --profile 指定工程目录")?;
+            let details = get_object_details(project, guid)?;
+            eprintln!("{} ({}) {}", details.object.name, details.object.object_type, details.object.path);
+            eprintln!("定义于 {}", details.work_unit_file);
+
+            let rows: Vec<EffectiveRow> = details.effective_values.iter().map(EffectiveRow::from).collect();
+            let table = ExportTable::from_serializable(&rows)?;
+            write_table(&table, output.as_deref(), format.as_deref())
+        }
+        Command::Event { event, output, format } => {
+            if cli.project.is_none() && cli.banks.is_none() {
+                return Err("请通过 --project、--banks 或 --profile 指定工程目录或 Bank 目录".to_string());
+            }
+            let report = get_event_report(cli.project, cli.banks, event)?;
+            let source = if report.source == "project" { "工程文件" } else { "SoundbanksInfo" };
+            eprintln!("{} ({}) {}，动作来自{}", report.event.name, report.event.short_id, report.event.path, source);
+            if !report.banks.is_empty() {
+                let banks: Vec<&str> = report.banks.iter().map(|b| b.name.as_str()).collect();
+                eprintln!("包含该 Event 的 SoundBank: {}", banks.join(", "));
+            }
+            for media in &report.media {
+                eprintln!("引用的媒体: {} {}", media.id, media.name);
+            }
+
+            let rows: Vec<ActionRow> = report.actions.iter().map(ActionRow::from).collect();
+            let table = ExportTable::from_serializable(&rows)?;
+            write_table(&table, output.as_deref(), format.as_deref())
+        }
+        Command::Tree { guid, depth } => {
+            let project = cli.project.ok_or("请通过 --project 或 --profile 指定工程目录")?;
+            let hierarchy = load_hierarchy(Path::new(&project))?;
+            let depth = depth.unwrap_or(usize::MAX);
+
+            let mut text = String::new();
+            match guid {
+                Some(guid) => {
+                    let node = hierarchy.node(&guid).ok_or_else(|| format!("工程中未找到对象 {}", guid))?;
+                    write_subtree(&hierarchy, &node, 0, depth, &mut text);
+                }
+                None => {
+                    for category in hierarchy.roots() {
+                        text.push_str(&category.name);
+                        text.push('\n');
+                        for node in &category.children {
+                            write_subtree(&hierarchy, node, 1, depth, &mut text);
+                        }
+                    }
+                }
+            }
+            write_output(None, &text)
+        }
+        Command::Routing { through, output, format } => {
+            let project = cli.project.ok_or("请通过 --project 或 --profile 指定工程目录")?;
+            let graph = get_bus_routing(project, through)?;
+            eprintln!("共 {} 个对象，{} 条路由", graph.nodes.len(), graph.edges.len());
+            match output {
+                Some(path) => graph.write(format.as_deref(), Path::new(&path), "routing"),
+                None => {
+                    let format = GraphFormat::parse(format.as_deref().unwrap_or("dot"))?;
+                    write_output(None, &graph.render(format, "routing")?)
+                }
+            }
+        }
+        Command::Deps { from, to, output, format } => {
+            let project = cli.project.ok_or("请通过 --project 或 --profile 指定工程目录")?;
+            if let (Some(from), Some(to)) = (from, to) {
+                let paths = find_dependency_paths(project, from.clone(), to.clone())?;
+                if paths.is_empty() {
+                    eprintln!("{} 不依赖 {}", from, to);
+                }
+                let mut text = String::new();
+                for path in &paths {
+                    for (i, node) in path.nodes.iter().enumerate() {
+                        if i > 0 {
+                            text.push_str(&format!(" -[{}]-> ", path.edges[i - 1].kind));
+                        }
+                        // 动作等对象没有名称，显示 GUID
+                        let name = if node.name.is_empty() { &node.id } else { &node.name };
+                        text.push_str(&format!("{} ({})", name, node.object_type));
+                    }
+                    text.push('\n');
+                }
+                return write_output(output.as_deref(), &text);
+            }
+
+            let graph = get_dependency_graph(project)?;
+            eprintln!("共 {} 个对象，{} 条依赖", graph.nodes.len(), graph.edges.len());
+            match output {
+                Some(path) => graph.write(format.as_deref(), Path::new(&path), "dependencies"),
+                None => {
+                    let format = GraphFormat::parse(format.as_deref().unwrap_or("dot"))?;
+                    write_output(None, &graph.render(format, "dependencies")?)
+                }
+            }
+        }
+        Command::Inclusions { object, output, format } => {
+            let project = cli.project.ok_or("请通过 --project 或 --profile 指定工程目录")?;
+            if let Some(object) = object {
+                let banks = find_object_banks(project, object.clone())?;
+                if banks.is_empty() {
+                    eprintln!("{} 不会进入任何 SoundBank", object);
+                }
+                let table = ExportTable::from_serializable(&banks)?;
+                return write_table(&table, output.as_deref(), format.as_deref());
+            }
+
+            let report = get_bank_inclusions(project, cli.banks)?;
+            for bank in &report.banks {
+                eprintln!(
+                    "{}: {} 个 Event，{} 个层级结构，{} 个媒体",
+                    bank.name,
+                    bank.events.len(),
+                    bank.structures.len(),
+                    bank.media.len()
+                );
+                for name in &bank.unresolved {
+                    eprintln!("  工程中未找到包含项 {}", name);
+                }
+            }
+            for difference in &report.differences {
+                if !difference.generated {
+                    eprintln!("SoundbanksInfo 中没有 {}", difference.bank);
+                    continue;
+                }
+                let lists = [
+                    ("缺少 Event", &difference.missing_events),
+                    ("多出 Event", &difference.extra_events),
+                    ("缺少媒体", &difference.missing_media),
+                    ("多出媒体", &difference.extra_media),
+                ];
+                for (label, items) in lists.iter().filter(|(_, items)| !items.is_empty()) {
+                    eprintln!("{} 与 SoundbanksInfo 不一致，{}: {}", difference.bank, label, items.join(", "));
+                }
+            }
+
+            let rows: Vec<InclusionRow> = report.banks.iter().flat_map(InclusionRow::from_bank).collect();
+            let table = ExportTable::from_serializable(&rows)?;
+            write_table(&table, output.as_deref(), format.as_deref())
+        }
+        Command::Media { budget, memory, output, format } => {
+            let banks = cli.banks.ok_or("请通过 --banks、--project 或 --profile 指定 Bank 目录")?;
+            let report = get_media_report(banks, budget)?;
+            for platform in &report.platforms {
+                eprintln!(
+                    "{}: {} 个 SoundBank，{} 个重复媒体，浪费 {} 字节",
+                    platform.platform,
+                    platform.banks.len(),
+                    platform.duplicates.len(),
+                    platform.wasted_bytes
+                );
+                for bank in platform.banks.iter().filter(|b| b.over_budget) {
+                    eprintln!("  {} 超出预算: {} 字节", bank.name, bank.memory_bytes);
+                }
+                for bank in platform.banks.iter().filter(|b| b.unknown_sizes > 0) {
+                    eprintln!("  {} 中有 {} 个媒体无法确定大小", bank.name, bank.unknown_sizes);
+                }
+            }
+
+            let table = if memory {
+                let rows: Vec<BankMemoryRow> = report
+                    .platforms
+                    .iter()
+                    .flat_map(|p| p.banks.iter().map(|b| BankMemoryRow::new(&p.platform, b)))
+                    .collect();
+                ExportTable::from_serializable(&rows)?
+            } else {
+                let rows: Vec<DuplicateRow> = report
+                    .platforms
+                    .iter()
+                    .flat_map(|p| p.duplicates.iter().map(|d| DuplicateRow::new(&p.platform, d)))
+                    .collect();
+                ExportTable::from_serializable(&rows)?
+            };
+            write_table(&table, output.as_deref(), format.as_deref())
+        }
+    }
+}
+
+/// event 子命令输出的一行
+#[derive(Serialize)]
+struct ActionRow {
+    action_type: String,
+    target: String,
+    target_type: String,
+    target_path: String,
+    scope: Option<String>,
+    delay: Option<f64>,
+    fade_time: Option<f64>,
+    fade_curve: Option<String>,
+    properties: Vec<String>,
+}
+
+impl From<&ActionInfo> for ActionRow {
+    fn from(action: &ActionInfo) -> Self {
+        let target = action.target.as_ref();
+        let text = |f: fn(&ObjectReference) -> &String| target.map(f).cloned().unwrap_or_default();
+        ActionRow {
+            action_type: action.action_type.clone(),
+            target: text(|t| &t.target_name),
+            target_type: text(|t| &t.target_type),
+            target_path: text(|t| &t.target_path),
+            scope: action.scope.clone(),
+            delay: action.delay,
+            fade_time: action.fade_time,
+            fade_curve: action.fade_curve.clone(),
+            properties: action.properties.iter().map(|p| format!("{}={}", p.name, p.value)).collect(),
+        }
+    }
+}
+
+/// inclusions 子命令输出的一行
+#[derive(Serialize)]
+struct InclusionRow {
+    bank: String,
+    kind: &'static str,
+    name: String,
+    object_type: String,
+    /// GUID 或 MediaID
+    id: String,
+    language: String,
+    path: String,
+    via: String,
+}
+
+impl InclusionRow {
+    fn from_bank(bank: &BankContents) -> Vec<Self> {
+        let object = |kind: &'static str, o: &BankObject| InclusionRow {
+            bank: bank.name.clone(),
+            kind,
+            name: o.name.clone(),
+            object_type: o.object_type.clone(),
+            id: o.guid.clone(),
+            language: String::new(),
+            path: o.path.clone(),
+            via: o.via.clone(),
+        };
+        let mut rows: Vec<InclusionRow> = bank.events.iter().map(|o| object("event", o)).collect();
+        rows.extend(bank.structures.iter().map(|o| object("structure", o)));
+        rows.extend(bank.media.iter().map(|m| InclusionRow {
+            bank: bank.name.clone(),
+            kind: "media",
+            name: m.name.clone(),
+            object_type: "Media".to_string(),
+            id: m.id.clone(),
+            language: m.language.clone(),
+            path: m.source.clone(),
+            via: m.via.clone(),
+        }));
+        rows
+    }
+}
+
+/// media 子命令输出的一行重复媒体
+#[derive(Serialize)]
+struct DuplicateRow {
+    platform: String,
+    id: String,
+    name: String,
+    language: String,
+    banks: Vec<String>,
+    total_bytes: u64,
+    wasted_bytes: u64,
+}
+
+impl DuplicateRow {
+    fn new(platform: &str, media: &DuplicateMedia) -> Self {
+        DuplicateRow {
+            platform: platform.to_string(),
+            id: media.id.clone(),
+            name: media.name.clone(),
+            language: media.language.clone(),
+            banks: media.banks.clone(),
+            total_bytes: media.total_bytes,
+            wasted_bytes: media.wasted_bytes,
+        }
+    }
+}
+
+/// media --memory 输出的一行 SoundBank 内存
+#[derive(Serialize)]
+struct BankMemoryRow {
+    platform: String,
+    bank: String,
+    language: String,
+    path: String,
+    memory_bytes: u64,
+    prefetch_bytes: u64,
+    media_count: usize,
+    streamed_count: usize,
+    unknown_sizes: usize,
+    over_budget: bool,
+}
+
+impl BankMemoryRow {
+    fn new(platform: &str, bank: &BankMemory) -> Self {
+        BankMemoryRow {
+            platform: platform.to_string(),
+            bank: bank.name.clone(),
+            language: bank.language.clone(),
+            path: bank.path.clone(),
+            memory_bytes: bank.memory_bytes,
+            prefetch_bytes: bank.prefetch_bytes,
+            media_count: bank.media_count,
+            streamed_count: bank.streamed_count,
+            unknown_sizes: bank.unknown_sizes,
+            over_budget: bank.over_budget,
+        }
+    }
+}
+
+/// 按缩进写入对象及其子对象，超过 `depth` 层的子对象只显示数量
+fn write_subtree(hierarchy: &ProjectHierarchy, node: &HierarchyNode, level: usize, depth: usize, text: &mut String) {
+    text.push_str(&format!("{}{} ({}) {}", "  ".repeat(level), node.name, node.object_type, node.guid));
+    if node.child_count > 0 && level >= depth {
+        text.push_str(&format!(" [+{}]", node.child_count));
+    }
+    text.push('\n');
+    if level < depth {
+        for child in hierarchy.children(&node.guid).unwrap_or_default() {
+            write_subtree(hierarchy, &child, level + 1, depth, text);
+        }
+    }
+}
+
+/// 使用导出模块写入结果表格
+fn write_table(table: &ExportTable, output: Option<&str>, format: Option<&str>) -> Result<(), String> {
+    match output {
+        Some(path) => {
+            let path = Path::new(path);
+            let format = match format {
+                Some(name) => ExportFormat::parse(name)?,
+                None => ExportFormat::from_path(path)?,
+            };
+            table.write(format, path)
+        }
+        None => {
+            let format = ExportFormat::parse(format.unwrap_or("markdown"))?;
+            write_output(None, &table.render(format)?)
+        }
+    }
+}
+
+/// 读取输入文件或标准输入，非 UTF-8 字节按有损方式处理
+fn read_input(path: Option<&str>) -> Result<String, String> {
+    let mut bytes = Vec::new();
+    match path {
+        Some(path) => bytes = fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?,
+        None => {
+            io::stdin()
+                .read_to_end(&mut bytes)
+                .map_err(|e| format!("读取标准输入失败: {}", e))?;
+        }
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// 写入输出文件或标准输出
+fn write_output(path: Option<&str>, contents: &str) -> Result<(), String> {
+    match path {
+        Some(path) => fs::write(path, contents).map_err(|e| format!("写入文件失败: {}", e)),
+        None => io::stdout()
+            .write_all(contents.as_bytes())
+            .map_err(|e| format!("写入标准输出失败: {}", e)),
+    }
+}
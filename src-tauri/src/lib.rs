@@ -1,10 +1,42 @@
-mod modules;
+pub mod modules;
 
 use modules::{
     validate_wwise_directory,
     validate_bank_directory,
     search_wwise_project,
     search_bank_directory,
+    search_ids_header,
+    generate_id_constants,
+    interpret_id_input,
+    resolve_ids_in_text,
+    annotate_log,
+    annotate_log_file,
+    export_results,
+    search_waapi,
+    test_waapi_connection,
+    show_in_wwise,
+    start_live_sync,
+    stop_live_sync,
+    get_object_details,
+    get_roots,
+    get_children,
+    get_parent,
+    get_event_report,
+    get_bus_routing,
+    export_bus_routing,
+    get_dependency_graph,
+    export_dependency_graph,
+    find_dependency_paths,
+    get_bank_inclusions,
+    find_object_banks,
+    get_media_report,
+    list_profiles,
+    save_profile,
+    load_profile,
+    delete_profile,
+    import_profiles,
+    import_legacy_profiles,
+    export_profiles,
 };
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -16,7 +48,39 @@ pub fn run() {
             validate_wwise_directory, 
             validate_bank_directory,
             search_wwise_project,
-            search_bank_directory
+            search_bank_directory,
+            search_ids_header,
+            generate_id_constants,
+            interpret_id_input,
+            resolve_ids_in_text,
+            annotate_log,
+            annotate_log_file,
+            export_results,
+            search_waapi,
+            test_waapi_connection,
+            show_in_wwise,
+            start_live_sync,
+            stop_live_sync,
+            get_object_details,
+            get_roots,
+            get_children,
+            get_parent,
+            get_event_report,
+            get_bus_routing,
+            export_bus_routing,
+            get_dependency_graph,
+            export_dependency_graph,
+            find_dependency_paths,
+            get_bank_inclusions,
+            find_object_banks,
+            get_media_report,
+            list_profiles,
+            save_profile,
+            load_profile,
+            delete_profile,
+            import_profiles,
+            import_legacy_profiles,
+            export_profiles
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,617 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use rayon::prelude::*;
+use roxmltree::{Document, Node};
+use serde::Serialize;
+use serde_json::Value;
+use crate::modules::bank_metadata::discover_bank_metadata;
+use crate::modules::dependencies::{read_inclusions, Inclusion, FILTER_EVENTS, FILTER_MEDIA, FILTER_STRUCTURES};
+use crate::modules::event_report::{json_array, json_text};
+use crate::modules::hierarchy::{HierarchyNode, ProjectHierarchy};
+use crate::modules::id_resolver::canonical_guid;
+use crate::modules::index::{index_project_documents, is_definition_node, load_project_documents, ProjectDocuments};
+use crate::modules::object_details::{child, elements, read_references, ObjectLookup};
+
+/// 层级结构所在的分类
+const STRUCTURE_CATEGORIES: &[&str] = &["AudioObjects", "InteractiveMusic"];
+
+/// 只用于组织对象、本身不写入 SoundBank 的类型
+const CONTAINER_TYPES: &[&str] = &["WorkUnit", "Folder", "PhysicalFolder"];
+
+const SOURCE_TYPES: &[&str] = &["AudioFileSource", "SourcePlugin", "ExternalSource"];
+
+/// 不区分语言的媒体
+const SFX_LANGUAGE: &str = "SFX";
+
+/// SoundBank 中的 Event 或层级结构
+#[derive(Debug, Clone, Serialize)]
+pub struct BankObject {
+    pub name: String,
+    pub object_type: String,
+    pub guid: String,
+    pub short_id: String,
+    pub path: String,
+    /// 使该对象进入 SoundBank 的包含项名称
+    pub via: String,
+}
+
+/// SoundBank 中的媒体
+#[derive(Debug, Clone, Serialize)]
+pub struct BankMedia {
+    pub id: String,
+    /// 音频文件名
+    pub name: String,
+    pub language: String,
+    /// 引用该媒体的源名称
+    pub source: String,
+    pub via: String,
+}
+
+/// 根据工程中的包含项计算出的 SoundBank 内容
+#[derive(Debug, Clone, Serialize)]
+pub struct BankContents {
+    pub name: String,
+    pub guid: String,
+    pub short_id: String,
+    pub path: String,
+    pub events: Vec<BankObject>,
+    pub structures: Vec<BankObject>,
+    pub media: Vec<BankMedia>,
+    /// 工程中找不到的包含项
+    pub unresolved: Vec<String>,
+}
+
+/// 对象所在的 SoundBank
+#[derive(Debug, Clone, Serialize)]
+pub struct BankMembership {
+    pub bank: String,
+    pub bank_guid: String,
+    /// "event"、"structure" 或 "media"
+    pub kind: String,
+    pub name: String,
+    pub via: String,
+}
+
+/// 计算结果与 SoundbanksInfo 的差异
+#[derive(Debug, Clone, Serialize)]
+pub struct BankDifference {
+    pub bank: String,
+    /// SoundbanksInfo 中是否有该 SoundBank
+    pub generated: bool,
+    /// 应包含但生成的 SoundBank 中没有的 Event
+    pub missing_events: Vec<String>,
+    /// 生成的 SoundBank 中有但按工程不应包含的 Event
+    pub extra_events: Vec<String>,
+    /// 应包含但生成的 SoundBank 中没有的 MediaID
+    pub missing_media: Vec<String>,
+    /// 生成的 SoundBank 中有但按工程不应包含的 MediaID
+    pub extra_media: Vec<String>,
+}
+
+/// SoundBank 包含报告
+#[derive(Debug, Clone, Serialize)]
+pub struct BankInclusionReport {
+    pub banks: Vec<BankContents>,
+    /// 与 SoundbanksInfo 不一致的 SoundBank，未提供 Bank 目录时为空
+    pub differences: Vec<BankDifference>,
+}
+
+/// SoundbanksInfo 中记录的 SoundBank，本地化 SoundBank 的各语言合并为一项
+#[derive(Debug, Clone, Default)]
+pub struct GeneratedBank {
+    pub name: String,
+    pub guid: String,
+    pub languages: Vec<String>,
+    /// Event 的 GUID 和名称
+    pub events: Vec<(String, String)>,
+    /// 媒体的 MediaID 和语言，包括流播放的媒体
+    pub media: Vec<(String, String)>,
+}
+
+/// 列出 SoundBank 媒体的列表，新版本只有 `Media`，旧版本按写入方式分为三个列表
+///
+/// 工程中的源不区分是否流播放，因此流播放的媒体也要读取。
+const MEDIA_LISTS: &[&str] = &["Media", "IncludedMemoryFiles", "IncludedPrefetchFiles", "ReferencedStreamedFiles"];
+
+/// 源引用的媒体
+struct Source {
+    name: String,
+    media_id: String,
+    audio_file: String,
+    language: String,
+    /// 在上级对象的 ActiveSourceList 中
+    active: bool,
+}
+
+struct BankDefinition {
+    guid: String,
+    inclusions: Vec<Inclusion>,
+}
+
+/// 单个 .wwu 文件中与 SoundBank 包含有关的对象
+#[derive(Default)]
+struct FileObjects {
+    structures: Vec<String>,
+    /// Event 和其动作的目标
+    event_targets: Vec<(String, Vec<String>)>,
+    /// 对象和其下的源
+    sources: Vec<(String, Vec<Source>)>,
+    banks: Vec<BankDefinition>,
+}
+
+/// 计算 SoundBank 内容所需的工程信息
+struct ProjectObjects<'a> {
+    hierarchy: &'a ProjectHierarchy,
+    structures: HashSet<String>,
+    event_targets: HashMap<String, Vec<String>>,
+    sources: HashMap<String, Vec<Source>>,
+}
+
+/// 根据 `SoundBanks` 中的包含项离线计算每个 SoundBank 的内容
+///
+/// 按 Wwise 生成 SoundBank 的主要规则近似计算：
+/// - 包含工作单元或文件夹等于包含其下的所有对象
+/// - events：包含的 Event 本身
+/// - structures：Event 动作的目标及其子对象，以及目标的上级对象（属性继承需要）
+/// - media：目标及其子对象下的源引用的媒体；同一语言有激活的源时只取激活的源
+///
+/// 不跟随 PostEvent 动作指向的其他 Event，也不计算 Switch Group、State Group、
+/// Game Parameter 等依赖，这些对象的差异需要以 SoundbanksInfo 为准。
+pub fn resolve_bank_contents(documents: &ProjectDocuments) -> Vec<BankContents> {
+    let objects = index_project_documents(documents);
+    let lookup = ObjectLookup::new(&objects);
+    let hierarchy = ProjectHierarchy::build(documents);
+    let files: Vec<FileObjects> =
+        documents.files.par_iter().map(|file| read_file(&file.contents, &lookup)).collect();
+
+    let mut project = ProjectObjects {
+        hierarchy: &hierarchy,
+        structures: HashSet::new(),
+        event_targets: HashMap::new(),
+        sources: HashMap::new(),
+    };
+    let mut definitions = Vec::new();
+    for file in files {
+        project.structures.extend(file.structures);
+        project.event_targets.extend(file.event_targets);
+        project.sources.extend(file.sources);
+        definitions.extend(file.banks);
+    }
+
+    let mut banks: Vec<BankContents> = definitions.iter().map(|bank| project.resolve(bank)).collect();
+    banks.sort_by_key(|b| b.name.to_lowercase());
+    banks
+}
+
+fn read_file(contents: &str, lookup: &ObjectLookup) -> FileObjects {
+    let mut objects = FileObjects::default();
+    let Ok(doc) = Document::parse(contents) else {
+        return objects;
+    };
+    for category in doc.root_element().children().filter(|n| n.is_element()) {
+        let structures = STRUCTURE_CATEGORIES.contains(&category.tag_name().name());
+        for root in category.children().filter(is_definition_node) {
+            read_node(&root, structures, lookup, &mut objects);
+        }
+    }
+    objects
+}
+
+fn read_node(node: &Node, structures: bool, lookup: &ObjectLookup, objects: &mut FileObjects) {
+    let guid = canonical_guid(node.attribute("ID").unwrap_or(""));
+    let object_type = node.tag_name().name();
+    match object_type {
+        "Event" => objects.event_targets.push((guid.clone(), action_targets(node, lookup))),
+        "SoundBank" => {
+            objects.banks.push(BankDefinition { guid: guid.clone(), inclusions: read_inclusions(node, lookup) })
+        }
+        _ if structures && !CONTAINER_TYPES.contains(&object_type) && !SOURCE_TYPES.contains(&object_type) => {
+            objects.structures.push(guid.clone())
+        }
+        _ => {}
+    }
+
+    let children: Vec<Node> = child(node, "ChildrenList")
+        .map(|list| list.children().filter(is_definition_node).collect())
+        .unwrap_or_default();
+    let sources = read_sources(node, &children);
+    if !sources.is_empty() {
+        objects.sources.push((guid, sources));
+    }
+    for item in &children {
+        read_node(item, structures, lookup, objects);
+    }
+}
+
+/// Event 中各动作的目标
+fn action_targets(event: &Node, lookup: &ObjectLookup) -> Vec<String> {
+    let Some(list) = child(event, "ChildrenList") else {
+        return Vec::new();
+    };
+    elements(&list, "Action")
+        .flat_map(|action| read_references(&action, lookup))
+        .filter(|r| r.name == "Target" && !r.embedded)
+        .map(|r| canonical_guid(&r.target_guid))
+        .collect()
+}
+
+/// 子对象中的源及其媒体
+fn read_sources(node: &Node, children: &[Node]) -> Vec<Source> {
+    let active: Vec<String> = child(node, "ActiveSourceList")
+        .map(|list| elements(&list, "ActiveSource").filter_map(|n| n.attribute("ID")).map(canonical_guid).collect())
+        .unwrap_or_default();
+    let text = |node: &Node, tag: &str| child(node, tag).and_then(|n| n.text()).unwrap_or("").trim().to_string();
+
+    let mut sources = Vec::new();
+    for item in children.iter().filter(|n| SOURCE_TYPES.contains(&n.tag_name().name())) {
+        let Some(list) = child(item, "MediaIDList") else {
+            continue;
+        };
+        let language = text(item, "Language");
+        let is_active = active.contains(&canonical_guid(item.attribute("ID").unwrap_or("")));
+        for media_id in elements(&list, "MediaID").filter_map(|n| n.attribute("ID")) {
+            sources.push(Source {
+                name: item.attribute("Name").unwrap_or("").to_string(),
+                media_id: media_id.to_string(),
+                audio_file: text(item, "AudioFile"),
+                language: if language.is_empty() { SFX_LANGUAGE.to_string() } else { language.clone() },
+                active: is_active,
+            });
+        }
+    }
+    sources
+}
+
+impl ProjectObjects<'_> {
+    fn resolve(&self, bank: &BankDefinition) -> BankContents {
+        let node = self.hierarchy.node(&bank.guid);
+        let field = |f: fn(&HierarchyNode) -> &String| node.as_ref().map(f).cloned().unwrap_or_default();
+        let mut contents = BankContents {
+            name: field(|n| &n.name),
+            guid: bank.guid.clone(),
+            short_id: field(|n| &n.short_id),
+            path: field(|n| &n.path),
+            events: Vec::new(),
+            structures: Vec::new(),
+            media: Vec::new(),
+            unresolved: Vec::new(),
+        };
+
+        let mut seen = HashSet::new();
+        for inclusion in &bank.inclusions {
+            let guid = canonical_guid(&inclusion.reference.target_guid);
+            let via = inclusion.reference.target_name.clone();
+            if self.hierarchy.node(&guid).is_none() {
+                contents.unresolved.push(via);
+                continue;
+            }
+
+            let included = self.descendants(&guid);
+            let events: Vec<&HierarchyNode> = included.iter().filter(|n| n.object_type == "Event").collect();
+            if inclusion.filter & FILTER_EVENTS != 0 {
+                for event in &events {
+                    if seen.insert(event.guid.clone()) {
+                        contents.events.push(bank_object(event, &via));
+                    }
+                }
+            }
+            if inclusion.filter & (FILTER_STRUCTURES | FILTER_MEDIA) == 0 {
+                continue;
+            }
+
+            // 直接包含的层级结构和 Event 的目标
+            let mut targets: Vec<String> =
+                included.iter().filter(|n| self.structures.contains(&n.guid)).map(|n| n.guid.clone()).collect();
+            for event in &events {
+                let event_targets = self.event_targets.get(&event.guid).into_iter().flatten();
+                targets.extend(event_targets.filter(|t| self.structures.contains(*t)).cloned());
+            }
+            let below: Vec<HierarchyNode> = targets.iter().flat_map(|t| self.descendants(t)).collect();
+
+            if inclusion.filter & FILTER_STRUCTURES != 0 {
+                for target in &targets {
+                    for ancestor in self.ancestors(target) {
+                        if seen.insert(ancestor.guid.clone()) {
+                            contents.structures.push(bank_object(&ancestor, &via));
+                        }
+                    }
+                }
+                for structure in below.iter().filter(|n| self.structures.contains(&n.guid)) {
+                    if seen.insert(structure.guid.clone()) {
+                        contents.structures.push(bank_object(structure, &via));
+                    }
+                }
+            }
+            if inclusion.filter & FILTER_MEDIA != 0 {
+                for object in &below {
+                    for source in self.selected_sources(&object.guid) {
+                        if seen.insert(format!("media:{}", source.media_id)) {
+                            contents.media.push(BankMedia {
+                                id: source.media_id.clone(),
+                                name: source.audio_file.clone(),
+                                language: source.language.clone(),
+                                source: source.name.clone(),
+                                via: via.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        contents.events.sort_by(|a, b| a.path.cmp(&b.path));
+        contents.structures.sort_by(|a, b| a.path.cmp(&b.path));
+        contents.media.sort_by_key(|m| (m.language.clone(), m.id.parse::<u64>().unwrap_or(u64::MAX)));
+        contents
+    }
+
+    /// 对象本身及其所有子对象
+    fn descendants(&self, guid: &str) -> Vec<HierarchyNode> {
+        let mut result = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack: Vec<HierarchyNode> = self.hierarchy.node(guid).into_iter().collect();
+        while let Some(node) = stack.pop() {
+            if !visited.insert(node.guid.clone()) {
+                continue;
+            }
+            let mut children = self.hierarchy.children(&node.guid).unwrap_or_default();
+            children.reverse();
+            stack.extend(children);
+            result.push(node);
+        }
+        result
+    }
+
+    /// 层级结构的上级对象，到工作单元或文件夹为止
+    fn ancestors(&self, guid: &str) -> Vec<HierarchyNode> {
+        let mut result = Vec::new();
+        let mut current = self.hierarchy.parent(guid).flatten();
+        while let Some(node) = current.filter(|n| self.structures.contains(&n.guid)) {
+            if result.iter().any(|n: &HierarchyNode| n.guid == node.guid) {
+                break;
+            }
+            current = self.hierarchy.parent(&node.guid).flatten();
+            result.push(node);
+        }
+        result
+    }
+
+    /// 对象下写入 SoundBank 的源：同一语言有激活的源时只取激活的源
+    fn selected_sources(&self, guid: &str) -> Vec<&Source> {
+        let sources = self.sources.get(guid).map(Vec::as_slice).unwrap_or_default();
+        sources
+            .iter()
+            .filter(|s| s.active || !sources.iter().any(|o| o.active && o.language == s.language))
+            .collect()
+    }
+}
+
+fn bank_object(node: &HierarchyNode, via: &str) -> BankObject {
+    BankObject {
+        name: node.name.clone(),
+        object_type: node.object_type.clone(),
+        guid: node.guid.clone(),
+        short_id: node.short_id.clone(),
+        path: node.path.clone(),
+        via: via.to_string(),
+    }
+}
+
+/// 包含指定对象的 SoundBank
+///
+/// `query` 可以是 Event 或层级结构的 GUID、ShortID、名称，或媒体的 MediaID、音频文件名。
+pub fn banks_containing(banks: &[BankContents], query: &str) -> Vec<BankMembership> {
+    let query = query.trim();
+    let guid = canonical_guid(query);
+    let matches = |object: &BankObject| {
+        object.guid == guid
+            || (!object.short_id.is_empty() && object.short_id == query)
+            || object.name.eq_ignore_ascii_case(query)
+    };
+
+    let mut result = Vec::new();
+    for bank in banks {
+        let membership = |kind: &str, name: &str, via: &str| BankMembership {
+            bank: bank.name.clone(),
+            bank_guid: bank.guid.clone(),
+            kind: kind.to_string(),
+            name: name.to_string(),
+            via: via.to_string(),
+        };
+        for object in bank.events.iter().filter(|o| matches(o)) {
+            result.push(membership("event", &object.name, &object.via));
+        }
+        for object in bank.structures.iter().filter(|o| matches(o)) {
+            result.push(membership("structure", &object.name, &object.via));
+        }
+        for media in bank.media.iter().filter(|m| m.id == query || m.name.eq_ignore_ascii_case(query)) {
+            result.push(membership("media", &media.name, &media.via));
+        }
+    }
+    result
+}
+
+/// 读取 Bank 目录中 SoundbanksInfo（或单独的 Bank 元数据文件）记录的 SoundBank
+pub fn read_generated_banks(directory: &Path) -> Result<Vec<GeneratedBank>, String> {
+    let files = discover_bank_metadata(directory)?;
+    let paths: Vec<PathBuf> = match files.soundbanks_info {
+        Some(path) => vec![path],
+        None => files.bank_files,
+    };
+
+    let mut banks: Vec<GeneratedBank> = Vec::new();
+    for path in &paths {
+        let contents = fs::read_to_string(path).map_err(|e| format!("读取文件 {} 失败: {}", path.display(), e))?;
+        let read = if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+            let json: Value = serde_json::from_str(&contents)
+                .map_err(|e| format!("解析 JSON 文件 {} 失败: {}", path.display(), e))?;
+            read_json_banks(&json)
+        } else {
+            let doc = Document::parse(&contents)
+                .map_err(|e| format!("解析 XML 文件 {} 失败: {}", path.display(), e))?;
+            read_xml_banks(&doc)
+        };
+
+        // 本地化 SoundBank 每种语言一项，按名称合并
+        for bank in read {
+            match banks.iter_mut().find(|b| b.name == bank.name) {
+                Some(existing) => {
+                    existing.languages.extend(bank.languages);
+                    existing.events.extend(bank.events);
+                    existing.media.extend(bank.media);
+                }
+                None => banks.push(bank),
+            }
+        }
+    }
+    Ok(banks)
+}
+
+fn read_json_banks(json: &Value) -> Vec<GeneratedBank> {
+    let Some(info) = json.get("SoundBanksInfo") else {
+        return Vec::new();
+    };
+    json_array(info, &["SoundBanks"])
+        .into_iter()
+        .map(|bank| GeneratedBank {
+            name: json_text(bank, "ShortName"),
+            guid: canonical_guid(&json_text(bank, "GUID")),
+            languages: vec![json_text(bank, "Language")],
+            events: json_array(bank, &["Events", "IncludedEvents"])
+                .into_iter()
+                .map(|e| (canonical_guid(&json_text(e, "GUID")), json_text(e, "Name")))
+                .collect(),
+            media: json_array(bank, MEDIA_LISTS)
+                .into_iter()
+                .map(|m| (json_text(m, "Id"), json_text(m, "Language")))
+                .collect(),
+        })
+        .collect()
+}
+
+fn read_xml_banks(doc: &Document) -> Vec<GeneratedBank> {
+    let attribute = |node: &Node, name: &str| node.attribute(name).unwrap_or("").to_string();
+    doc.descendants()
+        .filter(|n| n.has_tag_name("SoundBank"))
+        .map(|bank| {
+            GeneratedBank {
+                name: child(&bank, "ShortName").and_then(|n| n.text()).unwrap_or("").to_string(),
+                guid: canonical_guid(&attribute(&bank, "GUID")),
+                languages: vec![attribute(&bank, "Language")],
+                events: xml_items(&bank, &["Events", "IncludedEvents"], "Event")
+                    .iter()
+                    .map(|e| (canonical_guid(&attribute(e, "GUID")), attribute(e, "Name")))
+                    .collect(),
+                media: xml_items(&bank, MEDIA_LISTS, "File")
+                    .iter()
+                    .map(|m| (attribute(m, "Id"), attribute(m, "Language")))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// 依次取出多个列表中的元素，新旧版本的标签不同
+fn xml_items<'a, 'input>(bank: &Node<'a, 'input>, tags: &[&str], item: &str) -> Vec<Node<'a, 'input>> {
+    tags.iter()
+        .filter_map(|tag| child(bank, tag))
+        .flat_map(|list| list.children().filter(|n| n.has_tag_name(item)))
+        .collect()
+}
+
+/// 比较计算出的 SoundBank 内容和 SoundbanksInfo，只返回不一致的 SoundBank
+///
+/// SoundbanksInfo 中没有出现的语言视为未生成，不比较这些语言的媒体。
+/// 只在 SoundbanksInfo 中出现的 SoundBank（如 Init）不参与比较。
+pub fn compare_banks(banks: &[BankContents], generated: &[GeneratedBank]) -> Vec<BankDifference> {
+    let languages: HashSet<&str> = generated
+        .iter()
+        .flat_map(|b| b.languages.iter().map(String::as_str).chain(b.media.iter().map(|(_, l)| l.as_str())))
+        .collect();
+
+    let mut differences = Vec::new();
+    for bank in banks {
+        let found = generated
+            .iter()
+            .find(|g| (!g.guid.is_empty() && g.guid == bank.guid) || g.name.eq_ignore_ascii_case(&bank.name));
+        let Some(found) = found else {
+            differences.push(BankDifference {
+                bank: bank.name.clone(),
+                generated: false,
+                missing_events: Vec::new(),
+                extra_events: Vec::new(),
+                missing_media: Vec::new(),
+                extra_media: Vec::new(),
+            });
+            continue;
+        };
+
+        let has_event = |guid: &str, name: &str| {
+            found.events.iter().any(|(g, n)| if g.is_empty() { n.eq_ignore_ascii_case(name) } else { g == guid })
+        };
+        let expects_event = |guid: &str, name: &str| {
+            bank.events.iter().any(|e| if guid.is_empty() { e.name.eq_ignore_ascii_case(name) } else { e.guid == guid })
+        };
+        let mut difference = BankDifference {
+            bank: bank.name.clone(),
+            generated: true,
+            missing_events: bank
+                .events
+                .iter()
+                .filter(|e| !has_event(&e.guid, &e.name))
+                .map(|e| e.name.clone())
+                .collect(),
+            extra_events: found.events.iter().filter(|(g, n)| !expects_event(g, n)).map(|(_, n)| n.clone()).collect(),
+            missing_media: bank
+                .media
+                .iter()
+                .filter(|m| m.language == SFX_LANGUAGE || languages.contains(m.language.as_str()))
+                .filter(|m| !found.media.iter().any(|(id, _)| *id == m.id))
+                .map(|m| m.id.clone())
+                .collect(),
+            extra_media: found
+                .media
+                .iter()
+                .filter(|(id, _)| !bank.media.iter().any(|m| m.id == *id))
+                .map(|(id, _)| id.clone())
+                .collect(),
+        };
+        // 本地化 SoundBank 的各语言可能记录相同的媒体
+        let mut seen = HashSet::new();
+        difference.extra_media.retain(|id| seen.insert(id.clone()));
+        let consistent = difference.missing_events.is_empty()
+            && difference.extra_events.is_empty()
+            && difference.missing_media.is_empty()
+            && difference.extra_media.is_empty();
+        if !consistent {
+            differences.push(difference);
+        }
+    }
+    differences
+}
+
+fn load_bank_contents(directory: &str) -> Result<Vec<BankContents>, String> {
+    let documents = load_project_documents(Path::new(directory))?;
+    Ok(resolve_bank_contents(&documents))
+}
+
+/// 离线计算工程中每个 SoundBank 将包含的 Event、层级结构和媒体
+///
+/// # 参数
+/// * `bank_directory` - 不为空时与其中的 SoundbanksInfo 比较
+#[tauri::command]
+pub fn get_bank_inclusions(directory: String, bank_directory: Option<String>) -> Result<BankInclusionReport, String> {
+    let banks = load_bank_contents(&directory)?;
+    let differences = match bank_directory.filter(|d| !d.trim().is_empty()) {
+        Some(bank_directory) => compare_banks(&banks, &read_generated_banks(Path::new(&bank_directory))?),
+        None => Vec::new(),
+    };
+    Ok(BankInclusionReport { banks, differences })
+}
+
+/// 查询对象将进入哪些 SoundBank，`object` 为 GUID、ShortID、MediaID 或名称
+#[tauri::command]
+pub fn find_object_banks(directory: String, object: String) -> Result<Vec<BankMembership>, String> {
+    Ok(banks_containing(&load_bank_contents(&directory)?, &object))
+}
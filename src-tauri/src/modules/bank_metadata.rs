@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Bank 目录中发现的元数据文件
+///
+/// Wwise 可以生成一个汇总的 SoundbanksInfo，也可以为每个 Bank 单独生成
+/// `<Bank>.json` / `<Bank>.xml`，同时附带 ProjectInfo、PlatformInfo 和 PluginInfo。
+#[derive(Debug, Default)]
+pub struct BankMetadataFiles {
+    /// SoundbanksInfo.json 或 SoundbanksInfo.xml（JSON 优先）
+    pub soundbanks_info: Option<PathBuf>,
+    /// 每个 Bank 单独的元数据文件
+    pub bank_files: Vec<PathBuf>,
+    pub project_info: Option<PathBuf>,
+    pub platform_info: Option<PathBuf>,
+    pub plugin_info: Option<PathBuf>,
+}
+
+impl BankMetadataFiles {
+    /// 是否找到了任何 Bank 元数据（汇总文件或单独的 Bank 文件）
+    pub fn has_bank_metadata(&self) -> bool {
+        self.soundbanks_info.is_some() || !self.bank_files.is_empty()
+    }
+
+    /// 所有信息文件（ProjectInfo、PlatformInfo、PluginInfo）及其根节点名称
+    pub fn info_files(&self) -> Vec<(&Path, &'static str)> {
+        let mut files = Vec::new();
+        if let Some(path) = &self.project_info {
+            files.push((path.as_path(), "ProjectInfo"));
+        }
+        if let Some(path) = &self.platform_info {
+            files.push((path.as_path(), "PlatformInfo"));
+        }
+        if let Some(path) = &self.plugin_info {
+            files.push((path.as_path(), "PluginInfo"));
+        }
+        files
+    }
+}
+
+/// 查找 Bank 目录中的元数据文件
+///
+/// 单独的 Bank 元数据文件必须与同名的 `.bnk` 位于同一目录，本地化 Bank 位于语言子目录中，
+/// 因此会额外扫描一层子目录。ProjectInfo 由 Wwise 写在 GeneratedSoundBanks 根目录，
+/// 用户通常选择的是平台目录，所以也会在上一级目录中查找。
+pub fn discover_bank_metadata(dir: &Path) -> Result<BankMetadataFiles, String> {
+    let mut files = BankMetadataFiles {
+        soundbanks_info: find_metadata_file(dir, "SoundbanksInfo"),
+        project_info: find_metadata_file(dir, "ProjectInfo")
+            .or_else(|| dir.parent().and_then(|p| find_metadata_file(p, "ProjectInfo"))),
+        platform_info: find_metadata_file(dir, "PlatformInfo"),
+        plugin_info: find_metadata_file(dir, "PluginInfo"),
+        ..Default::default()
+    };
+
+    // 汇总文件存在时不再使用单独的 Bank 文件，避免重复结果
+    if files.soundbanks_info.is_some() {
+        return Ok(files);
+    }
+
+    let entries = fs::read_dir(dir).map_err(|e| format!("无法读取目录: {}", e))?;
+    let mut dirs = vec![dir.to_path_buf()];
+    dirs.extend(entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()));
+
+    for bank_dir in dirs {
+        collect_bank_files(&bank_dir, &mut files.bank_files);
+    }
+    files.bank_files.sort();
+
+    Ok(files)
+}
+
+/// 在目录中查找 `<stem>.json` 或 `<stem>.xml`，JSON 优先
+fn find_metadata_file(dir: &Path, stem: &str) -> Option<PathBuf> {
+    ["json", "xml"]
+        .iter()
+        .map(|ext| dir.join(format!("{}.{}", stem, ext)))
+        .find(|p| p.is_file())
+}
+
+/// 收集目录中每个 `.bnk` 对应的元数据文件
+fn collect_bank_files(dir: &Path, bank_files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_bank = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("bnk"));
+        if !is_bank {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+            if let Some(metadata) = find_metadata_file(dir, stem) {
+                bank_files.push(metadata);
+            }
+        }
+    }
+}
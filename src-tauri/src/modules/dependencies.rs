@@ -0,0 +1,300 @@
+use std::path::Path;
+use rayon::prelude::*;
+use roxmltree::{Document, Node};
+use crate::modules::graph::{Graph, GraphEdge, GraphNode, GraphPath};
+use crate::modules::hierarchy::ProjectHierarchy;
+use crate::modules::id_resolver::canonical_guid;
+use crate::modules::index::{index_project_documents, is_definition_node, load_project_documents, ProjectDocuments};
+use crate::modules::object_details::{child, elements, read_references, resolve_ref, ObjectLookup, ObjectReference};
+
+/// 容器、工作单元或文件夹到子对象
+pub const EDGE_CHILD: &str = "child";
+/// Event 到动作
+pub const EDGE_ACTION: &str = "action";
+/// 动作到目标对象
+pub const EDGE_TARGET: &str = "target";
+/// 对象到 ShareSet（Attenuation、Conversion、Effect 等）
+pub const EDGE_SHARESET: &str = "shareset";
+/// SoundBank 到包含的对象
+pub const EDGE_INCLUSION: &str = "inclusion";
+/// Switch Container 到 Switch Group 或 State Group
+pub const EDGE_SWITCH_GROUP: &str = "switch_group";
+/// 源到媒体文件
+pub const EDGE_MEDIA: &str = "media";
+
+/// SoundBank 包含过滤：Event
+pub const FILTER_EVENTS: u32 = 1;
+/// SoundBank 包含过滤：层级结构
+pub const FILTER_STRUCTURES: u32 = 2;
+/// SoundBank 包含过滤：媒体
+pub const FILTER_MEDIA: u32 = 4;
+
+/// ShareSet 对象类型
+const SHARESET_TYPES: &[&str] =
+    &["Attenuation", "Conversion", "Effect", "ModulatorLfo", "ModulatorEnvelope", "ModulatorTime", "AudioDevice"];
+
+/// 指向 ShareSet 的引用名称，目标未在工程中找到时按名称判断
+const SHARESET_REFERENCES: &[&str] = &["Attenuation", "Conversion", "Effect0", "Effect1", "Effect2", "Effect3"];
+
+/// 路径查询最多返回的路径数
+const MAX_PATHS: usize = 50;
+
+/// SoundBank 中的一项包含
+#[derive(Debug, Clone)]
+pub struct Inclusion {
+    pub reference: ObjectReference,
+    /// `FILTER_EVENTS`、`FILTER_STRUCTURES`、`FILTER_MEDIA` 的组合
+    pub filter: u32,
+}
+
+/// 依赖边；目标在构建图时加入节点
+struct DependencyEdge {
+    source: String,
+    target: ObjectReference,
+    kind: &'static str,
+    label: String,
+}
+
+/// 单个 .wwu 文件中的对象和依赖
+#[derive(Default)]
+struct FileDependencies {
+    nodes: Vec<GraphNode>,
+    edges: Vec<DependencyEdge>,
+}
+
+/// 从 .wwu 文件构建工程的依赖图
+///
+/// 边包括 Event → 动作 → 目标、容器 → 子对象、对象 → ShareSet、SoundBank → 包含的对象、
+/// Switch Container → Switch Group，以及源 → 媒体（节点 id 为 `media:<MediaID>`）。
+pub fn build_dependency_graph(documents: &ProjectDocuments) -> Graph {
+    let objects = index_project_documents(documents);
+    let lookup = ObjectLookup::new(&objects);
+    let hierarchy = ProjectHierarchy::build(documents);
+    let files: Vec<FileDependencies> =
+        documents.files.par_iter().map(|file| read_file(&file.contents, &lookup)).collect();
+
+    let mut graph = Graph::default();
+    let mut edges = Vec::new();
+    for file in files {
+        for mut node in file.nodes {
+            // 层级中的路径包含嵌套工作单元
+            if let Some(entry) = hierarchy.node(&node.id) {
+                node.path = entry.path;
+            }
+            graph.add_node(node);
+        }
+        edges.extend(file.edges);
+    }
+
+    for edge in edges {
+        let target = match edge.kind {
+            EDGE_MEDIA => edge.target.target_guid.clone(),
+            _ => canonical_guid(&edge.target.target_guid),
+        };
+        if graph.node(&target).is_none() {
+            let reference = edge.target;
+            graph.add_node(GraphNode {
+                id: target.clone(),
+                name: reference.target_name,
+                object_type: reference.target_type,
+                short_id: String::new(),
+                path: reference.target_path,
+            });
+        }
+        graph.add_edge(GraphEdge { source: edge.source, target, kind: edge.kind.to_string(), label: edge.label });
+    }
+    graph
+}
+
+fn read_file(contents: &str, lookup: &ObjectLookup) -> FileDependencies {
+    let mut dependencies = FileDependencies::default();
+    let Ok(doc) = Document::parse(contents) else {
+        return dependencies;
+    };
+    for category in doc.root_element().children().filter(|n| n.is_element()) {
+        for root in category.children().filter(is_definition_node) {
+            read_node(&root, None, lookup, &mut dependencies);
+        }
+    }
+    dependencies
+}
+
+fn read_node(node: &Node, parent: Option<&Node>, lookup: &ObjectLookup, dependencies: &mut FileDependencies) {
+    let guid = canonical_guid(node.attribute("ID").unwrap_or(""));
+    let object_type = node.tag_name().name();
+    dependencies.nodes.push(GraphNode {
+        id: guid.clone(),
+        name: node.attribute("Name").unwrap_or("").to_string(),
+        object_type: object_type.to_string(),
+        short_id: node.attribute("ShortID").unwrap_or("").to_string(),
+        path: lookup.get(&guid).map(|o| o.path.clone()).unwrap_or_default(),
+    });
+
+    if let Some(parent) = parent {
+        let kind = if parent.has_tag_name("Event") && object_type == "Action" { EDGE_ACTION } else { EDGE_CHILD };
+        dependencies.edges.push(DependencyEdge {
+            source: canonical_guid(parent.attribute("ID").unwrap_or("")),
+            target: node_reference(node),
+            kind,
+            label: String::new(),
+        });
+    }
+
+    for reference in read_references(node, lookup).into_iter().filter(|r| !r.embedded) {
+        let shareset = SHARESET_REFERENCES.contains(&reference.name.as_str())
+            || SHARESET_TYPES.contains(&reference.target_type.as_str());
+        let kind = match reference.name.as_str() {
+            "Target" => EDGE_TARGET,
+            "SwitchGroupOrStateGroup" => EDGE_SWITCH_GROUP,
+            _ if shareset => EDGE_SHARESET,
+            _ => continue,
+        };
+        dependencies.edges.push(DependencyEdge {
+            source: guid.clone(),
+            label: reference.name.clone(),
+            target: reference,
+            kind,
+        });
+    }
+
+    if object_type == "SoundBank" {
+        for inclusion in read_inclusions(node, lookup) {
+            dependencies.edges.push(DependencyEdge {
+                source: guid.clone(),
+                label: filter_label(inclusion.filter),
+                target: inclusion.reference,
+                kind: EDGE_INCLUSION,
+            });
+        }
+    }
+
+    let media_ids: Vec<&str> = child(node, "MediaIDList")
+        .map(|list| elements(&list, "MediaID").filter_map(|n| n.attribute("ID")).collect())
+        .unwrap_or_default();
+    for media_id in media_ids {
+        let audio_file = child(node, "AudioFile").and_then(|n| n.text()).unwrap_or("").trim();
+        dependencies.nodes.push(GraphNode {
+            id: media_node_id(media_id),
+            name: if audio_file.is_empty() { media_id.to_string() } else { audio_file.to_string() },
+            object_type: "Media".to_string(),
+            short_id: String::new(),
+            path: String::new(),
+        });
+        dependencies.edges.push(DependencyEdge {
+            source: guid.clone(),
+            target: ObjectReference {
+                name: "MediaID".to_string(),
+                target_name: media_id.to_string(),
+                target_type: "Media".to_string(),
+                target_guid: media_node_id(media_id),
+                target_path: String::new(),
+                resolved: true,
+                embedded: false,
+            },
+            kind: EDGE_MEDIA,
+            label: String::new(),
+        });
+    }
+
+    if let Some(list) = child(node, "ChildrenList") {
+        for item in list.children().filter(is_definition_node) {
+            read_node(&item, Some(node), lookup, dependencies);
+        }
+    }
+}
+
+/// 文件中定义的子对象，名称等信息取自节点本身
+fn node_reference(node: &Node) -> ObjectReference {
+    ObjectReference {
+        name: String::new(),
+        target_name: node.attribute("Name").unwrap_or("").to_string(),
+        target_type: node.tag_name().name().to_string(),
+        target_guid: node.attribute("ID").unwrap_or("").to_string(),
+        target_path: String::new(),
+        resolved: true,
+        embedded: false,
+    }
+}
+
+/// 媒体节点的 id
+pub fn media_node_id(media_id: &str) -> String {
+    format!("media:{}", media_id)
+}
+
+/// 读取 SoundBank 的 ObjectInclusionList
+///
+/// 包含项为 `<ObjectInclusion Filter="7"><ObjectRef .../></ObjectInclusion>`，也接受直接带 `Filter` 属性的 ObjectRef。
+/// 没有 `Filter` 时包含全部内容。
+pub fn read_inclusions(bank: &Node, lookup: &ObjectLookup) -> Vec<Inclusion> {
+    let Some(list) = child(bank, "ObjectInclusionList") else {
+        return Vec::new();
+    };
+    let all = FILTER_EVENTS | FILTER_STRUCTURES | FILTER_MEDIA;
+    list.children()
+        .filter(|n| n.is_element())
+        .filter_map(|item| {
+            let target = if item.has_tag_name("ObjectRef") { item } else { child(&item, "ObjectRef")? };
+            let filter = item.attribute("Filter").and_then(|f| f.parse().ok()).unwrap_or(all);
+            Some(Inclusion { reference: resolve_ref("Inclusion", &target, lookup), filter })
+        })
+        .collect()
+}
+
+/// 包含过滤的说明，如 `events, structures, media`
+pub fn filter_label(filter: u32) -> String {
+    let names: Vec<&str> = [(FILTER_EVENTS, "events"), (FILTER_STRUCTURES, "structures"), (FILTER_MEDIA, "media")]
+        .iter()
+        .filter(|(flag, _)| filter & flag != 0)
+        .map(|(_, name)| *name)
+        .collect();
+    names.join(", ")
+}
+
+/// 按节点 id、GUID、MediaID、ShortID 或名称查找节点
+pub fn find_node<'a>(graph: &'a Graph, query: &str) -> Option<&'a GraphNode> {
+    let query = query.trim();
+    graph
+        .node(query)
+        .or_else(|| graph.node(&canonical_guid(query)))
+        .or_else(|| graph.node(&media_node_id(query)))
+        .or_else(|| graph.nodes.iter().find(|n| !n.short_id.is_empty() && n.short_id == query))
+        .or_else(|| graph.nodes.iter().find(|n| n.name.eq_ignore_ascii_case(query)))
+}
+
+/// 从一个对象沿依赖到达另一个对象的路径，如 Event 如何引用到某个媒体
+pub fn dependency_paths(graph: &Graph, from: &str, to: &str) -> Result<Vec<GraphPath>, String> {
+    let source = find_node(graph, from).ok_or_else(|| format!("工程中未找到对象 {}", from))?;
+    let target = find_node(graph, to).ok_or_else(|| format!("工程中未找到对象 {}", to))?;
+    Ok(graph.paths(&source.id, &target.id, MAX_PATHS))
+}
+
+fn load_dependencies(directory: &str) -> Result<Graph, String> {
+    let documents = load_project_documents(Path::new(directory))?;
+    Ok(build_dependency_graph(&documents))
+}
+
+/// 获取工程的依赖图
+#[tauri::command]
+pub fn get_dependency_graph(directory: String) -> Result<Graph, String> {
+    load_dependencies(&directory)
+}
+
+/// 导出工程的依赖图
+///
+/// # 参数
+/// * `format` - 导出格式，可选值: "json", "graphml", "dot", "mermaid"；为空时根据扩展名推断
+///
+/// # 返回
+/// * `Ok(usize)` - 导出的边数
+#[tauri::command]
+pub fn export_dependency_graph(directory: String, format: Option<String>, path: String) -> Result<usize, String> {
+    let graph = load_dependencies(&directory)?;
+    graph.write(format.as_deref(), Path::new(&path), "dependencies")?;
+    Ok(graph.edges.len())
+}
+
+/// 查询从 `from` 到 `to` 的依赖路径，两者可以是 GUID、ShortID、MediaID 或名称
+#[tauri::command]
+pub fn find_dependency_paths(directory: String, from: String, to: String) -> Result<Vec<GraphPath>, String> {
+    dependency_paths(&load_dependencies(&directory)?, &from, &to)
+}
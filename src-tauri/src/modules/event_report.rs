@@ -0,0 +1,474 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use roxmltree::{Document, Node};
+use serde::Serialize;
+use serde_json::Value;
+use crate::modules::bank_metadata::discover_bank_metadata;
+use crate::modules::id_resolver::{parse_id_token, TokenKind};
+use crate::modules::index::{index_project_documents, load_project_documents, IndexedObject, ProjectDocuments};
+use crate::modules::object_details::{
+    child, elements, find_definition, read_properties, read_references, ObjectLookup, ObjectReference,
+    PropertyValue,
+};
+
+/// Action 的 `ActionType` 属性对应的动作名称，未设置时为 Play
+const ACTION_TYPES: &[(u32, &str)] = &[
+    (1, "Play"),
+    (2, "Stop"),
+    (3, "StopAll"),
+    (7, "Pause"),
+    (8, "PauseAll"),
+    (9, "Resume"),
+    (10, "ResumeAll"),
+    (12, "Break"),
+    (13, "Seek"),
+    (14, "SeekAll"),
+    (15, "PostEvent"),
+    (16, "Mute"),
+    (17, "Unmute"),
+    (18, "UnmuteAll"),
+    (19, "SetVoicePitch"),
+    (20, "ResetVoicePitch"),
+    (21, "ResetVoicePitchAll"),
+    (22, "SetVoiceVolume"),
+    (23, "ResetVoiceVolume"),
+    (24, "ResetVoiceVolumeAll"),
+    (25, "SetBusVolume"),
+    (26, "ResetBusVolume"),
+    (27, "ResetBusVolumeAll"),
+    (28, "SetLPF"),
+    (29, "ResetLPF"),
+    (30, "ResetLPFAll"),
+    (31, "EnableState"),
+    (32, "DisableState"),
+    (33, "SetState"),
+    (34, "SetGameParameter"),
+    (35, "ResetGameParameter"),
+    (36, "SetSwitch"),
+    (37, "EnableBypass"),
+    (38, "DisableBypass"),
+    (39, "ResetBypassEffect"),
+    (40, "ResetBypassEffectAll"),
+];
+
+/// 淡入淡出曲线的名称
+const CURVE_SHAPES: &[&str] = &["Log3", "Sine", "Log1", "InvSCurve", "Linear", "SCurve", "Exp1", "SineRecip", "Exp3"];
+
+/// 单独列出、不再放入 `properties` 的 Action 属性
+const ACTION_FIELDS: &[&str] = &["ActionType", "Scope", "Delay", "FadeTime", "FadeInCurve", "FadeOutCurve"];
+
+/// 报告中的 Event
+#[derive(Debug, Clone, Serialize)]
+pub struct EventSummary {
+    pub name: String,
+    pub guid: String,
+    pub short_id: String,
+    pub path: String,
+}
+
+/// Event 中的一个动作
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionInfo {
+    pub guid: String,
+    pub short_id: String,
+    /// 动作类型，如 `Play`、`Stop`、`SetState`
+    pub action_type: String,
+    pub target: Option<ObjectReference>,
+    /// 作用范围：`GameObject` 或 `Global`；SoundbanksInfo 中没有该信息
+    pub scope: Option<String>,
+    /// 延迟（秒）
+    pub delay: Option<f64>,
+    /// 淡入淡出时间（秒）
+    pub fade_time: Option<f64>,
+    pub fade_curve: Option<String>,
+    /// 其他属性，如 Play 的 Probability、Seek 的 SeekPercent
+    pub properties: Vec<PropertyValue>,
+}
+
+/// 包含 Event 的 SoundBank
+#[derive(Debug, Clone, Serialize)]
+pub struct EventBank {
+    pub name: String,
+    pub id: String,
+    pub path: String,
+    pub language: String,
+}
+
+/// Event 引用的媒体
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaRef {
+    pub id: String,
+    pub name: String,
+}
+
+/// Event 的动作报告
+#[derive(Debug, Clone, Serialize)]
+pub struct EventReport {
+    pub event: EventSummary,
+    /// 动作的来源：`project`（.wwu）或 `soundbanks`（SoundbanksInfo，没有工程文件时使用）
+    pub source: String,
+    pub actions: Vec<ActionInfo>,
+    /// 提供 Bank 目录时，包含该 Event 的 SoundBank
+    pub banks: Vec<EventBank>,
+    /// SoundbanksInfo 中记录的 Event 引用的媒体
+    pub media: Vec<MediaRef>,
+}
+
+/// 要查找的 Event：GUID、ShortID 或名称
+enum EventKey {
+    Guid(String),
+    ShortId(String),
+    Name(String),
+}
+
+impl EventKey {
+    fn parse(input: &str) -> Self {
+        match parse_id_token(input) {
+            Some(token) if token.kind == TokenKind::Guid => EventKey::Guid(token.value),
+            Some(token) => EventKey::ShortId(token.value),
+            None => EventKey::Name(input.trim().to_string()),
+        }
+    }
+
+    fn matches(&self, name: &str, guid: &str, short_id: &str) -> bool {
+        match self {
+            EventKey::Guid(value) => guid.eq_ignore_ascii_case(value),
+            EventKey::ShortId(value) => short_id == value,
+            EventKey::Name(value) => name == value,
+        }
+    }
+}
+
+fn action_type_name(value: &str) -> String {
+    let code = value.parse::<u32>().unwrap_or(1);
+    ACTION_TYPES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or_else(|| format!("ActionType {}", code))
+}
+
+/// 从 .wwu 读取 Event 的动作
+pub fn project_event_actions(
+    documents: &ProjectDocuments,
+    objects: &[IndexedObject],
+    event: &str,
+) -> Option<(EventSummary, Vec<ActionInfo>)> {
+    let key = EventKey::parse(event);
+    let object = objects
+        .iter()
+        .find(|o| o.object_type == "Event" && key.matches(&o.name, &o.guid, &o.short_id))?;
+    let lookup = ObjectLookup::new(objects);
+
+    let summary = EventSummary {
+        name: object.name.clone(),
+        guid: object.guid.clone(),
+        short_id: object.short_id.clone(),
+        path: object.path.clone(),
+    };
+    for file in documents.files.iter().filter(|f| f.contents.contains(object.guid.as_str())) {
+        let Ok(doc) = Document::parse(&file.contents) else {
+            continue;
+        };
+        if let Some(node) = find_definition(&doc, &object.guid) {
+            let actions = child(&node, "ChildrenList")
+                .map(|list| elements(&list, "Action").map(|action| read_action(&action, &lookup)).collect())
+                .unwrap_or_default();
+            return Some((summary, actions));
+        }
+    }
+    Some((summary, Vec::new()))
+}
+
+fn read_action(action: &Node, lookup: &ObjectLookup) -> ActionInfo {
+    let properties = read_properties(action);
+    let property = |name: &str| properties.iter().find(|p| p.name == name).map(|p| p.value.as_str());
+    let number = |name: &str| property(name).and_then(|v| v.parse::<f64>().ok()).unwrap_or(0.0);
+
+    let action_type = action_type_name(property("ActionType").unwrap_or("1"));
+    let fade_curve = property("FadeInCurve")
+        .or_else(|| property("FadeOutCurve"))
+        .unwrap_or("4")
+        .parse::<usize>()
+        .ok()
+        .and_then(|shape| CURVE_SHAPES.get(shape))
+        .unwrap_or(&"Linear")
+        .to_string();
+    let scope = match property("Scope") {
+        Some("1") => "Global",
+        _ => "GameObject",
+    };
+    let target = read_references(action, lookup).into_iter().find(|r| r.name == "Target");
+
+    ActionInfo {
+        guid: action.attribute("ID").unwrap_or("").to_string(),
+        short_id: action.attribute("ShortID").unwrap_or("").to_string(),
+        action_type,
+        target,
+        scope: Some(scope.to_string()),
+        delay: Some(number("Delay")),
+        fade_time: Some(number("FadeTime")),
+        fade_curve: Some(fade_curve),
+        properties: properties.iter().filter(|p| !ACTION_FIELDS.contains(&p.name.as_str())).cloned().collect(),
+    }
+}
+
+/// SoundbanksInfo 中一个 Event 的记录
+#[derive(Debug, Default)]
+pub struct BankEvent {
+    pub summary: Option<EventSummary>,
+    pub banks: Vec<EventBank>,
+    /// SoundbanksInfo 中记录的 SetState、SetSwitch 等动作
+    pub actions: Vec<ActionInfo>,
+    pub media: Vec<MediaRef>,
+}
+
+/// 在 Bank 目录的元数据中查找 Event
+///
+/// 较新版本的 SoundbanksInfo 在 Event 中记录 `ActionSetState`、`ActionSetSwitch` 等动作和 `MediaRefs`，
+/// 旧版本只记录 Event 所在的 SoundBank。
+pub fn bank_event_info(directory: &Path, event: &str) -> Result<BankEvent, String> {
+    let files = discover_bank_metadata(directory)?;
+    let paths: Vec<PathBuf> = match files.soundbanks_info {
+        Some(path) => vec![path],
+        None => files.bank_files,
+    };
+
+    let key = EventKey::parse(event);
+    let mut result = BankEvent::default();
+    let mut media_names = HashMap::new();
+    let mut media_ids = Vec::new();
+    for path in &paths {
+        let contents = fs::read_to_string(path).map_err(|e| format!("读取文件 {} 失败: {}", path.display(), e))?;
+        if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+            let json: Value = serde_json::from_str(&contents)
+                .map_err(|e| format!("解析 JSON 文件 {} 失败: {}", path.display(), e))?;
+            read_json_banks(&json, &key, &mut result, &mut media_names, &mut media_ids);
+        } else {
+            let doc = Document::parse(&contents)
+                .map_err(|e| format!("解析 XML 文件 {} 失败: {}", path.display(), e))?;
+            read_xml_banks(&doc, &key, &mut result, &mut media_names, &mut media_ids);
+        }
+    }
+
+    let mut seen = HashSet::new();
+    media_ids.retain(|id| seen.insert(id.clone()));
+    result.media = media_ids
+        .into_iter()
+        .map(|id| MediaRef { name: media_names.get(&id).cloned().unwrap_or_default(), id })
+        .collect();
+    Ok(result)
+}
+
+/// 字符串或数字字段的文本
+pub fn json_text(value: &Value, key: &str) -> String {
+    match value.get(key) {
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Number(number)) => number.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// 依次取出多个键下数组中的元素，新旧版本的键名不同
+pub fn json_array<'a>(value: &'a Value, keys: &[&str]) -> Vec<&'a Value> {
+    keys.iter()
+        .filter_map(|key| value.get(*key).and_then(Value::as_array))
+        .flatten()
+        .collect()
+}
+
+fn read_json_banks(
+    json: &Value,
+    key: &EventKey,
+    result: &mut BankEvent,
+    media_names: &mut HashMap<String, String>,
+    media_ids: &mut Vec<String>,
+) {
+    let Some(info) = json.get("SoundBanksInfo") else {
+        return;
+    };
+    for media in json_array(info, &["StreamedFiles", "MediaFilesNotInAnyBank"]) {
+        media_names.insert(json_text(media, "Id"), json_text(media, "ShortName"));
+    }
+
+    for bank in json_array(info, &["SoundBanks"]) {
+        for media in json_array(bank, &["Media", "IncludedMemoryFiles", "IncludedPrefetchFiles", "ReferencedStreamedFiles"]) {
+            media_names.insert(json_text(media, "Id"), json_text(media, "ShortName"));
+        }
+
+        for event in json_array(bank, &["Events", "IncludedEvents"]) {
+            let (name, guid, id) = (json_text(event, "Name"), json_text(event, "GUID"), json_text(event, "Id"));
+            if !key.matches(&name, &guid, &id) {
+                continue;
+            }
+            result.banks.push(EventBank {
+                name: json_text(bank, "ShortName"),
+                id: json_text(bank, "Id"),
+                path: json_text(bank, "Path"),
+                language: json_text(bank, "Language"),
+            });
+            if result.summary.is_some() {
+                continue;
+            }
+            result.summary = Some(EventSummary { name, guid, short_id: id, path: json_text(event, "ObjectPath") });
+
+            if let Some(fields) = event.as_object() {
+                for (field, value) in fields {
+                    let Some(action_type) = field.strip_prefix("Action") else {
+                        continue;
+                    };
+                    for target in value.as_array().into_iter().flatten() {
+                        result.actions.push(bank_action(
+                            action_type,
+                            &json_text(target, "Name"),
+                            &json_text(target, "GUID"),
+                            &json_text(target, "Group"),
+                        ));
+                    }
+                }
+            }
+            for media in json_array(event, &["MediaRefs"]) {
+                media_ids.push(json_text(media, "Id"));
+            }
+        }
+    }
+}
+
+fn read_xml_banks(
+    doc: &Document,
+    key: &EventKey,
+    result: &mut BankEvent,
+    media_names: &mut HashMap<String, String>,
+    media_ids: &mut Vec<String>,
+) {
+    let text = |node: &Node, tag: &str| child(node, tag).and_then(|n| n.text()).unwrap_or("").to_string();
+    for file in doc.descendants().filter(|n| n.has_tag_name("File")) {
+        media_names.insert(file.attribute("Id").unwrap_or("").to_string(), text(&file, "ShortName"));
+    }
+
+    for bank in doc.descendants().filter(|n| n.has_tag_name("SoundBank")) {
+        let events = ["Events", "IncludedEvents"].iter().filter_map(|tag| child(&bank, tag));
+        for event in events.flat_map(|list| elements(&list, "Event").collect::<Vec<_>>()) {
+            let name = event.attribute("Name").unwrap_or("");
+            let guid = event.attribute("GUID").unwrap_or("");
+            let id = event.attribute("Id").unwrap_or("");
+            if !key.matches(name, guid, id) {
+                continue;
+            }
+            result.banks.push(EventBank {
+                name: text(&bank, "ShortName"),
+                id: bank.attribute("Id").unwrap_or("").to_string(),
+                path: text(&bank, "Path"),
+                language: bank.attribute("Language").unwrap_or("").to_string(),
+            });
+            if result.summary.is_some() {
+                continue;
+            }
+            result.summary = Some(EventSummary {
+                name: name.to_string(),
+                guid: guid.to_string(),
+                short_id: id.to_string(),
+                path: event.attribute("ObjectPath").unwrap_or("").to_string(),
+            });
+
+            for list in event.children().filter(|n| n.is_element()) {
+                let tag = list.tag_name().name();
+                if let Some(action_type) = tag.strip_prefix("Action") {
+                    for target in list.children().filter(|n| n.is_element()) {
+                        result.actions.push(bank_action(
+                            action_type,
+                            target.attribute("Name").unwrap_or(""),
+                            target.attribute("GUID").unwrap_or(""),
+                            target.attribute("Group").unwrap_or(""),
+                        ));
+                    }
+                } else if tag == "MediaRefs" {
+                    media_ids.extend(list.children().filter_map(|n| n.attribute("Id")).map(str::to_string));
+                }
+            }
+        }
+    }
+}
+
+fn bank_action(action_type: &str, name: &str, guid: &str, group: &str) -> ActionInfo {
+    let target_type = match action_type {
+        "SetState" => "State",
+        "SetSwitch" => "Switch",
+        "PostEvent" => "Event",
+        "Trigger" => "Trigger",
+        "SetFX" => "Effect",
+        _ => "",
+    };
+    ActionInfo {
+        guid: String::new(),
+        short_id: String::new(),
+        action_type: action_type.to_string(),
+        target: Some(ObjectReference {
+            name: "Target".to_string(),
+            target_name: name.to_string(),
+            target_type: target_type.to_string(),
+            target_guid: guid.to_string(),
+            target_path: if group.is_empty() { String::new() } else { format!("{}\\{}", group, name) },
+            resolved: true,
+            embedded: false,
+        }),
+        scope: None,
+        delay: None,
+        fade_time: None,
+        fade_curve: None,
+        properties: Vec::new(),
+    }
+}
+
+/// 列出 Event 的所有动作及其目标、作用范围、延迟和淡入淡出
+///
+/// `event` 为 GUID、ShortID 或名称。优先从工程的 .wwu 读取；工程目录未提供、无法读取或其中没有该 Event 时，
+/// 使用 Bank 目录中 SoundbanksInfo 记录的动作。提供 Bank 目录时同时列出包含该 Event 的 SoundBank。
+#[tauri::command]
+pub fn get_event_report(
+    directory: Option<String>,
+    bank_directory: Option<String>,
+    event: String,
+) -> Result<EventReport, String> {
+    let directory = directory.filter(|d| !d.is_empty());
+    let bank_directory = bank_directory.filter(|d| !d.is_empty());
+    if directory.is_none() && bank_directory.is_none() {
+        return Err("请指定工程目录或 Bank 目录".to_string());
+    }
+
+    let project = match &directory {
+        Some(directory) => match load_project_documents(Path::new(directory)) {
+            Ok(documents) => {
+                let objects = index_project_documents(&documents);
+                project_event_actions(&documents, &objects, &event)
+            }
+            // 有 Bank 目录时仍可从 SoundbanksInfo 读取
+            Err(_) if bank_directory.is_some() => None,
+            Err(e) => return Err(e),
+        },
+        None => None,
+    };
+    let bank = match &bank_directory {
+        Some(directory) => bank_event_info(Path::new(directory), &event)?,
+        None => BankEvent::default(),
+    };
+
+    match (project, bank.summary) {
+        (Some((event, actions)), _) => Ok(EventReport {
+            event,
+            source: "project".to_string(),
+            actions,
+            banks: bank.banks,
+            media: bank.media,
+        }),
+        (None, Some(event)) => Ok(EventReport {
+            event,
+            source: "soundbanks".to_string(),
+            actions: bank.actions,
+            banks: bank.banks,
+            media: bank.media,
+        }),
+        (None, None) => Err(format!("未找到 Event {}", event)),
+    }
+}
@@ -0,0 +1,210 @@
+use std::fs;
+use std::path::Path;
+use rust_xlsxwriter::{Format, Workbook};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// 导出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+    Xlsx,
+}
+
+impl ExportFormat {
+    /// 解析格式名称，可选值: "csv", "json", "markdown" / "md", "xlsx"
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            "markdown" | "md" => Ok(ExportFormat::Markdown),
+            "xlsx" => Ok(ExportFormat::Xlsx),
+            _ => Err(format!("不支持的导出格式: {}，可选值: csv, json, markdown, xlsx", name)),
+        }
+    }
+
+    /// 根据文件扩展名推断格式
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .ok_or_else(|| "无法根据文件扩展名确定导出格式".to_string())?;
+        Self::parse(ext)
+    }
+}
+
+/// SearchResult 的字段顺序，导出时排在最前面
+const PREFERRED_COLUMNS: &[&str] = &["name", "object_type", "guid", "short_id", "media_id"];
+
+/// 待导出的表格：列名 + 原始对象
+///
+/// 列为所有行字段的并集，SearchResult 的字段在前，其余字段依次追加在后面，
+/// 因此带有额外字段的结果也能完整导出。
+pub struct ExportTable {
+    pub columns: Vec<String>,
+    pub rows: Vec<Map<String, Value>>,
+}
+
+impl ExportTable {
+    pub fn new(rows: Vec<Map<String, Value>>) -> Self {
+        let mut columns: Vec<String> = PREFERRED_COLUMNS
+            .iter()
+            // 没有结果时仍输出 SearchResult 的表头
+            .filter(|c| rows.is_empty() || rows.iter().any(|row| row.contains_key(**c)))
+            .map(|c| c.to_string())
+            .collect();
+        for row in &rows {
+            for key in row.keys() {
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+        ExportTable { columns, rows }
+    }
+
+    /// 从任意可序列化的结果列表构建表格
+    pub fn from_serializable<T: Serialize>(results: &[T]) -> Result<Self, String> {
+        let rows = results
+            .iter()
+            .map(|result| match serde_json::to_value(result) {
+                Ok(Value::Object(map)) => Ok(map),
+                Ok(_) => Err("导出的结果必须是对象".to_string()),
+                Err(e) => Err(format!("序列化结果失败: {}", e)),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(rows))
+    }
+
+    /// 单元格文本：字符串原样输出，数组用分号连接，带 `name` 的对象（如所在的 SoundBank）输出名称，其他值输出为 JSON
+    fn cell(&self, row: &Map<String, Value>, column: &str) -> String {
+        match row.get(column) {
+            None | Some(Value::Null) => String::new(),
+            Some(value) => cell_text(value),
+        }
+    }
+
+    /// 渲染为文本格式（CSV、JSON、Markdown）
+    pub fn render(&self, format: ExportFormat) -> Result<String, String> {
+        match format {
+            ExportFormat::Csv => Ok(self.render_csv()),
+            ExportFormat::Json => serde_json::to_string_pretty(&self.rows)
+                .map_err(|e| format!("序列化 JSON 失败: {}", e)),
+            ExportFormat::Markdown => Ok(self.render_markdown()),
+            ExportFormat::Xlsx => Err("XLSX 是二进制格式，只能写入文件".to_string()),
+        }
+    }
+
+    fn render_csv(&self) -> String {
+        let mut out = String::new();
+        let header: Vec<String> = self.columns.iter().map(|c| csv_field(c)).collect();
+        out.push_str(&header.join(","));
+        out.push_str("\r\n");
+        for row in &self.rows {
+            let fields: Vec<String> = self.columns.iter().map(|c| csv_field(&self.cell(row, c))).collect();
+            out.push_str(&fields.join(","));
+            out.push_str("\r\n");
+        }
+        out
+    }
+
+    fn render_markdown(&self) -> String {
+        let mut out = String::new();
+        let header: Vec<String> = self.columns.iter().map(|c| markdown_cell(c)).collect();
+        out.push_str(&format!("| {} |\n", header.join(" | ")));
+        out.push_str(&format!("|{}\n", " --- |".repeat(self.columns.len())));
+        for row in &self.rows {
+            let cells: Vec<String> = self.columns.iter().map(|c| markdown_cell(&self.cell(row, c))).collect();
+            out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        out
+    }
+
+    fn write_xlsx(&self, path: &Path) -> Result<(), String> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        let header_format = Format::new().set_bold();
+        let xlsx_error = |e: rust_xlsxwriter::XlsxError| format!("写入 XLSX 失败: {}", e);
+
+        for (col, column) in self.columns.iter().enumerate() {
+            worksheet
+                .write_string_with_format(0, col as u16, column, &header_format)
+                .map_err(xlsx_error)?;
+        }
+        for (row_index, row) in self.rows.iter().enumerate() {
+            for (col, column) in self.columns.iter().enumerate() {
+                // ID 全部按文本写入，避免 Excel 将大整数转为科学计数法
+                worksheet
+                    .write_string(row_index as u32 + 1, col as u16, self.cell(row, column))
+                    .map_err(xlsx_error)?;
+            }
+        }
+        worksheet.autofit();
+
+        workbook.save(path).map_err(xlsx_error)
+    }
+
+    /// 写入文件
+    pub fn write(&self, format: ExportFormat, path: &Path) -> Result<(), String> {
+        if format == ExportFormat::Xlsx {
+            return self.write_xlsx(path);
+        }
+        let mut contents = self.render(format)?;
+        if format == ExportFormat::Csv {
+            // 带 BOM，Excel 才能正确识别 UTF-8 中文
+            contents.insert(0, '\u{feff}');
+        }
+        fs::write(path, contents).map_err(|e| format!("写入文件失败: {}", e))
+    }
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(cell_text).collect::<Vec<_>>().join("; "),
+        Value::Object(map) if map.get("name").is_some_and(Value::is_string) => cell_text(&map["name"]),
+        other => other.to_string(),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
+
+/// 导出搜索结果
+///
+/// # 参数
+/// * `results` - 当前的搜索结果（SearchResult 及其额外字段）
+/// * `format` - 导出格式，可选值: "csv", "json", "markdown", "xlsx"；为空时根据扩展名推断
+/// * `path` - 导出文件路径
+///
+/// # 返回
+/// * `Ok(usize)` - 导出的行数
+/// * `Err(String)` - 导出失败，返回错误信息
+#[tauri::command]
+pub fn export_results(
+    results: Vec<Map<String, Value>>,
+    format: Option<String>,
+    path: String,
+) -> Result<usize, String> {
+    let path = Path::new(&path);
+    let format = match format.filter(|f| !f.is_empty()) {
+        Some(name) => ExportFormat::parse(&name)?,
+        None => ExportFormat::from_path(path)?,
+    };
+
+    let table = ExportTable::new(results);
+    table.write(format, path)?;
+
+    Ok(table.rows.len())
+}
@@ -0,0 +1,325 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::Path;
+use serde::Serialize;
+
+/// 图的导出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Json,
+    Dot,
+    Mermaid,
+    GraphMl,
+}
+
+impl GraphFormat {
+    /// 解析格式名称，可选值: "json", "dot" / "gv", "mermaid" / "mmd", "graphml"
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "json" => Ok(GraphFormat::Json),
+            "dot" | "gv" => Ok(GraphFormat::Dot),
+            "mermaid" | "mmd" => Ok(GraphFormat::Mermaid),
+            "graphml" => Ok(GraphFormat::GraphMl),
+            _ => Err(format!("不支持的图导出格式: {}，可选值: json, dot, mermaid, graphml", name)),
+        }
+    }
+
+    /// 根据文件扩展名推断格式
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .ok_or_else(|| "无法根据文件扩展名确定导出格式".to_string())?;
+        Self::parse(ext)
+    }
+}
+
+/// 图中的一个对象
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+    /// 节点标识，工程对象为 GUID
+    pub id: String,
+    pub name: String,
+    pub object_type: String,
+    pub short_id: String,
+    pub path: String,
+}
+
+/// 有向边
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphEdge {
+    pub source: String,
+    pub target: String,
+    /// 边的类型，如 `output_bus`
+    pub kind: String,
+    /// 导出时显示的说明
+    pub label: String,
+}
+
+/// 两个节点之间的一条路径
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphPath {
+    /// 路径上的节点，从起点到终点
+    pub nodes: Vec<GraphNode>,
+    /// 相邻节点之间的边，比节点少一个
+    pub edges: Vec<GraphEdge>,
+}
+
+/// 对象之间的有向图，节点按加入顺序保存
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Graph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    #[serde(skip)]
+    positions: HashMap<String, usize>,
+}
+
+impl Graph {
+    /// 加入节点，相同 id 的节点只保留第一个
+    pub fn add_node(&mut self, node: GraphNode) {
+        if !self.positions.contains_key(&node.id) {
+            self.positions.insert(node.id.clone(), self.nodes.len());
+            self.nodes.push(node);
+        }
+    }
+
+    pub fn add_edge(&mut self, edge: GraphEdge) {
+        self.edges.push(edge);
+    }
+
+    pub fn node(&self, id: &str) -> Option<&GraphNode> {
+        self.positions.get(id).map(|&i| &self.nodes[i])
+    }
+
+    /// 能沿边到达指定节点的所有节点（包括该节点本身）及它们之间的边
+    pub fn upstream(&self, id: &str) -> Graph {
+        let mut incoming: HashMap<&str, Vec<&GraphEdge>> = HashMap::new();
+        for edge in &self.edges {
+            incoming.entry(edge.target.as_str()).or_default().push(edge);
+        }
+
+        let mut reached: HashSet<&str> = HashSet::new();
+        let mut queue = VecDeque::new();
+        if self.positions.contains_key(id) {
+            reached.insert(id);
+            queue.push_back(id);
+        }
+        while let Some(current) = queue.pop_front() {
+            for edge in incoming.get(current).into_iter().flatten() {
+                if reached.insert(edge.source.as_str()) {
+                    queue.push_back(edge.source.as_str());
+                }
+            }
+        }
+
+        let mut graph = Graph::default();
+        for node in self.nodes.iter().filter(|n| reached.contains(n.id.as_str())) {
+            graph.add_node(node.clone());
+        }
+        for edge in &self.edges {
+            if reached.contains(edge.source.as_str()) && reached.contains(edge.target.as_str()) {
+                graph.add_edge(edge.clone());
+            }
+        }
+        graph
+    }
+
+    /// 从 `from` 沿边到达 `to` 的不重复经过节点的路径，按长度排序，最多返回最短的 `limit` 条
+    pub fn paths(&self, from: &str, to: &str, limit: usize) -> Vec<GraphPath> {
+        let mut outgoing: HashMap<&str, Vec<&GraphEdge>> = HashMap::new();
+        for edge in &self.edges {
+            outgoing.entry(edge.source.as_str()).or_default().push(edge);
+        }
+
+        // 只沿能到达终点的节点搜索，避免遍历无关的分支
+        let reaching: HashSet<String> = self.upstream(to).nodes.into_iter().map(|n| n.id).collect();
+        let mut search = PathSearch { from, to, outgoing, reaching, steps: Vec::new() };
+        let found = if search.reaching.contains(from) { search.run(limit) } else { Vec::new() };
+
+        found
+            .into_iter()
+            .map(|edges| {
+                let mut nodes: Vec<GraphNode> = self.node(from).cloned().into_iter().collect();
+                nodes.extend(edges.iter().filter_map(|e| self.node(&e.target).cloned()));
+                GraphPath { nodes, edges: edges.into_iter().cloned().collect() }
+            })
+            .collect()
+    }
+
+    /// Graphviz DOT 格式
+    pub fn to_dot(&self, name: &str) -> String {
+        let mut out = format!("digraph \"{}\" {{\n    rankdir=LR;\n    node [shape=box];\n", dot_escape(name));
+        for node in &self.nodes {
+            out.push_str(&format!(
+                "    \"{}\" [label=\"{}\\n{}\"];\n",
+                dot_escape(&node.id),
+                dot_escape(&node.name),
+                dot_escape(&node.object_type)
+            ));
+        }
+        for edge in &self.edges {
+            out.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                dot_escape(&edge.source),
+                dot_escape(&edge.target),
+                dot_escape(&edge.label)
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Mermaid flowchart 格式，节点按顺序编号为 n0、n1……
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart LR\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            out.push_str(&format!(
+                "    n{}[\"{}<br/>{}\"]\n",
+                i,
+                mermaid_escape(&node.name),
+                mermaid_escape(&node.object_type)
+            ));
+        }
+        for edge in &self.edges {
+            let (Some(source), Some(target)) = (self.positions.get(&edge.source), self.positions.get(&edge.target)) else {
+                continue;
+            };
+            if edge.label.is_empty() {
+                out.push_str(&format!("    n{} --> n{}\n", source, target));
+            } else {
+                out.push_str(&format!("    n{} -->|\"{}\"| n{}\n", source, mermaid_escape(&edge.label), target));
+            }
+        }
+        out
+    }
+
+    /// GraphML 格式，节点和边的字段保存为 data 属性
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n",
+            "  <key id=\"type\" for=\"node\" attr.name=\"object_type\" attr.type=\"string\"/>\n",
+            "  <key id=\"short_id\" for=\"node\" attr.name=\"short_id\" attr.type=\"string\"/>\n",
+            "  <key id=\"path\" for=\"node\" attr.name=\"path\" attr.type=\"string\"/>\n",
+            "  <key id=\"kind\" for=\"edge\" attr.name=\"kind\" attr.type=\"string\"/>\n",
+            "  <key id=\"label\" for=\"edge\" attr.name=\"label\" attr.type=\"string\"/>\n",
+            "  <graph id=\"G\" edgedefault=\"directed\">\n",
+        ));
+        for node in &self.nodes {
+            out.push_str(&format!("    <node id=\"{}\">\n", xml_escape(&node.id)));
+            let fields = [("name", &node.name), ("type", &node.object_type), ("short_id", &node.short_id), ("path", &node.path)];
+            for (key, value) in fields {
+                if !value.is_empty() {
+                    out.push_str(&format!("      <data key=\"{}\">{}</data>\n", key, xml_escape(value)));
+                }
+            }
+            out.push_str("    </node>\n");
+        }
+        for (i, edge) in self.edges.iter().enumerate() {
+            out.push_str(&format!(
+                "    <edge id=\"e{}\" source=\"{}\" target=\"{}\">\n      <data key=\"kind\">{}</data>\n",
+                i,
+                xml_escape(&edge.source),
+                xml_escape(&edge.target),
+                xml_escape(&edge.kind)
+            ));
+            if !edge.label.is_empty() {
+                out.push_str(&format!("      <data key=\"label\">{}</data>\n", xml_escape(&edge.label)));
+            }
+            out.push_str("    </edge>\n");
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    /// 按格式生成文本，`name` 为 DOT 中的图名称
+    pub fn render(&self, format: GraphFormat, name: &str) -> Result<String, String> {
+        match format {
+            GraphFormat::Json => serde_json::to_string_pretty(self).map_err(|e| format!("序列化 JSON 失败: {}", e)),
+            GraphFormat::Dot => Ok(self.to_dot(name)),
+            GraphFormat::Mermaid => Ok(self.to_mermaid()),
+            GraphFormat::GraphMl => Ok(self.to_graphml()),
+        }
+    }
+
+    /// 写入文件，格式为空时根据扩展名推断
+    pub fn write(&self, format: Option<&str>, path: &Path, name: &str) -> Result<(), String> {
+        let format = match format.filter(|f| !f.is_empty()) {
+            Some(format) => GraphFormat::parse(format)?,
+            None => GraphFormat::from_path(path)?,
+        };
+        fs::write(path, self.render(format, name)?).map_err(|e| format!("写入文件失败: {}", e))
+    }
+}
+
+/// 广度优先枚举路径，按边数从少到多找到，找到 `limit` 条后即停止
+struct PathSearch<'a> {
+    from: &'a str,
+    to: &'a str,
+    outgoing: HashMap<&'a str, Vec<&'a GraphEdge>>,
+    /// 能到达终点的节点
+    reaching: HashSet<String>,
+    /// 已展开的路径，每项为最后一条边和去掉这条边后的路径的位置，共享相同的前缀
+    steps: Vec<(&'a GraphEdge, Option<usize>)>,
+}
+
+impl<'a> PathSearch<'a> {
+    fn run(&mut self, limit: usize) -> Vec<Vec<&'a GraphEdge>> {
+        let mut found = Vec::new();
+        let mut queue = VecDeque::from([(None, self.from)]);
+        while let Some((step, current)) = queue.pop_front() {
+            if found.len() >= limit {
+                break;
+            }
+            if current == self.to {
+                found.push(self.edges(step));
+                continue;
+            }
+            for &edge in self.outgoing.get(current).into_iter().flatten() {
+                let next = edge.target.as_str();
+                if self.reaching.contains(next) && next != self.from && !self.passes(step, next) {
+                    self.steps.push((edge, step));
+                    queue.push_back((Some(self.steps.len() - 1), next));
+                }
+            }
+        }
+        found
+    }
+
+    /// 路径是否已经经过指定节点（起点除外）
+    fn passes(&self, mut step: Option<usize>, node: &str) -> bool {
+        while let Some(index) = step {
+            let (edge, previous) = self.steps[index];
+            if edge.target == node {
+                return true;
+            }
+            step = previous;
+        }
+        false
+    }
+
+    /// 从起点开始的边
+    fn edges(&self, mut step: Option<usize>) -> Vec<&'a GraphEdge> {
+        let mut edges = Vec::new();
+        while let Some(index) = step {
+            let (edge, previous) = self.steps[index];
+            edges.push(edge);
+            step = previous;
+        }
+        edges.reverse();
+        edges
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
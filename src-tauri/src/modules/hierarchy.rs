@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use rayon::prelude::*;
+use roxmltree::{Document, Node};
+use serde::Serialize;
+use crate::modules::id_resolver::canonical_guid;
+use crate::modules::index::{category_folder_name, is_definition_node, load_project_documents, ProjectDocuments};
+
+/// Project Explorer 中各分类的显示顺序，未列出的分类排在后面
+const CATEGORY_ORDER: &[&str] = &[
+    "AudioObjects",
+    "InteractiveMusic",
+    "Busses",
+    "Events",
+    "DynamicDialogue",
+    "SoundBanks",
+    "Switches",
+    "States",
+    "GameParameters",
+    "Triggers",
+    "Effects",
+    "Attenuations",
+    "Conversions",
+    "AudioDevices",
+];
+
+/// 不在 Project Explorer 中显示的子对象
+const HIDDEN_TYPES: &[&str] = &["AudioFileSource", "SourcePlugin", "ExternalSource"];
+
+/// 工程层级中的一个对象
+#[derive(Debug, Clone, Serialize)]
+pub struct HierarchyNode {
+    pub name: String,
+    pub object_type: String,
+    pub guid: String,
+    pub short_id: String,
+    pub path: String,
+    /// 上级对象的 GUID，分类下的顶层工作单元为空
+    pub parent_guid: String,
+    pub child_count: usize,
+}
+
+/// 一个分类及其顶层工作单元
+#[derive(Debug, Clone, Serialize)]
+pub struct HierarchyCategory {
+    /// Project Explorer 中的目录名称，如 `Actor-Mixer Hierarchy`
+    pub name: String,
+    /// .wwu 中的分类节点名称，如 `AudioObjects`
+    pub category: String,
+    pub children: Vec<HierarchyNode>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    name: String,
+    object_type: String,
+    short_id: String,
+    category: String,
+    parent: Option<String>,
+    children: Vec<String>,
+}
+
+/// 单个 .wwu 文件中的对象
+#[derive(Default)]
+struct FileEntries {
+    /// 文件中定义的对象
+    entries: Vec<(String, Entry)>,
+    /// 引用其他文件中工作单元的节点（PersistMode="Reference"）：工作单元 GUID 和所在的上级对象
+    work_unit_refs: Vec<(String, Entry)>,
+    /// 文件的根工作单元
+    root: Option<String>,
+}
+
+/// 从 .wwu 文件构建的工程层级
+///
+/// 嵌套的工作单元保存在单独的文件中，上级文件只记录一个引用节点，构建时按 GUID 将两者连接起来。
+#[derive(Debug, Default)]
+pub struct ProjectHierarchy {
+    entries: HashMap<String, Entry>,
+    categories: Vec<(String, Vec<String>)>,
+}
+
+impl ProjectHierarchy {
+    pub fn build(documents: &ProjectDocuments) -> Self {
+        let files: Vec<FileEntries> = documents.files.par_iter().map(|file| read_file(&file.contents)).collect();
+
+        let mut hierarchy = ProjectHierarchy::default();
+        let mut work_unit_parents = HashMap::new();
+        for file in &files {
+            for (guid, entry) in &file.entries {
+                hierarchy.entries.entry(guid.clone()).or_insert_with(|| entry.clone());
+            }
+            for (guid, stub) in &file.work_unit_refs {
+                work_unit_parents.insert(guid.clone(), stub.parent.clone());
+            }
+        }
+
+        // 引用节点对应的文件不存在时保留引用节点本身
+        for file in &files {
+            for (guid, stub) in &file.work_unit_refs {
+                hierarchy.entries.entry(guid.clone()).or_insert_with(|| stub.clone());
+            }
+        }
+
+        let mut top_level: HashMap<String, Vec<String>> = HashMap::new();
+        for root in files.iter().filter_map(|f| f.root.as_ref()) {
+            match work_unit_parents.get(root).cloned().flatten() {
+                Some(parent) => {
+                    if let Some(entry) = hierarchy.entries.get_mut(root) {
+                        entry.parent = Some(parent);
+                    }
+                }
+                None => {
+                    let category = hierarchy.entries[root].category.clone();
+                    top_level.entry(category).or_default().push(root.clone());
+                }
+            }
+        }
+
+        for list in top_level.values_mut() {
+            list.sort_by_key(|guid| hierarchy.entries[guid].name.to_lowercase());
+            list.dedup();
+        }
+        let mut categories: Vec<(String, Vec<String>)> = top_level.into_iter().collect();
+        categories.sort_by_key(|(category, _)| {
+            let order = CATEGORY_ORDER.iter().position(|c| c == category).unwrap_or(CATEGORY_ORDER.len());
+            (order, category.clone())
+        });
+        hierarchy.categories = categories;
+        hierarchy
+    }
+
+    /// 各分类及其顶层工作单元
+    pub fn roots(&self) -> Vec<HierarchyCategory> {
+        self.categories
+            .iter()
+            .map(|(category, work_units)| HierarchyCategory {
+                name: category_folder_name(category).to_string(),
+                category: category.clone(),
+                children: work_units.iter().filter_map(|guid| self.node(guid)).collect(),
+            })
+            .collect()
+    }
+
+    pub fn node(&self, guid: &str) -> Option<HierarchyNode> {
+        let guid = canonical_guid(guid);
+        let entry = self.entries.get(&guid)?;
+        Some(HierarchyNode {
+            name: entry.name.clone(),
+            object_type: entry.object_type.clone(),
+            path: self.path(&guid),
+            parent_guid: entry.parent.clone().unwrap_or_default(),
+            child_count: entry.children.len(),
+            short_id: entry.short_id.clone(),
+            guid,
+        })
+    }
+
+    pub fn children(&self, guid: &str) -> Option<Vec<HierarchyNode>> {
+        let entry = self.entries.get(&canonical_guid(guid))?;
+        Some(entry.children.iter().filter_map(|child| self.node(child)).collect())
+    }
+
+    pub fn parent(&self, guid: &str) -> Option<Option<HierarchyNode>> {
+        let entry = self.entries.get(&canonical_guid(guid))?;
+        Some(entry.parent.as_ref().and_then(|parent| self.node(parent)))
+    }
+
+    /// 从分类目录开始的完整路径
+    fn path(&self, guid: &str) -> String {
+        let mut names = Vec::new();
+        let mut current = self.entries.get(guid);
+        let mut category = "";
+        while let Some(entry) = current {
+            names.push(entry.name.as_str());
+            category = &entry.category;
+            current = entry.parent.as_ref().and_then(|parent| self.entries.get(parent));
+            // 防止引用关系成环
+            if names.len() > self.entries.len() {
+                break;
+            }
+        }
+        names.push(category_folder_name(category));
+        names.reverse();
+        format!("\\{}", names.join("\\"))
+    }
+}
+
+fn read_file(contents: &str) -> FileEntries {
+    let mut file = FileEntries::default();
+    let Ok(doc) = Document::parse(contents) else {
+        return file;
+    };
+    for category in doc.root_element().children().filter(|n| n.is_element()) {
+        let name = category.tag_name().name();
+        for root in category.children().filter(is_definition_node) {
+            file.root.get_or_insert_with(|| canonical_guid(root.attribute("ID").unwrap_or("")));
+            read_node(&root, name, None, &mut file);
+        }
+    }
+    file
+}
+
+fn read_node(node: &Node, category: &str, parent: Option<&str>, file: &mut FileEntries) -> String {
+    let guid = canonical_guid(node.attribute("ID").unwrap_or(""));
+    let mut entry = Entry {
+        name: node.attribute("Name").unwrap_or("").to_string(),
+        object_type: node.tag_name().name().to_string(),
+        short_id: node.attribute("ShortID").unwrap_or("").to_string(),
+        category: category.to_string(),
+        parent: parent.map(str::to_string),
+        children: Vec::new(),
+    };
+
+    if node.has_tag_name("WorkUnit") && node.attribute("PersistMode") == Some("Reference") {
+        file.work_unit_refs.push((guid.clone(), entry));
+        return guid;
+    }
+
+    if let Some(list) = node.children().find(|n| n.has_tag_name("ChildrenList")) {
+        for child in list.children().filter(is_definition_node) {
+            if !HIDDEN_TYPES.contains(&child.tag_name().name()) {
+                entry.children.push(read_node(&child, category, Some(&guid), file));
+            }
+        }
+    }
+    file.entries.push((guid.clone(), entry));
+    guid
+}
+
+/// 最近一次构建的工程层级，展开子节点时复用
+static HIERARCHY_CACHE: Mutex<Option<(PathBuf, Arc<ProjectHierarchy>)>> = Mutex::new(None);
+
+/// 读取工程并更新缓存
+pub fn load_hierarchy(directory: &Path) -> Result<Arc<ProjectHierarchy>, String> {
+    let documents = load_project_documents(directory)?;
+    let hierarchy = Arc::new(ProjectHierarchy::build(&documents));
+    *HIERARCHY_CACHE.lock().unwrap_or_else(|e| e.into_inner()) = Some((directory.to_path_buf(), hierarchy.clone()));
+    Ok(hierarchy)
+}
+
+/// 使用缓存的工程层级，目录不同或尚未构建时重新读取
+pub fn cached_hierarchy(directory: &Path) -> Result<Arc<ProjectHierarchy>, String> {
+    let cache = HIERARCHY_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((cached_dir, hierarchy)) = cache.as_ref() {
+        if cached_dir == directory {
+            return Ok(hierarchy.clone());
+        }
+    }
+    drop(cache);
+    load_hierarchy(directory)
+}
+
+/// 获取工程的各分类及其顶层工作单元，每次调用重新读取工程
+#[tauri::command]
+pub fn get_roots(directory: String) -> Result<Vec<HierarchyCategory>, String> {
+    Ok(load_hierarchy(Path::new(&directory))?.roots())
+}
+
+/// 获取对象的直接子对象
+#[tauri::command]
+pub fn get_children(directory: String, guid: String) -> Result<Vec<HierarchyNode>, String> {
+    cached_hierarchy(Path::new(&directory))?
+        .children(&guid)
+        .ok_or_else(|| format!("工程中未找到对象 {}", guid))
+}
+
+/// 获取对象的上级对象，分类下的顶层工作单元返回 None
+#[tauri::command]
+pub fn get_parent(directory: String, guid: String) -> Result<Option<HierarchyNode>, String> {
+    cached_hierarchy(Path::new(&directory))?
+        .parent(&guid)
+        .ok_or_else(|| format!("工程中未找到对象 {}", guid))
+}
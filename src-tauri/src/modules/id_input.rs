@@ -0,0 +1,238 @@
+use serde::Serialize;
+use crate::modules::id_resolver::{canonical_guid, parse_integer_token, TokenKind};
+use crate::modules::query::{is_structured_query, Query};
+use crate::modules::utils::is_valid_guid;
+
+/// 输入的 ID 形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum InputForm {
+    /// 带连字符的 GUID，可带花括号
+    Guid,
+    /// 32 位十六进制、不带连字符的 GUID
+    DashlessGuid,
+    /// 内存转储中按 GUID 结构体字节序排列的 32 位十六进制
+    ByteSwappedGuid,
+    Decimal,
+    Hex,
+    NegativeInt32,
+    /// 无法识别为完整 ID，按原样作为部分 ID 搜索
+    Partial,
+    /// 结构化查询，如 `type:Event name:Play_*`
+    Query,
+}
+
+/// 输入 ID 的一种解释
+#[derive(Debug, Clone, Serialize)]
+pub struct IdInterpretation {
+    pub form: InputForm,
+    /// 规范化后用于搜索的值：GUID 为带花括号的大写形式，整数为无符号十进制
+    pub value: String,
+    /// 该解释适用的 ID 类型
+    pub id_types: Vec<String>,
+    /// 显示给用户的说明
+    pub description: String,
+}
+
+impl IdInterpretation {
+    /// 按原样作为部分 ID 搜索所有类型，用于正则表达式等不需要规范化的输入
+    pub fn partial(input: &str) -> Self {
+        Self::new(InputForm::Partial, input.to_string(), ALL_TYPES, format!("部分 ID {}", input))
+    }
+
+    /// 结构化查询，`description` 为解析后的条件说明
+    pub fn query(input: &str, description: String) -> Self {
+        Self::new(InputForm::Query, input.to_string(), ALL_TYPES, description)
+    }
+
+    fn new(form: InputForm, value: String, id_types: &[&str], description: String) -> Self {
+        IdInterpretation {
+            form,
+            value,
+            id_types: id_types.iter().map(|t| t.to_string()).collect(),
+            description,
+        }
+    }
+}
+
+const GUID_TYPES: &[&str] = &["GUID"];
+const INTEGER_TYPES: &[&str] = &["ShortID", "MediaID"];
+const ALL_TYPES: &[&str] = &["GUID", "ShortID", "MediaID"];
+
+/// 将用户输入的 ID 规范化，返回所有可能的解释
+///
+/// 支持 `{GUID}`、不带花括号的 GUID、32 位十六进制 GUID（同时按内存字节序解释）、
+/// `0x` 十六进制、有符号 int32 和十进制；其余输入按原样作为部分 ID。
+pub fn normalize_id_input(input: &str) -> Vec<IdInterpretation> {
+    let input = input.trim();
+
+    if is_valid_guid(input) {
+        let guid = canonical_guid(input);
+        let description = if guid == input {
+            format!("GUID {}", guid)
+        } else {
+            format!("GUID {} → {}", input, guid)
+        };
+        return vec![IdInterpretation::new(InputForm::Guid, guid, GUID_TYPES, description)];
+    }
+
+    // 内存转储中常见以空格分隔的字节
+    let hex: String = input
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if hex.len() == 32 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        let guid = dashless_guid(&hex);
+        let swapped = byte_swapped_guid(&hex);
+        let mut interpretations = vec![IdInterpretation::new(
+            InputForm::DashlessGuid,
+            guid.clone(),
+            GUID_TYPES,
+            format!("无连字符的 GUID → {}", guid),
+        )];
+        if swapped != guid {
+            interpretations.push(IdInterpretation::new(
+                InputForm::ByteSwappedGuid,
+                swapped.clone(),
+                GUID_TYPES,
+                format!("内存字节序的 GUID → {}", swapped),
+            ));
+        }
+        return interpretations;
+    }
+
+    if let Some(token) = parse_integer_token(input) {
+        return match token.kind {
+            TokenKind::Hex => vec![IdInterpretation::new(
+                InputForm::Hex,
+                token.value.clone(),
+                INTEGER_TYPES,
+                format!("十六进制 {} → {}", input, token.value),
+            )],
+            TokenKind::NegativeInt32 => vec![IdInterpretation::new(
+                InputForm::NegativeInt32,
+                token.value.clone(),
+                INTEGER_TYPES,
+                format!("有符号 int32 {} → {}", input, token.value),
+            )],
+            // 十进制数字也可能是 GUID 的片段，GUID 按原始输入搜索
+            _ => {
+                let mut interpretations = vec![IdInterpretation::new(
+                    InputForm::Decimal,
+                    token.value.clone(),
+                    INTEGER_TYPES,
+                    if token.value == input {
+                        format!("十进制 {}", input)
+                    } else {
+                        format!("十进制 {} → {}", input, token.value)
+                    },
+                )];
+                interpretations.push(IdInterpretation::new(
+                    InputForm::Partial,
+                    input.to_string(),
+                    GUID_TYPES,
+                    format!("部分 GUID {}", input),
+                ));
+                interpretations
+            }
+        };
+    }
+
+    vec![IdInterpretation::partial(input)]
+}
+
+/// 为 32 位十六进制插入连字符
+fn dashless_guid(hex: &str) -> String {
+    canonical_guid(&format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+/// 按 GUID 结构体的内存布局解释 16 个字节：Data1、Data2、Data3 为小端序，Data4 原样
+fn byte_swapped_guid(hex: &str) -> String {
+    let bytes: Vec<&str> = (0..16).map(|i| &hex[i * 2..i * 2 + 2]).collect();
+    let swapped: String = [3, 2, 1, 0, 5, 4, 7, 6, 8, 9, 10, 11, 12, 13, 14, 15]
+        .iter()
+        .map(|&i| bytes[i])
+        .collect();
+    dashless_guid(&swapped)
+}
+
+/// 解析输入的 ID，返回规范化后的所有解释
+///
+/// # 参数
+/// * `id_string` - 用户输入的 ID 字符串
+///
+/// # 返回
+/// * `Ok(Vec<IdInterpretation>)` - 输入的所有解释
+/// * `Err(String)` - 输入为空或结构化查询有语法错误
+#[tauri::command]
+pub fn interpret_id_input(id_string: String) -> Result<Vec<IdInterpretation>, String> {
+    if id_string.trim().is_empty() {
+        return Err("请输入查询条件".to_string());
+    }
+    if is_structured_query(&id_string) {
+        let query = Query::parse(&id_string).map_err(|e| e.to_string())?;
+        return Ok(vec![IdInterpretation::query(id_string.trim(), query.describe())]);
+    }
+    Ok(normalize_id_input(&id_string))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forms(input: &str) -> Vec<(InputForm, String)> {
+        normalize_id_input(input).into_iter().map(|i| (i.form, i.value)).collect()
+    }
+
+    #[test]
+    fn normalizes_guids_with_and_without_dashes() {
+        let guid = "{6E1B9F2A-1C3D-4E5F-8A9B-0C1D2E3F4A5B}";
+        assert_eq!(forms("6e1b9f2a-1c3d-4e5f-8a9b-0c1d2e3f4a5b"), [(InputForm::Guid, guid.to_string())]);
+        assert_eq!(normalize_id_input(guid)[0].description, format!("GUID {}", guid));
+
+        // 无连字符的 32 位十六进制同时按内存字节序解释
+        let expected = [
+            (InputForm::DashlessGuid, guid.to_string()),
+            (InputForm::ByteSwappedGuid, "{2A9F1B6E-3D1C-5F4E-8A9B-0C1D2E3F4A5B}".to_string()),
+        ];
+        assert_eq!(forms("6E1B9F2A1C3D4E5F8A9B0C1D2E3F4A5B"), expected);
+        assert_eq!(forms("{6e 1b 9f 2a 1c 3d 4e 5f 8a 9b 0c 1d 2e 3f 4a 5b}"), expected);
+    }
+
+    #[test]
+    fn swaps_only_the_first_three_guid_fields() {
+        assert_eq!(byte_swapped_guid("00112233445566778899AABBCCDDEEFF"), "{33221100-5544-7766-8899-AABBCCDDEEFF}");
+        // 交换后不变时只有一种解释
+        assert_eq!(forms("11111111222222223333333333333333").len(), 1);
+    }
+
+    #[test]
+    fn converts_negative_int32_and_hex_to_unsigned() {
+        assert_eq!(forms("-882743616"), [(InputForm::NegativeInt32, "3412223680".to_string())]);
+        assert_eq!(forms("-1"), [(InputForm::NegativeInt32, "4294967295".to_string())]);
+        assert_eq!(normalize_id_input("-1")[0].id_types, INTEGER_TYPES);
+        assert_eq!(forms("0xCB7E2D80"), [(InputForm::Hex, "3414044032".to_string())]);
+        // 超出 int32 范围时不是 ID
+        assert_eq!(forms("-2147483649"), [(InputForm::Partial, "-2147483649".to_string())]);
+    }
+
+    #[test]
+    fn searches_decimals_as_ids_and_partial_guids() {
+        let interpretations = normalize_id_input(" 00123 ");
+        assert_eq!(interpretations[0].description, "十进制 00123 → 123");
+        assert_eq!(
+            forms("00123"),
+            [(InputForm::Decimal, "123".to_string()), (InputForm::Partial, "00123".to_string())]
+        );
+        assert_eq!(interpretations[1].id_types, GUID_TYPES);
+        assert!(interpret_id_input("  ".to_string()).is_err());
+    }
+}
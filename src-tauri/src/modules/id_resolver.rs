@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::OnceLock;
+use regex::Regex;
+use serde::Serialize;
+use crate::modules::index::{build_bank_index, build_project_index, IndexedObject};
+
+/// 从文本中提取到的 ID 形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum TokenKind {
+    Guid,
+    Decimal,
+    Hex,
+    NegativeInt32,
+}
+
+/// 从文本中提取到的一个候选 ID
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdToken {
+    pub kind: TokenKind,
+    /// 规范化后的值：GUID 为带花括号的大写形式，整数为无符号十进制
+    pub value: String,
+}
+
+/// 一个命中的对象
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedMatch {
+    /// 命中的索引来源: "wwise" 或 "bank"
+    pub source: String,
+    /// 命中的 ID 类型: "GUID"、"ShortID" 或 "MediaID"
+    pub id_type: String,
+    #[serde(flatten)]
+    pub object: IndexedObject,
+}
+
+/// 文本中一个候选 ID 的解析结果
+#[derive(Debug, Serialize)]
+pub struct TokenResolution {
+    /// 第一次出现时的原始文本
+    pub token: String,
+    pub kind: TokenKind,
+    pub value: String,
+    /// 在文本中出现的次数
+    pub occurrences: usize,
+    pub matches: Vec<ResolvedMatch>,
+}
+
+fn guid_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\{?\b[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12}\b\}?")
+            .expect("GUID 正则表达式无效")
+    })
+}
+
+/// 将 GUID 规范化为带花括号的大写形式
+pub fn canonical_guid(guid: &str) -> String {
+    format!("{{{}}}", guid.trim_start_matches('{').trim_end_matches('}').to_uppercase())
+}
+
+/// 将一个整数文本解析为候选 ID（十进制、十六进制或有符号 int32）
+pub fn parse_integer_token(text: &str) -> Option<IdToken> {
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        return Some(IdToken { kind: TokenKind::Hex, value: value.to_string() });
+    }
+    if text.starts_with('-') {
+        // 有符号 int32 溢出的 ShortID，按补码还原
+        let value = text.parse::<i32>().ok()?;
+        return Some(IdToken { kind: TokenKind::NegativeInt32, value: (value as u32).to_string() });
+    }
+    let value = text.parse::<u32>().ok()?;
+    Some(IdToken { kind: TokenKind::Decimal, value: value.to_string() })
+}
+
+/// 将单个 ID 文本（GUID 或整数）解析为候选 ID
+pub fn parse_id_token(text: &str) -> Option<IdToken> {
+    let text = text.trim();
+    if guid_regex().find(text).is_some_and(|m| m.len() == text.len()) {
+        return Some(IdToken { kind: TokenKind::Guid, value: canonical_guid(text) });
+    }
+    parse_integer_token(text)
+}
+
+/// 从任意文本中提取所有候选 ID，返回 (原始文本, 候选 ID) 列表，保持出现顺序
+pub fn extract_id_tokens(text: &str) -> Vec<(String, IdToken)> {
+    find_id_tokens(text)
+        .into_iter()
+        .map(|(range, token)| (text[range].to_string(), token))
+        .collect()
+}
+
+/// 查找文本中所有候选 ID 及其位置，按出现顺序排列
+pub fn find_id_tokens(text: &str) -> Vec<(Range<usize>, IdToken)> {
+    let mut found = Vec::new();
+    let mut guid_ranges = Vec::new();
+
+    for m in guid_regex().find_iter(text) {
+        guid_ranges.push(m.range());
+        found.push((
+            m.range(),
+            IdToken { kind: TokenKind::Guid, value: canonical_guid(m.as_str()) },
+        ));
+    }
+
+    for (start, raw) in scan_integers(text) {
+        // GUID 内部的数字段不作为整数
+        if guid_ranges.iter().any(|r| r.contains(&start)) {
+            continue;
+        }
+        if let Some(token) = parse_integer_token(raw) {
+            found.push((start..start + raw.len(), token));
+        }
+    }
+
+    found.sort_by_key(|(range, _)| range.start);
+    found
+}
+
+/// 扫描文本中独立的整数（十进制、0x 十六进制、负数），返回 (起始位置, 原始文本)
+///
+/// 紧挨字母、数字、下划线或小数点的数字不算独立整数，例如 `Play_01`、`v2`、`1.5`；
+/// 连字符只有前面不是字母数字时才视为负号，因此日期 `2024-01-02` 不会产生负数。
+fn scan_integers(text: &str) -> Vec<(usize, &str)> {
+    let bytes = text.as_bytes();
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let mut integers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(|b| b.is_ascii_digit())
+            && (i == 0 || !is_word(bytes[i - 1]));
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let digits_start = if negative { i + 1 } else { i };
+        let preceded_ok = start == 0 || !(is_word(bytes[start - 1]) || bytes[start - 1] == b'.');
+
+        let is_hex = !negative
+            && bytes[digits_start] == b'0'
+            && matches!(bytes.get(digits_start + 1), Some(b'x') | Some(b'X'));
+        let mut end = if is_hex { digits_start + 2 } else { digits_start };
+        while end < bytes.len()
+            && (if is_hex { bytes[end].is_ascii_hexdigit() } else { bytes[end].is_ascii_digit() })
+        {
+            end += 1;
+        }
+        // 完整跳过当前单词，避免把 123abc 的后半部分当作新数字
+        let mut word_end = end;
+        while word_end < bytes.len() && is_word(bytes[word_end]) {
+            word_end += 1;
+        }
+
+        let followed_by_decimal = bytes.get(end) == Some(&b'.')
+            && bytes.get(end + 1).is_some_and(|b| b.is_ascii_digit());
+        if preceded_ok && word_end == end && !followed_by_decimal {
+            integers.push((start, &text[start..end]));
+        }
+        i = word_end.max(start + 1);
+    }
+
+    integers
+}
+
+/// 工程索引和 Bank 索引上的 ID 查找表
+#[derive(Default)]
+pub struct IdResolver {
+    sources: Vec<(String, Vec<IndexedObject>)>,
+    /// 规范化的值 -> (来源下标, 对象下标, ID 类型)
+    lookup: HashMap<String, Vec<(usize, usize, &'static str)>>,
+}
+
+impl IdResolver {
+    /// 根据已配置的工程目录和 Bank 目录构建查找表，未配置的来源会被跳过
+    pub fn load(project_dir: Option<&str>, bank_dir: Option<&str>) -> Result<Self, String> {
+        let mut resolver = IdResolver::default();
+        if let Some(dir) = project_dir.filter(|d| !d.is_empty()) {
+            resolver.add_source("wwise", build_project_index(Path::new(dir))?.objects);
+        }
+        if let Some(dir) = bank_dir.filter(|d| !d.is_empty()) {
+            resolver.add_source("bank", build_bank_index(Path::new(dir))?);
+        }
+        if resolver.sources.is_empty() {
+            return Err("请至少配置 Wwise 工程目录或 Bank 目录".to_string());
+        }
+        Ok(resolver)
+    }
+
+    /// 添加一个索引来源
+    pub fn add_source(&mut self, source: &str, objects: Vec<IndexedObject>) {
+        let source_index = self.sources.len();
+        for (object_index, object) in objects.iter().enumerate() {
+            let keys = [
+                (canonical_guid(&object.guid), "GUID", !object.guid.is_empty()),
+                (object.short_id.clone(), "ShortID", !object.short_id.is_empty()),
+            ];
+            let media_keys = object.media_ids.iter().map(|id| (id.clone(), "MediaID", true));
+            for (key, id_type, present) in keys.into_iter().chain(media_keys) {
+                if present {
+                    self.lookup
+                        .entry(key)
+                        .or_default()
+                        .push((source_index, object_index, id_type));
+                }
+            }
+        }
+        self.sources.push((source.to_string(), objects));
+    }
+
+    /// 查找与候选 ID 完全相同的对象
+    pub fn resolve(&self, token: &IdToken) -> Vec<ResolvedMatch> {
+        let Some(hits) = self.lookup.get(&token.value) else {
+            return Vec::new();
+        };
+        hits.iter()
+            // GUID 只与 GUID 匹配，整数只与 ShortID / MediaID 匹配
+            .filter(|(.., id_type)| (*id_type == "GUID") == (token.kind == TokenKind::Guid))
+            .map(|(source_index, object_index, id_type)| {
+                let (source, objects) = &self.sources[*source_index];
+                ResolvedMatch {
+                    source: source.clone(),
+                    id_type: id_type.to_string(),
+                    object: objects[*object_index].clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// 提取文本中的所有 GUID 和整数 ID，并在工程索引和 Bank 索引中一次性解析
+///
+/// # 参数
+/// * `text` - 任意文本（日志、崩溃报告等）
+/// * `project_dir` - Wwise 工程目录，可为空
+/// * `bank_dir` - Bank 目录，可为空
+///
+/// # 返回
+/// * `Ok(Vec<TokenResolution>)` - 每个不同候选 ID 的解析结果，按首次出现顺序排列
+/// * `Err(String)` - 解析失败，返回错误信息
+#[tauri::command]
+pub fn resolve_ids_in_text(
+    text: String,
+    project_dir: Option<String>,
+    bank_dir: Option<String>,
+) -> Result<Vec<TokenResolution>, String> {
+    let resolver = IdResolver::load(project_dir.as_deref(), bank_dir.as_deref())?;
+
+    let mut resolutions: Vec<TokenResolution> = Vec::new();
+    let mut positions: HashMap<IdToken, usize> = HashMap::new();
+
+    for (raw, token) in extract_id_tokens(&text) {
+        if let Some(&pos) = positions.get(&token) {
+            resolutions[pos].occurrences += 1;
+            continue;
+        }
+        positions.insert(token.clone(), resolutions.len());
+        resolutions.push(TokenResolution {
+            token: raw,
+            kind: token.kind,
+            matches: resolver.resolve(&token),
+            value: token.value,
+            occurrences: 1,
+        });
+    }
+
+    Ok(resolutions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(kind: TokenKind, value: &str) -> IdToken {
+        IdToken { kind, value: value.to_string() }
+    }
+
+    #[test]
+    fn parses_hex_and_signed_int32() {
+        assert_eq!(parse_integer_token("0x7B"), Some(token(TokenKind::Hex, "123")));
+        assert_eq!(parse_integer_token("0XFFFFFFFF"), Some(token(TokenKind::Hex, "4294967295")));
+        assert_eq!(parse_integer_token("0x100000000"), None);
+        // int32 溢出的 ShortID 按补码还原为 uint32
+        assert_eq!(parse_integer_token("-1"), Some(token(TokenKind::NegativeInt32, "4294967295")));
+        assert_eq!(parse_integer_token("-882743616"), Some(token(TokenKind::NegativeInt32, "3412223680")));
+        assert_eq!(parse_integer_token("-2147483648"), Some(token(TokenKind::NegativeInt32, "2147483648")));
+        assert_eq!(parse_integer_token("-2147483649"), None);
+        assert_eq!(parse_integer_token("4294967296"), None);
+        assert_eq!(parse_id_token(" 3412223680 "), Some(token(TokenKind::Decimal, "3412223680")));
+    }
+
+    #[test]
+    fn scans_only_standalone_integers() {
+        let text = "Play_01 v2 1.5 2024-01-02 id=123 (-45) 0x1F 99abc end.7";
+        let found: Vec<&str> = scan_integers(text).into_iter().map(|(_, raw)| raw).collect();
+        assert_eq!(found, ["2024", "01", "02", "123", "-45", "0x1F"]);
+        // 连字符前是字母时不是负号
+        assert_eq!(scan_integers("-7 x-8"), [(0, "-7"), (5, "8")]);
+    }
+
+    #[test]
+    fn extracts_guids_and_ids_from_log_lines() {
+        let line = "[Wwise] PostEvent 3412223680 failed on {6e1b9f2a-1c3d-4e5f-8a9b-0c1d2e3f4a5b}, bank=-1 media 0x2A";
+        let tokens = extract_id_tokens(line);
+        let raw: Vec<&str> = tokens.iter().map(|(raw, _)| raw.as_str()).collect();
+        // GUID 中的数字段不作为整数
+        assert_eq!(raw, ["3412223680", "{6e1b9f2a-1c3d-4e5f-8a9b-0c1d2e3f4a5b}", "-1", "0x2A"]);
+        assert_eq!(tokens[1].1, token(TokenKind::Guid, "{6E1B9F2A-1C3D-4E5F-8A9B-0C1D2E3F4A5B}"));
+        assert_eq!(tokens[2].1, token(TokenKind::NegativeInt32, "4294967295"));
+        assert_eq!(tokens[3].1, token(TokenKind::Hex, "42"));
+
+        // 没有花括号的 GUID 同样识别
+        let bare = extract_id_tokens("guid=6E1B9F2A-1C3D-4E5F-8A9B-0C1D2E3F4A5B");
+        assert_eq!(bare.len(), 1);
+        assert_eq!(bare[0].1.kind, TokenKind::Guid);
+    }
+}
@@ -0,0 +1,692 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use crate::modules::index::{build_bank_index, build_project_index, IndexedObject};
+use crate::modules::id_input::{normalize_id_input, IdInterpretation, InputForm};
+use crate::modules::matcher::{IdMatcher, MatchMode, MatchRank};
+use crate::modules::types::{SearchResponse, SearchResult};
+
+/// Wwise_IDs.h 中的一个常量
+#[derive(Debug, Clone)]
+pub struct IdConstant {
+    /// 命名空间路径，如 `["AK", "EVENTS", "PLAY_CLICK"]`
+    pub path: Vec<String>,
+    pub id: u32,
+}
+
+impl IdConstant {
+    /// C++ 风格的完整名称，如 `AK::EVENTS::PLAY_CLICK`
+    pub fn full_name(&self) -> String {
+        self.path.join("::")
+    }
+
+    /// 根据命名空间推断对应的 Wwise 对象类型
+    pub fn object_type(&self) -> &'static str {
+        let path: Vec<&str> = self.path.iter().map(|s| s.as_str()).collect();
+        match path.as_slice() {
+            ["AK", category, _] => CATEGORIES
+                .iter()
+                .find(|(ns, _)| ns == category)
+                .map(|(_, object_type)| *object_type)
+                .unwrap_or("Constant"),
+            ["AK", category, _, "GROUP"] => GROUP_CATEGORIES
+                .iter()
+                .find(|(ns, ..)| ns == category)
+                .map(|(_, group_type, ..)| *group_type)
+                .unwrap_or("Constant"),
+            ["AK", category, _, child_ns, _] => GROUP_CATEGORIES
+                .iter()
+                .find(|(ns, _, child, _)| ns == category && child == child_ns)
+                .map(|(.., child_type)| *child_type)
+                .unwrap_or("Constant"),
+            _ => "Constant",
+        }
+    }
+}
+
+/// Wwise_IDs.h 的命名空间与对象类型对应关系
+const CATEGORIES: &[(&str, &str)] = &[
+    ("EVENTS", "Event"),
+    ("GAME_PARAMETERS", "GameParameter"),
+    ("TRIGGERS", "Trigger"),
+    ("BANKS", "SoundBank"),
+    ("BUSSES", "Bus"),
+    ("AUX_BUSSES", "AuxBus"),
+    ("AUDIO_DEVICES", "AudioDevice"),
+    ("EXTERNAL_SOURCES", "ExternalSource"),
+];
+
+/// 带分组的命名空间：(命名空间, 分组类型, 子命名空间, 子对象类型)
+const GROUP_CATEGORIES: &[(&str, &str, &str, &str)] = &[
+    ("STATES", "StateGroup", "STATE", "State"),
+    ("SWITCHES", "SwitchGroup", "SWITCH", "Switch"),
+];
+
+/// 解析 Wwise_IDs.h 的内容
+///
+/// 只识别 `namespace X { ... }` 和 `static const AkUniqueID NAME = 123U;` 形式，
+/// 注释和预处理指令会被忽略。
+pub fn parse_ids_header(contents: &str) -> Vec<IdConstant> {
+    let tokens = tokenize_header(contents);
+    let mut constants = Vec::new();
+    let mut scopes: Vec<String> = Vec::new();
+    let mut pending_namespace: Option<String> = None;
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match tokens[i].as_str() {
+            "namespace" => {
+                pending_namespace = tokens.get(i + 1).cloned();
+                i += 2;
+                continue;
+            }
+            "{" => scopes.push(pending_namespace.take().unwrap_or_default()),
+            "}" => {
+                scopes.pop();
+            }
+            "const" => {
+                // const <类型> NAME = VALUE ;
+                let Some(end) = tokens[i..].iter().position(|t| t == ";").map(|p| i + p) else {
+                    break;
+                };
+                let eq = tokens[i..end].iter().position(|t| t == "=").map(|p| i + p);
+                if let Some(eq) = eq.filter(|eq| *eq > i + 1) {
+                    if let Some(id) = tokens.get(eq + 1).and_then(|v| parse_header_number(v)) {
+                        let mut path: Vec<String> =
+                            scopes.iter().filter(|s| !s.is_empty()).cloned().collect();
+                        path.push(tokens[eq - 1].clone());
+                        constants.push(IdConstant { path, id });
+                    }
+                }
+                i = end + 1;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    constants
+}
+
+/// 将头文件内容拆分为标识符、数字和 `{ } ; =` 符号，跳过注释和预处理指令
+fn tokenize_header(contents: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = contents.chars().peekable();
+    let mut current = String::new();
+    let mut line_start = true;
+
+    while let Some(c) = chars.next() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            current.push(c);
+            line_start = false;
+            continue;
+        }
+        if !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                chars.by_ref().find(|c| *c == '\n');
+                line_start = true;
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '#' if line_start => {
+                chars.by_ref().find(|c| *c == '\n');
+                line_start = true;
+            }
+            '{' | '}' | ';' | '=' => {
+                tokens.push(c.to_string());
+                line_start = false;
+            }
+            '\n' => line_start = true,
+            c if c.is_whitespace() => {}
+            _ => line_start = false,
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// 解析 `123U`、`0x7BU` 等数值
+fn parse_header_number(value: &str) -> Option<u32> {
+    let value = value.trim_end_matches(['U', 'u', 'L', 'l']);
+    if let Some(hex) = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()
+    } else {
+        value.parse::<u32>().ok()
+    }
+}
+
+/// 读取并解析 Wwise_IDs.h 文件
+pub fn load_ids_header(path: &Path) -> Result<Vec<IdConstant>, String> {
+    if !path.is_file() {
+        return Err("Wwise_IDs.h 文件不存在".to_string());
+    }
+    let contents = fs::read_to_string(path).map_err(|e| format!("读取文件失败: {}", e))?;
+    Ok(parse_ids_header(&contents))
+}
+
+/// 在 Wwise_IDs.h 中搜索 ID
+///
+/// # 参数
+/// * `path` - Wwise_IDs.h 文件路径
+/// * `id_string` - 要搜索的 ID 字符串；能解释为整数（十进制、`0x` 十六进制、有符号 int32）时按 ShortID 匹配，否则按命名空间路径匹配
+/// * `id_types` - 要搜索的 ID 类型数组，头文件只包含 ShortID
+/// * `match_mode` - 匹配方式，见 [`MatchMode::parse`]；名称搜索在 auto 模式下按子串匹配
+///
+/// # 返回
+/// * `Ok(SearchResponse)` - 输入的解释和搜索结果列表，名称为完整的命名空间路径，完全匹配的结果在前
+/// * `Err(String)` - 搜索失败，返回错误信息
+#[tauri::command]
+pub fn search_ids_header(
+    path: String,
+    id_string: String,
+    id_types: Vec<String>,
+    match_mode: Option<String>,
+) -> Result<SearchResponse, String> {
+    if !id_types.iter().any(|t| t == "ShortID") {
+        return Ok(SearchResponse { interpretations: Vec::new(), results: Vec::new(), scan: None });
+    }
+
+    let mode = MatchMode::parse(match_mode.as_deref())?;
+    let interpretations = if mode == MatchMode::Regex {
+        vec![IdInterpretation::partial(id_string.trim())]
+    } else {
+        normalize_id_input(&id_string)
+    };
+    // 能解释为整数时按 ShortID 搜索，否则按命名空间路径搜索
+    let numeric = interpretations
+        .iter()
+        .find(|i| matches!(i.form, InputForm::Decimal | InputForm::Hex | InputForm::NegativeInt32));
+    let is_numeric = numeric.is_some();
+    let matcher = match numeric {
+        Some(interpretation) => IdMatcher::new(&interpretation.value, mode)?,
+        None => {
+            let name_mode = if mode == MatchMode::Auto { MatchMode::Contains } else { mode };
+            IdMatcher::new(id_string.trim(), name_mode)?
+        }
+    };
+
+    let constants = load_ids_header(Path::new(&path))?;
+
+    let mut ranked: Vec<(MatchRank, &IdConstant)> = constants
+        .iter()
+        .filter_map(|constant| {
+            let rank = if is_numeric {
+                matcher.rank(&constant.id.to_string(), "ShortID")
+            } else {
+                matcher.rank(&constant.full_name(), "Name")
+            };
+            rank.map(|rank| (rank, constant))
+        })
+        .collect();
+    ranked.sort_by_key(|(rank, _)| *rank);
+
+    let results = ranked
+        .into_iter()
+        .map(|(_, constant)| SearchResult {
+            name: constant.full_name(),
+            object_type: constant.object_type().to_string(),
+            guid: String::new(),
+            short_id: constant.id.to_string(),
+            media_id: String::new(),
+            banks: Vec::new(),
+        })
+        .collect();
+
+    Ok(SearchResponse { interpretations, results, scan: None })
+}
+
+/// 按 Wwise_IDs.h 的规则将对象名称转换为常量名（大写，非字母数字替换为下划线）
+pub fn constant_name(name: &str) -> String {
+    let mut result: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    if result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert(0, '_');
+    }
+    result
+}
+
+/// 从工程或 Bank 索引生成与 Wwise_IDs.h 相同结构的常量列表
+pub fn constants_from_index(objects: &[IndexedObject]) -> Vec<IdConstant> {
+    let mut seen = BTreeSet::new();
+    let mut constants = Vec::new();
+
+    for object in objects {
+        let Ok(id) = object.short_id.parse::<u32>() else {
+            continue;
+        };
+
+        let mut path = vec!["AK".to_string()];
+        if let Some((ns, _)) = CATEGORIES.iter().find(|(_, t)| *t == object.object_type) {
+            path.push(ns.to_string());
+            path.push(constant_name(&object.name));
+        } else if let Some((ns, ..)) = GROUP_CATEGORIES.iter().find(|(_, t, ..)| *t == object.object_type) {
+            path.push(ns.to_string());
+            path.push(constant_name(&object.name));
+            path.push("GROUP".to_string());
+        } else if let Some((ns, _, child_ns, _)) =
+            GROUP_CATEGORIES.iter().find(|(.., t)| *t == object.object_type)
+        {
+            if object.parent.is_empty() {
+                continue;
+            }
+            path.push(ns.to_string());
+            path.push(constant_name(&object.parent));
+            path.push(child_ns.to_string());
+            path.push(constant_name(&object.name));
+        } else {
+            continue;
+        }
+
+        // 同一对象可能出现在多个 Bank 中
+        if seen.insert(path.clone()) {
+            constants.push(IdConstant { path, id });
+        }
+    }
+
+    constants
+}
+
+/// 常量命名空间树，用于按层级输出
+#[derive(Default)]
+struct ConstantTree {
+    constants: BTreeMap<String, u32>,
+    namespaces: BTreeMap<String, ConstantTree>,
+}
+
+impl ConstantTree {
+    fn build(constants: &[IdConstant]) -> Self {
+        let mut root = ConstantTree::default();
+        for constant in constants {
+            let Some((name, namespaces)) = constant.path.split_last() else {
+                continue;
+            };
+            let mut node = &mut root;
+            for ns in namespaces {
+                node = node.namespaces.entry(ns.clone()).or_default();
+            }
+            node.constants.insert(name.clone(), constant.id);
+        }
+        root
+    }
+}
+
+/// 支持生成的常量文件语言
+const LANGUAGES: &[&str] = &["csharp", "rust", "typescript", "lua"];
+
+/// C# 中存放不在任何命名空间中的常量的类，也是 Lua 返回的根 table 的名称
+const ROOT_NAME: &str = "WwiseIds";
+
+/// 将常量渲染为指定语言的源码
+///
+/// # 参数
+/// * `constants` - 常量列表
+/// * `language` - 目标语言，可选值: "csharp", "rust", "typescript", "lua"
+pub fn render_constants(constants: &[IdConstant], language: &str) -> Result<String, String> {
+    let tree = ConstantTree::build(constants);
+    let mut out = String::new();
+
+    match language {
+        "csharp" => {
+            // C# 的常量只能声明在类中，每层命名空间都生成为静态类
+            out.push_str("// 由 wid 生成，请勿手动修改\n\n");
+            if !tree.constants.is_empty() {
+                let root = ConstantTree { constants: tree.constants.clone(), namespaces: BTreeMap::new() };
+                render_csharp(ROOT_NAME, &root, 0, &mut out);
+            }
+            for (name, ns) in &tree.namespaces {
+                render_csharp(name, ns, 0, &mut out);
+            }
+        }
+        "rust" => {
+            out.push_str("// 由 wid 生成，请勿手动修改\n\n");
+            render_rust(&tree, 0, &mut out);
+        }
+        "typescript" => {
+            out.push_str("// 由 wid 生成，请勿手动修改\n\n");
+            for (name, id) in &tree.constants {
+                out.push_str(&format!("export const {} = {};\n", name, id));
+            }
+            for (name, ns) in &tree.namespaces {
+                out.push_str(&format!("export const {} = {{\n", name));
+                render_table(ns, 1, ":", &mut out);
+                out.push_str("} as const;\n");
+            }
+        }
+        "lua" => {
+            // Lua 模块只能返回一次，所有常量和命名空间放在同一个根 table 中
+            out.push_str("-- 由 wid 生成，请勿手动修改\n\n");
+            out.push_str(&format!("local {} = {{\n", ROOT_NAME));
+            render_table(&tree, 1, " =", &mut out);
+            out.push_str(&format!("}}\n\nreturn {}\n", ROOT_NAME));
+        }
+        _ => {
+            return Err(format!(
+                "不支持的语言: {}，可选值: {}",
+                language,
+                LANGUAGES.join(", ")
+            ))
+        }
+    }
+
+    Ok(out)
+}
+
+fn render_csharp(name: &str, tree: &ConstantTree, depth: usize, out: &mut String) {
+    let indent = "    ".repeat(depth);
+    out.push_str(&format!("{}public static class {}\n{}{{\n", indent, name, indent));
+    for (name, id) in &tree.constants {
+        out.push_str(&format!("{}    public const uint {} = {}U;\n", indent, name, id));
+    }
+    for (name, ns) in &tree.namespaces {
+        render_csharp(name, ns, depth + 1, out);
+    }
+    out.push_str(&format!("{}}}\n", indent));
+}
+
+/// Rust 模块名使用小写，遇到关键字时使用原始标识符
+fn rust_module_name(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+        "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+        "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while", "async",
+        "await", "dyn", "abstract", "become", "box", "do", "final", "macro", "override", "priv",
+        "typeof", "unsized", "virtual", "yield", "try", "gen",
+    ];
+    let lower = name.to_lowercase();
+    if KEYWORDS.contains(&lower.as_str()) {
+        format!("r#{}", lower)
+    } else {
+        lower
+    }
+}
+
+fn render_rust(tree: &ConstantTree, depth: usize, out: &mut String) {
+    let indent = "    ".repeat(depth);
+    for (name, id) in &tree.constants {
+        out.push_str(&format!("{}pub const {}: u32 = {};\n", indent, name, id));
+    }
+    for (name, ns) in &tree.namespaces {
+        out.push_str(&format!("{}pub mod {} {{\n", indent, rust_module_name(name)));
+        render_rust(ns, depth + 1, out);
+        out.push_str(&format!("{}}}\n", indent));
+    }
+}
+
+/// TypeScript 对象字面量与 Lua table 结构相同，只有键值分隔符不同
+fn render_table(tree: &ConstantTree, depth: usize, separator: &str, out: &mut String) {
+    let indent = "    ".repeat(depth);
+    for (name, id) in &tree.constants {
+        out.push_str(&format!("{}{}{} {},\n", indent, name, separator, id));
+    }
+    for (name, ns) in &tree.namespaces {
+        out.push_str(&format!("{}{}{} {{\n", indent, name, separator));
+        render_table(ns, depth + 1, separator, out);
+        out.push_str(&format!("{}}},\n", indent));
+    }
+}
+
+/// 从指定来源加载常量
+///
+/// # 参数
+/// * `source` - 来源类型，可选值: "wwise"（工程目录）, "bank"（Bank 目录）, "header"（Wwise_IDs.h）
+/// * `source_path` - 来源路径
+pub fn load_constants(source: &str, source_path: &str) -> Result<Vec<IdConstant>, String> {
+    let path = Path::new(source_path);
+    match source {
+        "wwise" => Ok(constants_from_index(&build_project_index(path)?.objects)),
+        "bank" => Ok(constants_from_index(&build_bank_index(path)?)),
+        "header" => load_ids_header(path),
+        _ => Err(format!("不支持的来源: {}", source)),
+    }
+}
+
+/// 生成 ID 常量文件
+///
+/// # 参数
+/// * `source` - 来源类型，可选值: "wwise", "bank", "header"
+/// * `source_path` - 来源路径（工程目录、Bank 目录或 Wwise_IDs.h）
+/// * `language` - 目标语言，可选值: "csharp", "rust", "typescript", "lua"
+/// * `output_path` - 输出文件路径
+///
+/// # 返回
+/// * `Ok(usize)` - 写入的常量数量
+/// * `Err(String)` - 生成失败，返回错误信息
+#[tauri::command]
+pub fn generate_id_constants(
+    source: String,
+    source_path: String,
+    language: String,
+    output_path: String,
+) -> Result<usize, String> {
+    let constants = load_constants(&source, &source_path)?;
+    if constants.is_empty() {
+        return Err("未找到可生成的 ID".to_string());
+    }
+
+    let contents = render_constants(&constants, &language)?;
+    fs::write(&output_path, contents).map_err(|e| format!("写入文件失败: {}", e))?;
+
+    Ok(constants.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = r#"/////////////////////////////////////////////////////////////////////////////////////////////////////
+//
+// Audiokinetic Wwise generated include file. Do not edit.
+//
+/////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#ifndef __WWISE_IDS_H__
+#define __WWISE_IDS_H__
+
+#include <AK/SoundEngine/Common/AkTypes.h>
+
+static const AkUniqueID LOOSE_ID = 7U;
+
+namespace AK
+{
+    namespace EVENTS
+    {
+        static const AkUniqueID PLAY_CLICK = 123U; /* 旧值 = 1U; */
+    } // namespace EVENTS
+
+    namespace STATES
+    {
+        namespace MATCH
+        {
+            static const AkUniqueID GROUP = 0x10U;
+
+            namespace STATE
+            {
+                static const AkUniqueID NONE = 0U;
+            } // namespace STATE
+        } // namespace MATCH
+    } // namespace STATES
+} // namespace AK
+
+#endif // __WWISE_IDS_H__
+"#;
+
+    fn constant(path: &[&str], id: u32) -> IdConstant {
+        IdConstant { path: path.iter().map(|s| s.to_string()).collect(), id }
+    }
+
+    #[test]
+    fn tokenizer_skips_comments_and_preprocessor_lines() {
+        let tokens = tokenize_header("#define X 1\nnamespace A { // B = 2;\n const C = 0x1U; /* D = 3; */ }\n  #endif");
+        assert_eq!(tokens, ["namespace", "A", "{", "const", "C", "=", "0x1U", ";", "}"]);
+        // 行中间的 # 不是预处理指令
+        assert_eq!(tokenize_header("A # B"), ["A", "B"]);
+    }
+
+    #[test]
+    fn parses_nested_namespaces_and_hex_values() {
+        let constants = parse_ids_header(HEADER);
+        let parsed: Vec<(String, u32, &str)> =
+            constants.iter().map(|c| (c.full_name(), c.id, c.object_type())).collect();
+        assert_eq!(
+            parsed,
+            [
+                ("LOOSE_ID".to_string(), 7, "Constant"),
+                ("AK::EVENTS::PLAY_CLICK".to_string(), 123, "Event"),
+                ("AK::STATES::MATCH::GROUP".to_string(), 16, "StateGroup"),
+                ("AK::STATES::MATCH::STATE::NONE".to_string(), 0, "State"),
+            ]
+        );
+        assert_eq!(parse_header_number("0X7Bul"), Some(123));
+        assert_eq!(parse_header_number("4294967296"), None);
+    }
+
+    #[test]
+    fn renders_csharp_namespaces_as_nested_static_classes() {
+        let csharp = render_constants(&parse_ids_header(HEADER), "csharp").unwrap();
+        assert_eq!(
+            csharp,
+            "// 由 wid 生成，请勿手动修改
+
+public static class WwiseIds
+{
+    public const uint LOOSE_ID = 7U;
+}
+public static class AK
+{
+    public static class EVENTS
+    {
+        public const uint PLAY_CLICK = 123U;
+    }
+    public static class STATES
+    {
+        public static class MATCH
+        {
+            public const uint GROUP = 16U;
+            public static class STATE
+            {
+                public const uint NONE = 0U;
+            }
+        }
+    }
+}
+"
+        );
+        assert!(!csharp.contains("namespace"));
+    }
+
+    #[test]
+    fn renders_rust_modules_with_raw_identifiers_for_keywords() {
+        let rust = render_constants(&parse_ids_header(HEADER), "rust").unwrap();
+        assert_eq!(
+            rust,
+            "// 由 wid 生成，请勿手动修改
+
+pub const LOOSE_ID: u32 = 7;
+pub mod ak {
+    pub mod events {
+        pub const PLAY_CLICK: u32 = 123;
+    }
+    pub mod states {
+        pub mod r#match {
+            pub const GROUP: u32 = 16;
+            pub mod state {
+                pub const NONE: u32 = 0;
+            }
+        }
+    }
+}
+"
+        );
+        assert_eq!(rust_module_name("TYPE"), "r#type");
+        assert_eq!(rust_module_name("GAME_PARAMETERS"), "game_parameters");
+    }
+
+    #[test]
+    fn renders_typescript_and_lua_tables() {
+        let constants = [constant(&["AK", "EVENTS", "PLAY_CLICK"], 123), constant(&["AK", "BANKS", "INIT"], 1355168291)];
+        assert_eq!(
+            render_constants(&constants, "typescript").unwrap(),
+            "// 由 wid 生成，请勿手动修改
+
+export const AK = {
+    BANKS: {
+        INIT: 1355168291,
+    },
+    EVENTS: {
+        PLAY_CLICK: 123,
+    },
+} as const;
+"
+        );
+        assert_eq!(
+            render_constants(&constants, "lua").unwrap(),
+            "-- 由 wid 生成，请勿手动修改
+
+local WwiseIds = {
+    AK = {
+        BANKS = {
+            INIT = 1355168291,
+        },
+        EVENTS = {
+            PLAY_CLICK = 123,
+        },
+    },
+}
+
+return WwiseIds
+"
+        );
+
+        let loose = render_constants(&[constant(&["LOOSE_ID"], 7)], "typescript").unwrap();
+        assert!(loose.ends_with("export const LOOSE_ID = 7;\n"));
+        assert!(render_constants(&constants, "go").unwrap_err().contains("csharp, rust, typescript, lua"));
+    }
+
+    #[test]
+    fn renders_lua_root_constants_and_namespaces_in_one_table() {
+        let constants = [
+            constant(&["LOOSE_ID"], 7),
+            constant(&["AK", "EVENTS", "PLAY_CLICK"], 123),
+            constant(&["PLUGINS", "REVERB"], 42),
+        ];
+        let lua = render_constants(&constants, "lua").unwrap();
+        assert_eq!(
+            lua,
+            "-- 由 wid 生成，请勿手动修改
+
+local WwiseIds = {
+    LOOSE_ID = 7,
+    AK = {
+        EVENTS = {
+            PLAY_CLICK = 123,
+        },
+    },
+    PLUGINS = {
+        REVERB = 42,
+    },
+}
+
+return WwiseIds
+"
+        );
+        assert_eq!(lua.matches("return").count(), 1);
+    }
+}
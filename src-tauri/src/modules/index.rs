@@ -0,0 +1,514 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use rayon::prelude::*;
+use roxmltree::{Document, Node};
+use serde::Serialize;
+use serde_json::Value;
+use crate::modules::bank_metadata::discover_bank_metadata;
+use crate::modules::schema::{detect_json_schema, detect_xml_schema, MetadataLayout, MetadataSchema};
+use crate::modules::types::{OwningBank, SearchResult};
+use crate::modules::utils::is_valid_guid;
+use crate::modules::walker::{walk_project_files, ScanStats};
+
+/// 索引中的一个 Wwise 对象
+///
+/// 工程索引来自 .wwu 文件，Bank 索引来自 SoundbanksInfo 或单独的 Bank 元数据文件。
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexedObject {
+    pub name: String,
+    pub object_type: String,
+    pub guid: String,
+    pub short_id: String,
+    /// 源的所有 MediaID（每个转换后的媒体一个），Bank 中的媒体只有一个
+    pub media_ids: Vec<String>,
+    /// 工程中的对象路径，如 `\Events\Default Work Unit\Play_Click`；Bank 对象为空
+    pub path: String,
+    /// 最近的上级对象名称（State 所属的 StateGroup、Event 所属的 SoundBank 等）
+    pub parent: String,
+    /// 所在的工作单元名称；Bank 对象为空
+    pub work_unit: String,
+    /// 包含该对象的所有 SoundBank；工程对象只有经过 [`attach_bank_membership`] 才有
+    pub banks: Vec<OwningBank>,
+}
+
+impl From<&IndexedObject> for SearchResult {
+    fn from(object: &IndexedObject) -> Self {
+        SearchResult {
+            name: if object.name.is_empty() { "未命名".to_string() } else { object.name.clone() },
+            object_type: object.object_type.clone(),
+            guid: object.guid.clone(),
+            short_id: object.short_id.clone(),
+            media_id: object.media_ids.first().cloned().unwrap_or_default(),
+            banks: object.banks.clone(),
+        }
+    }
+}
+
+/// 工程索引和扫描统计
+#[derive(Debug)]
+pub struct ProjectIndex {
+    pub objects: Vec<IndexedObject>,
+    pub stats: ScanStats,
+}
+
+/// 读入内存的 .wwu 文件
+#[derive(Debug)]
+pub struct WorkUnitFile {
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+/// 工程目录中的所有 .wwu 文件和扫描统计
+#[derive(Debug)]
+pub struct ProjectDocuments {
+    pub files: Vec<WorkUnitFile>,
+    pub stats: ScanStats,
+}
+
+/// 读取 Wwise 工程目录中的所有 .wwu 文件，无法读取的文件跳过
+pub fn load_project_documents(directory: &Path) -> Result<ProjectDocuments, String> {
+    let scan = walk_project_files(directory, "wwu")?;
+    if scan.files.is_empty() {
+        return Err(format!("未找到 .wwu 文件（已扫描 {} 个文件）", scan.stats.files_visited));
+    }
+
+    let files = scan
+        .files
+        .into_par_iter()
+        .filter_map(|path| {
+            let contents = fs::read_to_string(&path).ok()?;
+            Some(WorkUnitFile { path, contents })
+        })
+        .collect();
+
+    Ok(ProjectDocuments { files, stats: scan.stats })
+}
+
+/// 从已读取的 .wwu 文件构建对象索引，无法解析的文件跳过
+pub fn index_project_documents(documents: &ProjectDocuments) -> Vec<IndexedObject> {
+    documents
+        .files
+        .par_iter()
+        .flat_map(|file| {
+            let Ok(doc) = Document::parse(&file.contents) else {
+                return Vec::new();
+            };
+
+            let mut file_objects = Vec::new();
+            for category in doc.root_element().children().filter(|n| n.is_element()) {
+                let folder = category_folder_name(category.tag_name().name());
+                index_wwu_node(&category, &format!("\\{}", folder), "", "", &mut file_objects);
+            }
+            file_objects
+        })
+        .collect()
+}
+
+/// 从 Wwise 工程目录的 .wwu 文件构建索引
+pub fn build_project_index(directory: &Path) -> Result<ProjectIndex, String> {
+    let documents = load_project_documents(directory)?;
+    let objects = index_project_documents(&documents);
+    Ok(ProjectIndex { objects, stats: documents.stats })
+}
+
+/// .wwu 顶层分类节点对应的工程浏览器目录名称
+pub fn category_folder_name(tag: &str) -> &str {
+    match tag {
+        "AudioObjects" => "Actor-Mixer Hierarchy",
+        "InteractiveMusic" => "Interactive Music Hierarchy",
+        "Busses" => "Master-Mixer Hierarchy",
+        "GameParameters" => "Game Parameters",
+        "DynamicDialogue" => "Dynamic Dialogue",
+        "Conversions" => "Conversion Settings",
+        "AudioDevices" => "Audio Devices",
+        "VirtualAcoustics" => "Virtual Acoustics",
+        "ControlSurfaceSessions" => "Control Surface Sessions",
+        "MixingSessions" => "Mixing Sessions",
+        "SoundcasterSessions" => "Soundcaster Sessions",
+        tag => tag,
+    }
+}
+
+/// 是否为对象定义节点（有名称和 GUID，且不是 ObjectRef、StateRef 等引用节点）
+pub fn is_definition_node(node: &Node) -> bool {
+    node.is_element()
+        && !node.tag_name().name().ends_with("Ref")
+        && node.has_attribute("Name")
+        && node.attribute("ID").is_some_and(is_valid_guid)
+}
+
+/// 递归收集 .wwu 节点下的对象定义
+fn index_wwu_node(
+    node: &Node,
+    path: &str,
+    parent: &str,
+    work_unit: &str,
+    objects: &mut Vec<IndexedObject>,
+) {
+    for child in node.children().filter(|n| n.is_element()) {
+        if !is_definition_node(&child) {
+            index_wwu_node(&child, path, parent, work_unit, objects);
+            continue;
+        }
+
+        let name = child.attribute("Name").unwrap_or("");
+        let work_unit = if child.has_tag_name("WorkUnit") { name } else { work_unit };
+        let child_path = format!("{}\\{}", path, name);
+
+        // AudioFileSource 等对象的 MediaID 位于 MediaIDList/MediaID 子节点，可能有多个
+        let media_ids = child
+            .children()
+            .filter(|n| n.has_tag_name("MediaIDList"))
+            .flat_map(|list| list.children().filter(|n| n.has_tag_name("MediaID")))
+            .filter_map(|n| n.attribute("ID"))
+            .map(str::to_string)
+            .collect();
+
+        objects.push(IndexedObject {
+            name: name.to_string(),
+            object_type: child.tag_name().name().to_string(),
+            guid: child.attribute("ID").unwrap_or("").to_string(),
+            short_id: child.attribute("ShortID").unwrap_or("").to_string(),
+            media_ids,
+            path: child_path.clone(),
+            parent: parent.to_string(),
+            work_unit: work_unit.to_string(),
+            banks: Vec::new(),
+        });
+
+        index_wwu_node(&child, &child_path, name, work_unit, objects);
+    }
+}
+
+/// 从 Bank 目录的元数据文件构建索引
+pub fn build_bank_index(directory: &Path) -> Result<Vec<IndexedObject>, String> {
+    if !directory.is_dir() {
+        return Err("目录不存在".to_string());
+    }
+
+    let files = discover_bank_metadata(directory)?;
+    if !files.has_bank_metadata() {
+        return Err("未找到 SoundbanksInfo 或单独的 Bank 元数据文件".to_string());
+    }
+
+    let bank_files: Vec<&Path> = match &files.soundbanks_info {
+        Some(info_path) => vec![info_path.as_path()],
+        None => files.bank_files.iter().map(|p| p.as_path()).collect(),
+    };
+
+    let file_objects: Vec<Vec<IndexedObject>> = bank_files
+        .par_iter()
+        .map(|file_path| index_metadata_file(file_path, "SoundBanksInfo"))
+        .collect::<Result<_, _>>()?;
+    let mut objects: Vec<IndexedObject> = file_objects.into_iter().flatten().collect();
+
+    for (info_path, root_name) in files.info_files() {
+        objects.extend(index_metadata_file(info_path, root_name)?);
+    }
+
+    Ok(merge_bank_duplicates(objects))
+}
+
+/// 把 Bank 索引中记录的 SoundBank 归属加到工程对象上
+///
+/// 按 GUID 对应；旧版本元数据没有 GUID 时按对象类型和 ShortID 对应，源按 MediaID 对应 Bank 中的媒体。
+pub fn attach_bank_membership(objects: &mut [IndexedObject], bank_objects: &[IndexedObject]) {
+    // 同一对象可能以不同类型出现多次（如 Event 中引用的 State），合并其 SoundBank
+    fn add<K: std::hash::Hash + Eq>(map: &mut HashMap<K, Vec<OwningBank>>, key: K, banks: &[OwningBank]) {
+        let entry = map.entry(key).or_default();
+        for bank in banks {
+            if !entry.contains(bank) {
+                entry.push(bank.clone());
+            }
+        }
+    }
+
+    let mut by_guid = HashMap::new();
+    let mut by_short_id = HashMap::new();
+    let mut by_media_id = HashMap::new();
+    for object in bank_objects.iter().filter(|o| !o.banks.is_empty()) {
+        if !object.guid.is_empty() {
+            add(&mut by_guid, object.guid.to_uppercase(), &object.banks);
+        }
+        if !object.short_id.is_empty() {
+            add(&mut by_short_id, (object.object_type.as_str(), object.short_id.as_str()), &object.banks);
+        }
+        for media_id in &object.media_ids {
+            add(&mut by_media_id, media_id.as_str(), &object.banks);
+        }
+    }
+
+    for object in objects.iter_mut() {
+        let banks = by_guid
+            .get(&object.guid.to_uppercase())
+            .or_else(|| by_short_id.get(&(object.object_type.as_str(), object.short_id.as_str())))
+            .cloned()
+            .or_else(|| {
+                let mut banks: Vec<OwningBank> = Vec::new();
+                for bank in object.media_ids.iter().filter_map(|id| by_media_id.get(id.as_str())).flatten() {
+                    if !banks.contains(bank) {
+                        banks.push(bank.clone());
+                    }
+                }
+                (!banks.is_empty()).then_some(banks)
+            });
+        if let Some(banks) = banks {
+            object.banks = banks;
+        }
+    }
+}
+
+/// 合并在多个 SoundBank（或同一 SoundBank 的多个语言）中重复出现的对象，保留第一次出现的顺序
+fn merge_bank_duplicates(objects: Vec<IndexedObject>) -> Vec<IndexedObject> {
+    let mut merged: Vec<IndexedObject> = Vec::with_capacity(objects.len());
+    let mut positions: HashMap<(String, String, String, String), usize> = HashMap::new();
+    for object in objects {
+        let key = (object.object_type.clone(), object.guid.clone(), object.short_id.clone(), object.media_ids.join(","));
+        match positions.get(&key) {
+            Some(&i) => {
+                let existing = &mut merged[i];
+                for bank in object.banks {
+                    if !existing.banks.contains(&bank) {
+                        existing.banks.push(bank);
+                    }
+                }
+            }
+            None => {
+                positions.insert(key, merged.len());
+                merged.push(object);
+            }
+        }
+    }
+    merged
+}
+
+/// 一个元数据文件的内容
+#[derive(Debug)]
+pub struct MetadataFile {
+    pub objects: Vec<IndexedObject>,
+    /// 根节点不存在时为 `None`
+    pub schema: Option<MetadataSchema>,
+}
+
+/// 读取一个元数据文件（JSON 或 XML）并收集其中的对象
+fn index_metadata_file(file_path: &Path, root_name: &str) -> Result<Vec<IndexedObject>, String> {
+    Ok(read_metadata_file(file_path, root_name)?.objects)
+}
+
+/// 读取一个元数据文件（JSON 或 XML），检测其版本并按对应的结构收集对象
+pub fn read_metadata_file(file_path: &Path, root_name: &str) -> Result<MetadataFile, String> {
+    let file_name = file_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let contents = fs::read_to_string(file_path)
+        .map_err(|e| format!("读取文件 {} 失败: {}", file_name, e))?;
+
+    let mut objects = Vec::new();
+    let mut schema = None;
+
+    let is_json = file_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    if is_json {
+        let json: Value = serde_json::from_str(&contents)
+            .map_err(|e| format!("解析 JSON 文件 {} 失败: {}", file_name, e))?;
+        if let Some(root) = json.get(root_name) {
+            let detected = detect_json_schema(root);
+            let object_types: fn(&str) -> &str = match detected.layout {
+                MetadataLayout::Legacy => legacy_json_object_type,
+                MetadataLayout::Current => json_object_type,
+            };
+            index_json_value(root, root_name, "", None, object_types, &mut objects);
+            schema = Some(detected);
+        }
+    } else {
+        let doc = Document::parse(&contents)
+            .map_err(|e| format!("解析 XML 文件 {} 失败: {}", file_name, e))?;
+        let root = doc.root_element();
+        if root.has_tag_name(root_name) {
+            // XML 中对象的元素名（Event、File 等）在新旧结构中一致，使用同一套解析
+            index_xml_node(&root, root_name, "", None, &mut objects);
+            schema = Some(detect_xml_schema(&root));
+        }
+    }
+
+    Ok(MetadataFile { objects, schema })
+}
+
+/// JSON 容器键对应的对象类型
+fn json_object_type(key: &str) -> &str {
+    match key {
+        "SoundBanks" => "SoundBank",
+        "Events" => "Event",
+        "Media" => "Media",
+        "GameParameters" => "GameParameter",
+        "StateGroups" => "StateGroup",
+        "States" => "State",
+        "SwitchGroups" => "SwitchGroup",
+        "Switches" => "Switch",
+        "Busses" => "Bus",
+        "AuxBusses" => "AuxBus",
+        "Triggers" => "Trigger",
+        "ExternalSources" => "ExternalSource",
+        "AcousticTextures" => "AcousticTexture",
+        "Plugins" => "Plugin",
+        "Custom" => "CustomPlugin",
+        "AudioDevices" => "AudioDevice",
+        "ShareSets" => "ShareSet",
+        "SwitchContainers" => "SwitchContainer",
+        "Platforms" => "Platform",
+        "Languages" => "Language",
+        key => key,
+    }
+}
+
+/// 旧结构（2021.1 及更早）JSON 容器键对应的对象类型
+fn legacy_json_object_type(key: &str) -> &str {
+    match key {
+        "IncludedEvents" => "Event",
+        "IncludedMemoryFiles"
+        | "IncludedPrefetchFiles"
+        | "ReferencedStreamedFiles"
+        | "StreamedFiles"
+        | "MediaFilesNotInAnyBank" => "Media",
+        key => json_object_type(key),
+    }
+}
+
+/// 提取元数据对象的 Id（旧版本 JSON 中为字符串，新版本为数字）
+fn json_id(obj: &serde_json::Map<String, Value>) -> String {
+    match obj.get("Id") {
+        Some(Value::String(id)) => id.clone(),
+        Some(Value::Number(id)) => id.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// 递归收集 JSON 元数据中的对象，`bank` 为当前所在的 SoundBank
+fn index_json_value(
+    value: &Value,
+    object_type: &str,
+    parent: &str,
+    bank: Option<&OwningBank>,
+    object_types: fn(&str) -> &str,
+    objects: &mut Vec<IndexedObject>,
+) {
+    match value {
+        Value::Object(obj) => {
+            let name = obj
+                .get("Name")
+                .or_else(|| obj.get("ShortName"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            let guid = obj.get("GUID").and_then(|v| v.as_str()).unwrap_or("");
+            let id = json_id(obj);
+
+            let is_object = !id.is_empty() || is_valid_guid(guid);
+            let owning = (is_object && object_type == "SoundBank").then(|| OwningBank {
+                name: name.to_string(),
+                id: id.clone(),
+                path: obj.get("Path").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                language: obj.get("Language").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            });
+            let bank = owning.as_ref().or(bank);
+            if is_object {
+                objects.push(new_metadata_object(name, object_type, guid, id, parent, bank));
+            }
+
+            let child_parent = if is_object && !name.is_empty() { name } else { parent };
+            for (key, val) in obj {
+                index_json_value(val, object_types(key), child_parent, bank, object_types, objects);
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                index_json_value(item, object_type, parent, bank, object_types, objects);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// XML 节点对应的对象类型
+fn xml_object_type<'a>(node: &Node<'a, '_>) -> &'a str {
+    let tag = node.tag_name().name();
+    match tag {
+        "File" => "Media",
+        "Plugin" => match node.parent_element().map(|p| p.tag_name().name()) {
+            Some("AudioDevices") => "AudioDevice",
+            Some("Custom") => "CustomPlugin",
+            Some("ShareSets") => "ShareSet",
+            _ => "Plugin",
+        },
+        tag => tag,
+    }
+}
+
+/// 递归收集 XML 元数据中的对象，`bank` 为当前所在的 SoundBank
+fn index_xml_node(
+    node: &Node,
+    object_type: &str,
+    parent: &str,
+    bank: Option<&OwningBank>,
+    objects: &mut Vec<IndexedObject>,
+) {
+    let name = node
+        .attribute("Name")
+        .or_else(|| node.attribute("ShortName"))
+        .or_else(|| child_text(node, "ShortName"))
+        .or_else(|| child_text(node, "ObjectPath"))
+        .unwrap_or("");
+    let guid = node.attribute("GUID").unwrap_or("");
+    let id = node.attribute("Id").unwrap_or("");
+
+    let is_object = !id.is_empty() || is_valid_guid(guid);
+    let owning = (is_object && object_type == "SoundBank").then(|| OwningBank {
+        name: name.to_string(),
+        id: id.to_string(),
+        path: child_text(node, "Path").unwrap_or("").to_string(),
+        language: node.attribute("Language").unwrap_or("").to_string(),
+    });
+    let bank = owning.as_ref().or(bank);
+    if is_object {
+        objects.push(new_metadata_object(name, object_type, guid, id.to_string(), parent, bank));
+    }
+
+    let child_parent = if is_object && !name.is_empty() { name } else { parent };
+    for child in node.children().filter(|n| n.is_element()) {
+        index_xml_node(&child, xml_object_type(&child), child_parent, bank, objects);
+    }
+}
+
+fn child_text<'a>(node: &Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    node.children().find(|n| n.has_tag_name(tag)).and_then(|n| n.text())
+}
+
+fn new_metadata_object(
+    name: &str,
+    object_type: &str,
+    guid: &str,
+    id: String,
+    parent: &str,
+    bank: Option<&OwningBank>,
+) -> IndexedObject {
+    // Media 的 Id 是 MediaID，其他对象的 Id 是 ShortID
+    let (short_id, media_ids) = if object_type == "Media" {
+        (String::new(), vec![id])
+    } else {
+        (id, Vec::new())
+    };
+
+    IndexedObject {
+        name: name.to_string(),
+        object_type: object_type.to_string(),
+        guid: if is_valid_guid(guid) { guid.to_string() } else { String::new() },
+        short_id,
+        media_ids,
+        path: String::new(),
+        parent: parent.to_string(),
+        work_unit: String::new(),
+        banks: bank.cloned().into_iter().collect(),
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+use roxmltree::Node;
+use serde::Serialize;
+use crate::modules::index::is_definition_node;
+use crate::modules::object_details::{read_properties, read_references, ObjectLookup, ObjectReference, PropertyValue};
+
+/// 由 Override 开关控制继承的属性和引用
+///
+/// 开关未打开时使用上级对象的设置，层级中最顶层的对象总是使用自己的设置。
+pub const OVERRIDE_RULES: &[(&str, &[&str])] = &[
+    ("OverrideOutput", &["OutputBus", "OutputBusVolume", "OutputBusLowpass", "OutputBusHighpass"]),
+    ("OverrideConversion", &["Conversion"]),
+    (
+        "OverridePositioning",
+        &[
+            "Attenuation",
+            "EnableAttenuation",
+            "3DSpatialization",
+            "SpeakerPanning",
+            "ListenerRelativeRouting",
+            "CenterPercentage",
+        ],
+    ),
+    (
+        "OverrideEffect",
+        &["Effect0", "Effect1", "Effect2", "Effect3", "BypassEffect", "BypassEffect0", "BypassEffect1", "BypassEffect2", "BypassEffect3"],
+    ),
+    ("OverrideGameAuxSends", &["UseGameAuxSends", "GameAuxSendVolume", "GameAuxSendLPF", "GameAuxSendHPF"]),
+    (
+        "OverrideUserAuxSends",
+        &[
+            "UserAuxSend0",
+            "UserAuxSend1",
+            "UserAuxSend2",
+            "UserAuxSend3",
+            "UserAuxSendVolume0",
+            "UserAuxSendVolume1",
+            "UserAuxSendVolume2",
+            "UserAuxSendVolume3",
+        ],
+    ),
+    ("OverrideEarlyReflections", &["ReflectionsAuxSend", "ReflectionsVolume"]),
+    ("OverridePriority", &["Priority", "PriorityDistanceFactor", "PriorityDistanceOffset"]),
+    ("OverrideVirtualVoice", &["VirtualVoiceQueueBehavior", "BelowThresholdBehavior"]),
+    ("OverrideHdrEnvelope", &["HdrEnableEnvelope", "HdrEnvelopeSensitivity", "HdrActiveRange"]),
+    (
+        "IgnoreParentMaxNumInst",
+        &["UseMaxSoundPerInstance", "MaxSoundPerInstance", "IsGlobalLimit", "OverLimitBehavior", "MaxReachedBehavior"],
+    ),
+];
+
+/// 与上级对象的值累加的属性
+pub const ADDITIVE_PROPERTIES: &[&str] = &["Volume", "Pitch", "Lowpass", "Highpass", "MakeUpGain", "InitialDelay"];
+
+/// 层级的边界：工作单元和文件夹下的对象是顶层对象
+const HIERARCHY_BOUNDARIES: &[&str] = &["WorkUnit", "Folder", "PhysicalFolder"];
+
+/// 有效值的来源规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InheritanceRule {
+    /// 不继承，只取对象自己的值
+    Own,
+    /// 由 Override 开关决定取自己或上级的值
+    Override,
+    /// 累加层级中各对象的值
+    Additive,
+}
+
+/// 提供值的对象
+#[derive(Debug, Clone, Serialize)]
+pub struct ValueSource {
+    pub name: String,
+    pub object_type: String,
+    pub guid: String,
+    pub path: String,
+}
+
+/// 累加属性中某一层级的值
+#[derive(Debug, Clone, Serialize)]
+pub struct Contribution {
+    pub source: ValueSource,
+    pub value: f64,
+}
+
+/// 考虑继承后对象实际使用的属性或引用
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveValue {
+    pub name: String,
+    /// 属性值；引用为目标名称
+    pub value: String,
+    /// 引用的目标
+    pub reference: Option<ObjectReference>,
+    pub rule: InheritanceRule,
+    /// 控制继承的 Override 开关，如 `OverrideOutput`
+    pub override_flag: String,
+    /// 提供该值的对象；累加属性为对象本身
+    pub source: ValueSource,
+    /// 值来自上级对象
+    pub inherited: bool,
+    /// 提供该值的对象没有设置，使用 Wwise 的默认值
+    pub is_default: bool,
+    /// 累加属性中各层级设置的值，从对象本身到顶层
+    pub contributions: Vec<Contribution>,
+}
+
+/// 层级中一个对象自己的设置
+struct Level<'a, 'input> {
+    node: Node<'a, 'input>,
+    properties: Vec<PropertyValue>,
+    references: Vec<ObjectReference>,
+}
+
+impl Level<'_, '_> {
+    fn property(&self, name: &str) -> Option<&PropertyValue> {
+        self.properties.iter().find(|p| p.name == name)
+    }
+
+    fn reference(&self, name: &str) -> Option<&ObjectReference> {
+        self.references.iter().find(|r| r.name == name)
+    }
+
+    fn is_set(&self, name: &str) -> bool {
+        self.property(name).is_some() || self.reference(name).is_some()
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.property(name).is_some_and(|p| p.value.eq_ignore_ascii_case("true"))
+    }
+
+    fn source(&self, lookup: &ObjectLookup) -> ValueSource {
+        let guid = self.node.attribute("ID").unwrap_or("");
+        ValueSource {
+            name: self.node.attribute("Name").unwrap_or("").to_string(),
+            object_type: self.node.tag_name().name().to_string(),
+            guid: guid.to_string(),
+            path: lookup.get(guid).map(|o| o.path.clone()).unwrap_or_default(),
+        }
+    }
+}
+
+/// 对象及其上级对象，从对象本身到层级的顶层对象
+fn hierarchy_chain<'a, 'input>(node: &Node<'a, 'input>) -> Vec<Node<'a, 'input>> {
+    let mut chain = vec![*node];
+    if HIERARCHY_BOUNDARIES.contains(&node.tag_name().name()) {
+        return chain;
+    }
+    for ancestor in node.ancestors().skip(1).filter(is_definition_node) {
+        if HIERARCHY_BOUNDARIES.contains(&ancestor.tag_name().name()) {
+            break;
+        }
+        chain.push(ancestor);
+    }
+    chain
+}
+
+fn override_flag(name: &str) -> Option<&'static str> {
+    OVERRIDE_RULES
+        .iter()
+        .find(|(_, names)| names.contains(&name))
+        .map(|(flag, _)| *flag)
+}
+
+/// 计算对象考虑继承后的属性和引用
+///
+/// 包含对象自己设置的所有属性和引用，以及上级对象设置的、会被该对象继承的属性和引用。
+pub fn evaluate_effective_values(node: &Node, lookup: &ObjectLookup) -> Vec<EffectiveValue> {
+    let levels = read_levels(node, lookup);
+
+    // 对象自己的设置在前，然后是上级对象按层级顺序设置的名称
+    let mut names = Vec::new();
+    let mut seen = HashSet::new();
+    for (depth, level) in levels.iter().enumerate() {
+        let own = level.properties.iter().map(|p| p.name.as_str());
+        let referenced = level.references.iter().map(|r| r.name.as_str());
+        for name in own.chain(referenced) {
+            let inheritable = override_flag(name).is_some() || ADDITIVE_PROPERTIES.contains(&name);
+            if (depth == 0 || inheritable) && seen.insert(name) {
+                names.push(name);
+            }
+        }
+    }
+
+    names.into_iter().map(|name| evaluate(name, &levels, lookup)).collect()
+}
+
+/// 只计算指定名称的有效值，对象和上级对象都没有设置的名称返回默认值
+pub fn evaluate_named_values(node: &Node, names: &[&str], lookup: &ObjectLookup) -> Vec<EffectiveValue> {
+    let levels = read_levels(node, lookup);
+    names.iter().map(|name| evaluate(name, &levels, lookup)).collect()
+}
+
+fn read_levels<'a, 'input>(node: &Node<'a, 'input>, lookup: &ObjectLookup) -> Vec<Level<'a, 'input>> {
+    hierarchy_chain(node)
+        .into_iter()
+        .map(|node| Level { properties: read_properties(&node), references: read_references(&node, lookup), node })
+        .collect()
+}
+
+fn evaluate(name: &str, levels: &[Level], lookup: &ObjectLookup) -> EffectiveValue {
+    let object = &levels[0];
+
+    if ADDITIVE_PROPERTIES.contains(&name) {
+        let contributions: Vec<Contribution> = levels
+            .iter()
+            .filter_map(|level| {
+                let value = level.property(name)?.value.parse::<f64>().ok()?;
+                Some(Contribution { source: level.source(lookup), value })
+            })
+            .collect();
+        let total: f64 = contributions.iter().map(|c| c.value).sum();
+        let source = object.source(lookup);
+        return EffectiveValue {
+            name: name.to_string(),
+            value: total.to_string(),
+            reference: None,
+            rule: InheritanceRule::Additive,
+            override_flag: String::new(),
+            inherited: contributions.iter().any(|c| c.source.guid != source.guid),
+            source,
+            is_default: contributions.is_empty(),
+            contributions,
+        };
+    }
+
+    let (rule, flag, owner) = match override_flag(name) {
+        Some(flag) => {
+            // 打开了开关的最近的对象提供该值，没有时由顶层对象提供
+            let owner = levels
+                .iter()
+                .position(|level| level.flag(flag))
+                .unwrap_or(levels.len() - 1);
+            (InheritanceRule::Override, flag, owner)
+        }
+        None => (InheritanceRule::Own, "", 0),
+    };
+
+    let level = &levels[owner];
+    let reference = level.reference(name).cloned();
+    let value = match &reference {
+        Some(reference) => reference.target_name.clone(),
+        None => level.property(name).map(|p| p.value.clone()).unwrap_or_default(),
+    };
+    EffectiveValue {
+        name: name.to_string(),
+        value,
+        is_default: !level.is_set(name),
+        reference,
+        rule,
+        override_flag: flag.to_string(),
+        source: level.source(lookup),
+        inherited: owner > 0,
+        contributions: Vec::new(),
+    }
+}
@@ -0,0 +1,209 @@
+use std::fs;
+use regex::Regex;
+use serde::Serialize;
+use crate::modules::id_resolver::{find_id_tokens, parse_id_token, IdResolver, IdToken};
+
+/// Wwise 声音引擎日志中常见的错误消息，例如
+/// `Event ID not found: 1234567`、`Media 98765 failed to load`、`Bank ID 123 could not be loaded`
+const WWISE_PATTERNS: &[&str] = &[
+    r"(?i)\b(?:event|media|bank|bus|aux bus|switch|state|rtpc|game ?parameter|trigger|plug-?in|source|object|sound|node|audio node)s?\b[^\r\n0-9{}-]{0,32}?(?P<id>\{?[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}\}?|0x[0-9a-f]{1,8}\b|-?\d+\b)",
+];
+
+/// 内置的日志格式
+///
+/// * `generic` - 注释文本中所有能解析的 GUID 和整数
+/// * `wwise` - 只注释 Wwise 错误消息中 Event/Media/Bank 等关键字后面的 ID
+pub const LOG_FORMATS: &[&str] = &["generic", "wwise"];
+
+/// 日志注释统计
+#[derive(Debug, Serialize)]
+pub struct AnnotationStats {
+    /// 处理的行数
+    pub lines: usize,
+    /// 被注释的行数
+    pub annotated_lines: usize,
+    /// 插入的注释数量
+    pub annotations: usize,
+}
+
+/// 注释后的日志
+#[derive(Debug, Serialize)]
+pub struct AnnotatedLog {
+    pub text: String,
+    #[serde(flatten)]
+    pub stats: AnnotationStats,
+}
+
+/// 日志注释器：在能解析的 ID 后面插入 `[wid: 名称 (类型)]`
+pub struct LogAnnotator {
+    resolver: IdResolver,
+    /// 为空时使用 generic 模式
+    patterns: Vec<Regex>,
+}
+
+impl LogAnnotator {
+    /// 创建注释器
+    ///
+    /// # 参数
+    /// * `resolver` - 用于解析 ID 的查找表
+    /// * `format` - 内置日志格式，见 [`LOG_FORMATS`]
+    /// * `custom_patterns` - 自定义正则表达式，必须包含命名分组 `id`；提供后会追加到内置格式的规则之后
+    pub fn new(resolver: IdResolver, format: &str, custom_patterns: &[String]) -> Result<Self, String> {
+        let builtin: &[&str] = match format {
+            "generic" => &[],
+            "wwise" => WWISE_PATTERNS,
+            _ => {
+                return Err(format!(
+                    "不支持的日志格式: {}，可选值: {}",
+                    format,
+                    LOG_FORMATS.join(", ")
+                ))
+            }
+        };
+
+        let mut patterns = Vec::new();
+        for pattern in builtin.iter().copied().chain(custom_patterns.iter().map(|p| p.as_str())) {
+            if pattern.trim().is_empty() {
+                continue;
+            }
+            let regex = Regex::new(pattern)
+                .map_err(|e| format!("正则表达式 {} 无效: {}", pattern, e))?;
+            if !regex.capture_names().any(|name| name == Some("id")) {
+                return Err(format!("正则表达式 {} 缺少命名分组 (?P<id>...)", pattern));
+            }
+            patterns.push(regex);
+        }
+
+        Ok(LogAnnotator { resolver, patterns })
+    }
+
+    /// 查找一行中需要注释的 ID 及其结束位置
+    fn find_ids(&self, line: &str) -> Vec<(usize, IdToken)> {
+        let mut found: Vec<(usize, IdToken)> = if self.patterns.is_empty() {
+            find_id_tokens(line)
+                .into_iter()
+                .map(|(range, token)| (range.end, token))
+                .collect()
+        } else {
+            self.patterns
+                .iter()
+                .flat_map(|regex| regex.captures_iter(line))
+                .filter_map(|caps| caps.name("id"))
+                .filter_map(|m| parse_id_token(m.as_str()).map(|token| (m.end(), token)))
+                .collect()
+        };
+        // 多条规则可能命中同一个 ID
+        found.sort_by_key(|(end, _)| *end);
+        found.dedup_by_key(|(end, _)| *end);
+        found
+    }
+
+    /// 生成一个 ID 的注释文本，无法解析时返回 None
+    fn annotation(&self, token: &IdToken) -> Option<String> {
+        let mut labels: Vec<String> = Vec::new();
+        for m in self.resolver.resolve(token) {
+            let name = if m.object.name.is_empty() { "未命名" } else { m.object.name.as_str() };
+            let label = format!("{} ({})", name, m.object.object_type);
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+        if labels.is_empty() {
+            None
+        } else {
+            Some(format!(" [wid: {}]", labels.join(" | ")))
+        }
+    }
+
+    /// 注释一行，返回注释后的文本和插入的注释数量
+    pub fn annotate_line(&self, line: &str) -> (String, usize) {
+        let mut result = String::with_capacity(line.len());
+        let mut last = 0;
+        let mut count = 0;
+        for (end, token) in self.find_ids(line) {
+            if let Some(annotation) = self.annotation(&token) {
+                result.push_str(&line[last..end]);
+                result.push_str(&annotation);
+                last = end;
+                count += 1;
+            }
+        }
+        result.push_str(&line[last..]);
+        (result, count)
+    }
+
+    /// 注释整段文本，保留原有的换行符
+    pub fn annotate(&self, text: &str) -> AnnotatedLog {
+        let mut output = String::with_capacity(text.len());
+        let mut stats = AnnotationStats { lines: 0, annotated_lines: 0, annotations: 0 };
+
+        for line in text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\r', '\n']);
+            let (annotated, count) = self.annotate_line(content);
+            output.push_str(&annotated);
+            output.push_str(&line[content.len()..]);
+
+            stats.lines += 1;
+            if count > 0 {
+                stats.annotated_lines += 1;
+                stats.annotations += count;
+            }
+        }
+
+        AnnotatedLog { text: output, stats }
+    }
+}
+
+/// 为日志文本中的 ID 追加解析出的名称和类型
+///
+/// # 参数
+/// * `text` - 日志文本
+/// * `format` - 日志格式，可选值: "generic", "wwise"
+/// * `patterns` - 自定义正则表达式，必须包含命名分组 `id`
+/// * `project_dir` - Wwise 工程目录，可为空
+/// * `bank_dir` - Bank 目录，可为空
+///
+/// # 返回
+/// * `Ok(AnnotatedLog)` - 注释后的文本和统计信息
+/// * `Err(String)` - 注释失败，返回错误信息
+#[tauri::command]
+pub fn annotate_log(
+    text: String,
+    format: String,
+    patterns: Vec<String>,
+    project_dir: Option<String>,
+    bank_dir: Option<String>,
+) -> Result<AnnotatedLog, String> {
+    let resolver = IdResolver::load(project_dir.as_deref(), bank_dir.as_deref())?;
+    let annotator = LogAnnotator::new(resolver, &format, &patterns)?;
+    Ok(annotator.annotate(&text))
+}
+
+/// 读取日志文件，注释后写入输出文件
+///
+/// # 参数
+/// * `input_path` - 日志文件路径
+/// * `output_path` - 输出文件路径
+/// * 其余参数同 [`annotate_log`]
+///
+/// # 返回
+/// * `Ok(AnnotationStats)` - 统计信息
+/// * `Err(String)` - 注释失败，返回错误信息
+#[tauri::command]
+pub fn annotate_log_file(
+    input_path: String,
+    output_path: String,
+    format: String,
+    patterns: Vec<String>,
+    project_dir: Option<String>,
+    bank_dir: Option<String>,
+) -> Result<AnnotationStats, String> {
+    let bytes = fs::read(&input_path).map_err(|e| format!("读取文件失败: {}", e))?;
+    // 日志中可能混有非 UTF-8 字节，按有损方式读取
+    let text = String::from_utf8_lossy(&bytes);
+
+    let annotated = annotate_log(text.into_owned(), format, patterns, project_dir, bank_dir)?;
+    fs::write(&output_path, annotated.text).map_err(|e| format!("写入文件失败: {}", e))?;
+
+    Ok(annotated.stats)
+}
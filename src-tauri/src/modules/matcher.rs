@@ -0,0 +1,205 @@
+use regex::{Regex, RegexBuilder};
+use crate::modules::id_input::{normalize_id_input, IdInterpretation};
+use crate::modules::index::IndexedObject;
+use crate::modules::query::{is_structured_query, search_query};
+use crate::modules::types::{SearchResponse, SearchResult};
+
+/// GUID 子串搜索的最短查询长度，过短的查询几乎会命中所有对象
+pub const MIN_GUID_SUBSTRING_LEN: usize = 6;
+
+/// 匹配方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMode {
+    /// 数字 ID（ShortID、MediaID）精确匹配，GUID 子串匹配
+    Auto,
+    Exact,
+    Prefix,
+    Contains,
+    Regex,
+}
+
+impl MatchMode {
+    /// 解析匹配方式，可选值: "auto", "exact", "prefix", "contains", "regex"；为空时为 auto
+    pub fn parse(name: Option<&str>) -> Result<Self, String> {
+        match name.map(|n| n.to_lowercase()).as_deref() {
+            None | Some("") | Some("auto") => Ok(MatchMode::Auto),
+            Some("exact") => Ok(MatchMode::Exact),
+            Some("prefix") => Ok(MatchMode::Prefix),
+            Some("contains") => Ok(MatchMode::Contains),
+            Some("regex") => Ok(MatchMode::Regex),
+            Some(other) => Err(format!(
+                "不支持的匹配方式: {}，可选值: auto, exact, prefix, contains, regex",
+                other
+            )),
+        }
+    }
+
+    /// 指定 ID 类型实际使用的匹配方式
+    fn resolve(self, id_type: &str) -> MatchMode {
+        match (self, id_type) {
+            (MatchMode::Auto, "GUID") => MatchMode::Contains,
+            (MatchMode::Auto, _) => MatchMode::Exact,
+            (mode, _) => mode,
+        }
+    }
+}
+
+/// 匹配等级，数值越小越靠前
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchRank {
+    Exact,
+    Prefix,
+    Partial,
+}
+
+/// ID 匹配器
+pub struct IdMatcher {
+    mode: MatchMode,
+    query: String,
+    regex: Option<Regex>,
+}
+
+impl IdMatcher {
+    pub fn new(query: &str, mode: MatchMode) -> Result<Self, String> {
+        let regex = if mode == MatchMode::Regex {
+            let regex = RegexBuilder::new(query)
+                .case_insensitive(true)
+                .build()
+                .map_err(|e| format!("正则表达式无效: {}", e))?;
+            Some(regex)
+        } else {
+            None
+        };
+        Ok(IdMatcher { mode, query: normalize(query), regex })
+    }
+
+    /// 指定 ID 类型能否参与搜索，不能时返回原因
+    ///
+    /// 数字 ID 只接受纯数字输入（正则除外），GUID 子串搜索要求查询达到最短长度。
+    pub fn check(&self, id_type: &str) -> Result<(), String> {
+        match (self.mode.resolve(id_type), id_type) {
+            (MatchMode::Regex, _) => Ok(()),
+            (_, "ShortID" | "MediaID") if !self.query.chars().all(|c| c.is_ascii_digit()) => {
+                Err(format!("{} 只能搜索数字", id_type))
+            }
+            (MatchMode::Prefix | MatchMode::Contains, "GUID")
+                if self.query.chars().count() < MIN_GUID_SUBSTRING_LEN =>
+            {
+                Err(format!("GUID 子串搜索至少需要 {} 个字符", MIN_GUID_SUBSTRING_LEN))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 计算匹配等级，不匹配时返回 None
+    pub fn rank(&self, value: &str, id_type: &str) -> Option<MatchRank> {
+        if value.is_empty() {
+            return None;
+        }
+        let value = normalize(value);
+        if value == self.query {
+            return Some(MatchRank::Exact);
+        }
+        match self.mode.resolve(id_type) {
+            MatchMode::Prefix if value.starts_with(&self.query) => Some(MatchRank::Prefix),
+            MatchMode::Contains if value.starts_with(&self.query) => Some(MatchRank::Prefix),
+            MatchMode::Contains if value.contains(&self.query) => Some(MatchRank::Partial),
+            MatchMode::Regex if self.regex.as_ref().is_some_and(|r| r.is_match(&value)) => {
+                Some(MatchRank::Partial)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 统一大小写并去掉 GUID 的花括号
+fn normalize(value: &str) -> String {
+    value.trim().trim_start_matches('{').trim_end_matches('}').to_lowercase()
+}
+
+/// 在索引中按 ID 类型和匹配方式搜索，完全匹配的结果排在最前面
+///
+/// 输入为结构化查询（如 `type:Event name:Play_*`）时交给 [`search_query`]，忽略 ID 类型和匹配方式。
+/// 否则先经过 [`normalize_id_input`] 规范化（正则表达式除外），每种解释只搜索它适用的 ID 类型。
+/// 同一个对象的多种 ID 同时命中时只返回一次，取最好的匹配等级。
+/// 选中的 ID 类型全部不能参与搜索时（例如只选了 ShortID 却输入了非数字），返回第一个原因。
+pub fn search_objects(
+    objects: &[IndexedObject],
+    id_string: &str,
+    id_types: &[String],
+    match_mode: MatchMode,
+) -> Result<SearchResponse, String> {
+    if match_mode != MatchMode::Regex && is_structured_query(id_string) {
+        return search_query(objects, id_string);
+    }
+
+    let interpretations = if match_mode == MatchMode::Regex {
+        vec![IdInterpretation::partial(id_string.trim())]
+    } else {
+        normalize_id_input(id_string)
+    };
+
+    let mut queries: Vec<(IdMatcher, Vec<&str>)> = Vec::new();
+    let mut first_error = None;
+    for interpretation in &interpretations {
+        let matcher = IdMatcher::new(&interpretation.value, match_mode)?;
+        let mut active_types = Vec::new();
+        for id_type in id_types.iter().filter(|t| interpretation.id_types.contains(t)) {
+            match matcher.check(id_type) {
+                Ok(()) => active_types.push(id_type.as_str()),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        if !active_types.is_empty() {
+            queries.push((matcher, active_types));
+        }
+    }
+    if queries.is_empty() {
+        return match first_error {
+            Some(e) => Err(e),
+            None => Ok(SearchResponse { interpretations, results: Vec::new(), scan: None }),
+        };
+    }
+
+    // (等级, 对象, 命中的 MediaID)
+    let mut ranked: Vec<(MatchRank, &IndexedObject, Option<&str>)> = objects
+        .iter()
+        .filter_map(|object| {
+            queries
+                .iter()
+                .flat_map(|(matcher, active_types)| {
+                    active_types.iter().flat_map(move |id_type| {
+                        let values: Vec<(&str, Option<&str>)> = match *id_type {
+                            "GUID" => vec![(object.guid.as_str(), None)],
+                            "ShortID" => vec![(object.short_id.as_str(), None)],
+                            "MediaID" => object.media_ids.iter().map(|id| (id.as_str(), Some(id.as_str()))).collect(),
+                            _ => Vec::new(),
+                        };
+                        values
+                            .into_iter()
+                            .filter_map(move |(value, media_id)| Some((matcher.rank(value, id_type)?, media_id)))
+                    })
+                })
+                .min_by_key(|(rank, _)| *rank)
+                .map(|(rank, media_id)| (rank, object, media_id))
+        })
+        .collect();
+
+    // 稳定排序，同一等级保持索引中的顺序
+    ranked.sort_by_key(|(rank, _, _)| *rank);
+
+    // 源有多个 MediaID 时，结果显示命中的那一个
+    let results = ranked
+        .into_iter()
+        .map(|(_, object, media_id)| {
+            let mut result = SearchResult::from(object);
+            if let Some(media_id) = media_id {
+                result.media_id = media_id.to_string();
+            }
+            result
+        })
+        .collect();
+    Ok(SearchResponse { interpretations, results, scan: None })
+}
@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use roxmltree::{Document, Node};
+use serde::Serialize;
+use serde_json::Value;
+use crate::modules::bank_metadata::discover_bank_metadata;
+use crate::modules::event_report::{json_array, json_text};
+use crate::modules::object_details::child;
+
+/// 媒体在 SoundBank 中的存放方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaStorage {
+    /// 完整写入 SoundBank
+    Memory,
+    /// 流播放，开头的预取部分写入 SoundBank
+    Prefetch,
+    /// 流播放，不占用 SoundBank 内存
+    Streamed,
+}
+
+/// SoundBank 中的一个媒体
+#[derive(Debug, Clone, Serialize)]
+pub struct BankMediaEntry {
+    pub id: String,
+    pub name: String,
+    pub language: String,
+    pub storage: MediaStorage,
+    /// 写入 SoundBank 的字节数，流播放的媒体为 0；无法确定时为 `None`
+    pub memory_bytes: Option<u64>,
+}
+
+/// 一个 SoundBank 文件的媒体内存
+#[derive(Debug, Clone, Serialize)]
+pub struct BankMemory {
+    pub name: String,
+    pub language: String,
+    /// 相对于平台目录的 .bnk 路径
+    pub path: String,
+    /// 完整写入和预取部分的总字节数
+    pub memory_bytes: u64,
+    pub prefetch_bytes: u64,
+    pub media_count: usize,
+    pub streamed_count: usize,
+    /// 无法确定大小的媒体数，这些媒体不计入总字节数
+    pub unknown_sizes: usize,
+    /// 超过内存预算
+    pub over_budget: bool,
+    #[serde(skip)]
+    pub media: Vec<BankMediaEntry>,
+}
+
+/// 写入多个 SoundBank 的媒体
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateMedia {
+    pub id: String,
+    pub name: String,
+    pub language: String,
+    /// 包含该媒体的 SoundBank
+    pub banks: Vec<String>,
+    /// 各 SoundBank 中写入的字节数之和
+    pub total_bytes: u64,
+    /// 只保留一份时可以节省的字节数
+    pub wasted_bytes: u64,
+}
+
+/// 一个平台的媒体报告
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformMediaReport {
+    pub platform: String,
+    pub directory: String,
+    pub banks: Vec<BankMemory>,
+    /// 按浪费的字节数从大到小排序
+    pub duplicates: Vec<DuplicateMedia>,
+    pub wasted_bytes: u64,
+}
+
+/// 媒体重复和内存报告
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaReport {
+    /// 每个 SoundBank 的内存预算（字节），为空时不检查
+    pub budget: Option<u64>,
+    pub platforms: Vec<PlatformMediaReport>,
+}
+
+/// 元数据中记录的媒体，大小稍后确定
+struct MediaRecord {
+    id: String,
+    name: String,
+    language: String,
+    storage: MediaStorage,
+    prefetch_size: Option<u64>,
+    /// 相对于平台目录的 .wem 路径
+    path: String,
+}
+
+struct BankRecord {
+    name: String,
+    language: String,
+    path: String,
+    media: Vec<MediaRecord>,
+}
+
+/// 生成媒体报告
+///
+/// `directory` 可以是一个平台的 Bank 目录，也可以是包含多个平台目录的 GeneratedSoundBanks 目录。
+pub fn build_media_report(directory: &Path, budget: Option<u64>) -> Result<MediaReport, String> {
+    let platforms: Vec<PathBuf> = if discover_bank_metadata(directory)?.has_bank_metadata() {
+        vec![directory.to_path_buf()]
+    } else {
+        let entries = fs::read_dir(directory).map_err(|e| format!("无法读取目录: {}", e))?;
+        let mut dirs: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_dir() && discover_bank_metadata(p).is_ok_and(|f| f.has_bank_metadata()))
+            .collect();
+        dirs.sort();
+        dirs
+    };
+    if platforms.is_empty() {
+        return Err("未找到 SoundbanksInfo 或单独的 Bank 元数据文件".to_string());
+    }
+
+    let platforms = platforms
+        .iter()
+        .map(|dir| platform_report(dir, budget))
+        .collect::<Result<_, _>>()?;
+    Ok(MediaReport { budget, platforms })
+}
+
+fn platform_report(directory: &Path, budget: Option<u64>) -> Result<PlatformMediaReport, String> {
+    let files = discover_bank_metadata(directory)?;
+    let paths: Vec<PathBuf> = match files.soundbanks_info {
+        Some(path) => vec![path],
+        None => files.bank_files,
+    };
+
+    let mut platform = String::new();
+    let mut banks = Vec::new();
+    for path in &paths {
+        let contents = fs::read_to_string(path).map_err(|e| format!("读取文件 {} 失败: {}", path.display(), e))?;
+        let (name, records) = if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+            let json: Value = serde_json::from_str(&contents)
+                .map_err(|e| format!("解析 JSON 文件 {} 失败: {}", path.display(), e))?;
+            read_json_media(&json)
+        } else {
+            let doc = Document::parse(&contents)
+                .map_err(|e| format!("解析 XML 文件 {} 失败: {}", path.display(), e))?;
+            read_xml_media(&doc)
+        };
+        if platform.is_empty() {
+            platform = name;
+        }
+        // 元数据中的 .bnk 和 .wem 路径都相对平台目录，单独的 Bank 元数据文件可能位于语言子目录中
+        banks.extend(records.into_iter().map(|record| bank_memory(directory, record, budget)));
+    }
+    if platform.is_empty() {
+        platform = directory.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    }
+
+    let duplicates = find_duplicates(&banks);
+    Ok(PlatformMediaReport {
+        platform,
+        directory: directory.to_string_lossy().to_string(),
+        wasted_bytes: duplicates.iter().map(|d| d.wasted_bytes).sum(),
+        banks,
+        duplicates,
+    })
+}
+
+fn read_json_media(json: &Value) -> (String, Vec<BankRecord>) {
+    let Some(info) = json.get("SoundBanksInfo") else {
+        return (String::new(), Vec::new());
+    };
+    let banks = json_array(info, &["SoundBanks"])
+        .into_iter()
+        .map(|bank| {
+            let lists = [
+                ("Media", None),
+                ("IncludedMemoryFiles", Some(MediaStorage::Memory)),
+                ("IncludedPrefetchFiles", Some(MediaStorage::Prefetch)),
+                ("ReferencedStreamedFiles", Some(MediaStorage::Streamed)),
+            ];
+            let mut media = Vec::new();
+            for (key, storage) in lists {
+                for entry in json_array(bank, &[key]) {
+                    let prefetch_size = json_text(entry, "PrefetchSize").parse().ok();
+                    let storage = storage.unwrap_or_else(|| {
+                        media_storage(&json_text(entry, "Streaming"), &json_text(entry, "Location"), prefetch_size)
+                    });
+                    media.push(MediaRecord {
+                        id: json_text(entry, "Id"),
+                        name: json_text(entry, "ShortName"),
+                        language: json_text(entry, "Language"),
+                        storage,
+                        prefetch_size,
+                        path: json_text(entry, "Path"),
+                    });
+                }
+            }
+            BankRecord {
+                name: json_text(bank, "ShortName"),
+                language: json_text(bank, "Language"),
+                path: json_text(bank, "Path"),
+                media,
+            }
+        })
+        .collect();
+    (json_text(info, "Platform"), banks)
+}
+
+fn read_xml_media(doc: &Document) -> (String, Vec<BankRecord>) {
+    let text = |node: &Node, tag: &str| child(node, tag).and_then(|n| n.text()).unwrap_or("").trim().to_string();
+    let attribute = |node: &Node, name: &str| node.attribute(name).unwrap_or("").to_string();
+
+    let banks = doc
+        .descendants()
+        .filter(|n| n.has_tag_name("SoundBank"))
+        .map(|bank| {
+            let lists = [
+                ("Media", None),
+                ("IncludedMemoryFiles", Some(MediaStorage::Memory)),
+                ("IncludedPrefetchFiles", Some(MediaStorage::Prefetch)),
+                ("ReferencedStreamedFiles", Some(MediaStorage::Streamed)),
+            ];
+            let mut media = Vec::new();
+            for (tag, storage) in lists {
+                let Some(list) = child(&bank, tag) else {
+                    continue;
+                };
+                for file in list.children().filter(|n| n.has_tag_name("File")) {
+                    // 新版本的 PrefetchSize 是属性，旧版本是子节点
+                    let prefetch_size =
+                        file.attribute("PrefetchSize").map(str::to_string).unwrap_or_else(|| text(&file, "PrefetchSize"));
+                    let prefetch_size = prefetch_size.parse().ok();
+                    let storage = storage.unwrap_or_else(|| {
+                        media_storage(&attribute(&file, "Streaming"), &attribute(&file, "Location"), prefetch_size)
+                    });
+                    media.push(MediaRecord {
+                        id: attribute(&file, "Id"),
+                        name: text(&file, "ShortName"),
+                        language: attribute(&file, "Language"),
+                        storage,
+                        prefetch_size,
+                        path: text(&file, "Path"),
+                    });
+                }
+            }
+            BankRecord {
+                name: text(&bank, "ShortName"),
+                language: attribute(&bank, "Language"),
+                path: text(&bank, "Path"),
+                media,
+            }
+        })
+        .collect();
+    (attribute(&doc.root_element(), "Platform"), banks)
+}
+
+/// 新版本 `Media` 列表中媒体的存放方式
+fn media_storage(streaming: &str, location: &str, prefetch_size: Option<u64>) -> MediaStorage {
+    let streaming = streaming.eq_ignore_ascii_case("true") || location.eq_ignore_ascii_case("Loose");
+    match (streaming, prefetch_size) {
+        (false, _) if location.is_empty() || location.eq_ignore_ascii_case("Memory") => MediaStorage::Memory,
+        // 在其他 SoundBank 中（Location 为 OtherBank）的媒体不占用这个 SoundBank 的内存
+        (false, _) => MediaStorage::Streamed,
+        (true, Some(size)) if size > 0 => MediaStorage::Prefetch,
+        (true, _) => MediaStorage::Streamed,
+    }
+}
+
+/// 确定每个媒体写入 SoundBank 的字节数并汇总
+///
+/// 大小依次取自 .bnk 的 DIDX（媒体索引）、元数据中的 PrefetchSize 和 .wem 文件。
+fn bank_memory(base: &Path, record: BankRecord, budget: Option<u64>) -> BankMemory {
+    let didx = read_didx(&base.join(normalize_path(&record.path))).unwrap_or_default();
+    let media: Vec<BankMediaEntry> = record
+        .media
+        .into_iter()
+        .map(|m| {
+            let memory_bytes = match m.storage {
+                MediaStorage::Streamed => Some(0),
+                MediaStorage::Prefetch => didx.get(&m.id).copied().or(m.prefetch_size),
+                MediaStorage::Memory => didx
+                    .get(&m.id)
+                    .copied()
+                    .or_else(|| fs::metadata(base.join(normalize_path(&m.path))).ok().map(|f| f.len())),
+            };
+            BankMediaEntry { id: m.id, name: m.name, language: m.language, storage: m.storage, memory_bytes }
+        })
+        .collect();
+
+    let bytes = |storage: MediaStorage| -> u64 {
+        media.iter().filter(|m| m.storage == storage).filter_map(|m| m.memory_bytes).sum()
+    };
+    let prefetch_bytes = bytes(MediaStorage::Prefetch);
+    let memory_bytes = bytes(MediaStorage::Memory) + prefetch_bytes;
+    BankMemory {
+        name: record.name,
+        language: record.language,
+        path: record.path,
+        memory_bytes,
+        prefetch_bytes,
+        media_count: media.len(),
+        streamed_count: media.iter().filter(|m| m.storage == MediaStorage::Streamed).count(),
+        unknown_sizes: media.iter().filter(|m| m.memory_bytes.is_none()).count(),
+        over_budget: budget.is_some_and(|budget| memory_bytes > budget),
+        media,
+    }
+}
+
+/// 元数据中的路径可能使用 Windows 分隔符
+fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+/// 读取 .bnk 的 DIDX 块：每个媒体的 MediaID 和大小
+///
+/// .bnk 由若干块组成，每块为 4 字节标签、4 字节小端长度和数据；DIDX 中每项为 MediaID、偏移和大小（各 4 字节）。
+pub fn read_didx(path: &Path) -> Result<HashMap<String, u64>, String> {
+    let bytes = fs::read(path).map_err(|e| format!("读取文件 {} 失败: {}", path.display(), e))?;
+    let read_u32 = |at: usize| -> Option<u32> {
+        bytes.get(at..at + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+
+    let mut sizes = HashMap::new();
+    let mut pos = 0;
+    while let (Some(tag), Some(length)) = (bytes.get(pos..pos + 4), read_u32(pos + 4)) {
+        let start = pos + 8;
+        let end = start.saturating_add(length as usize).min(bytes.len());
+        if tag == b"DIDX" {
+            for entry in (start..end).step_by(12) {
+                if let (Some(id), Some(size)) = (read_u32(entry), read_u32(entry + 8)) {
+                    sizes.insert(id.to_string(), size as u64);
+                }
+            }
+            break;
+        }
+        pos = end;
+    }
+    Ok(sizes)
+}
+
+/// 在多个 SoundBank 中占用内存的媒体；本地化 SoundBank 的每个语言文件单独计算
+fn find_duplicates(banks: &[BankMemory]) -> Vec<DuplicateMedia> {
+    let mut embedded: Vec<(&BankMediaEntry, Vec<(&str, u64)>)> = Vec::new();
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    for bank in banks {
+        for media in &bank.media {
+            let Some(bytes) = media.memory_bytes.filter(|b| *b > 0) else {
+                continue;
+            };
+            let i = *positions.entry(media.id.as_str()).or_insert_with(|| {
+                embedded.push((media, Vec::new()));
+                embedded.len() - 1
+            });
+            embedded[i].1.push((bank.name.as_str(), bytes));
+        }
+    }
+
+    let mut duplicates: Vec<DuplicateMedia> = embedded
+        .into_iter()
+        .filter(|(_, banks)| banks.len() > 1)
+        .map(|(media, banks)| {
+            let total_bytes: u64 = banks.iter().map(|(_, b)| b).sum();
+            let largest = banks.iter().map(|(_, b)| *b).max().unwrap_or(0);
+            DuplicateMedia {
+                id: media.id.clone(),
+                name: media.name.clone(),
+                language: media.language.clone(),
+                banks: banks.iter().map(|(name, _)| name.to_string()).collect(),
+                total_bytes,
+                wasted_bytes: total_bytes - largest,
+            }
+        })
+        .collect();
+    duplicates.sort_by_key(|d| std::cmp::Reverse(d.wasted_bytes));
+    duplicates
+}
+
+/// 解析大小，如 `8MB`、`512KB`、`1.5 MiB`、`1048576`；单位按 1024 进位
+pub fn parse_size(text: &str) -> Result<u64, String> {
+    let text = text.trim();
+    let split = text.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let number: f64 = number.parse().map_err(|_| format!("无法解析大小: {}", text))?;
+    let multiplier = match unit.trim().to_lowercase().as_str() {
+        "" | "b" => 1u64,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        _ => return Err(format!("无法解析大小: {}，单位可选 B、KB、MB、GB", text)),
+    };
+    Ok((number * multiplier as f64).round() as u64)
+}
+
+/// 生成 SoundBank 之间的媒体重复和内存报告
+///
+/// # 参数
+/// * `directory` - 平台的 Bank 目录，或包含多个平台目录的 GeneratedSoundBanks 目录
+/// * `budget` - 每个 SoundBank 的媒体内存预算（字节），为空时不检查
+#[tauri::command]
+pub fn get_media_report(directory: String, budget: Option<u64>) -> Result<MediaReport, String> {
+    build_media_report(Path::new(&directory), budget)
+}
@@ -2,10 +2,54 @@ pub mod types;
 pub mod utils;
 pub mod validators;
 pub mod wwise_search;
+pub mod bank_metadata;
 pub mod bank_search;
+pub mod index;
+pub mod matcher;
+pub mod id_input;
+pub mod query;
+pub mod ids_header;
+pub mod id_resolver;
+pub mod log_annotator;
+pub mod exporter;
+pub mod profiles;
+pub mod team_config;
+pub mod wproj;
+pub mod walker;
+pub mod schema;
+pub mod waapi_client;
+pub mod waapi;
+pub mod waapi_live;
+pub mod object_details;
+pub mod inheritance;
+pub mod hierarchy;
+pub mod event_report;
+pub mod graph;
+pub mod routing;
+pub mod dependencies;
+pub mod bank_inclusion;
+pub mod media_report;
 
 // 重新导出主要类型和函数
 // pub use types::SearchResult;
 pub use validators::{validate_wwise_directory, validate_bank_directory};
 pub use wwise_search::search_wwise_project;
-pub use bank_search::search_bank_directory;
\ No newline at end of file
+pub use bank_search::search_bank_directory;
+pub use ids_header::{search_ids_header, generate_id_constants};
+pub use id_resolver::resolve_ids_in_text;
+pub use id_input::interpret_id_input;
+pub use log_annotator::{annotate_log, annotate_log_file};
+pub use exporter::export_results;
+pub use waapi::{search_waapi, test_waapi_connection, show_in_wwise};
+pub use waapi_live::{start_live_sync, stop_live_sync};
+pub use object_details::get_object_details;
+pub use hierarchy::{get_roots, get_children, get_parent};
+pub use event_report::get_event_report;
+pub use routing::{get_bus_routing, export_bus_routing};
+pub use dependencies::{get_dependency_graph, export_dependency_graph, find_dependency_paths};
+pub use bank_inclusion::{get_bank_inclusions, find_object_banks};
+pub use media_report::get_media_report;
+pub use profiles::{
+    list_profiles, save_profile, load_profile, delete_profile,
+    import_profiles, import_legacy_profiles, export_profiles,
+};
\ No newline at end of file
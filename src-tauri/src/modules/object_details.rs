@@ -0,0 +1,420 @@
+use std::collections::HashMap;
+use std::path::Path;
+use roxmltree::{Document, Node};
+use serde::Serialize;
+use crate::modules::id_resolver::canonical_guid;
+use crate::modules::index::{
+    index_project_documents, is_definition_node, load_project_documents, IndexedObject, ProjectDocuments,
+};
+use crate::modules::inheritance::{evaluate_effective_values, EffectiveValue};
+use crate::modules::utils::is_valid_guid;
+
+/// 属性值
+#[derive(Debug, Clone, Serialize)]
+pub struct PropertyValue {
+    pub name: String,
+    /// .wwu 中记录的类型，如 `Real64`、`int32`、`bool`
+    pub value_type: String,
+    /// 默认值；只设置了平台值时为空
+    pub value: String,
+    /// 按平台设置的值
+    pub platform_values: Vec<PlatformValue>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformValue {
+    /// 平台名称或 GUID
+    pub platform: String,
+    pub value: String,
+}
+
+/// 引用的对象，目标在工程索引中解析
+#[derive(Debug, Clone, Serialize)]
+pub struct ObjectReference {
+    /// 引用名称，如 `OutputBus`、`Conversion`、`Target`
+    pub name: String,
+    pub target_name: String,
+    pub target_type: String,
+    pub target_guid: String,
+    /// 目标在工程中的路径；未在工程中找到时为空
+    pub target_path: String,
+    /// 目标在工程中找到
+    pub resolved: bool,
+    /// 目标是定义在该对象内的自定义对象（如自定义 Effect）
+    pub embedded: bool,
+}
+
+/// RTPC 曲线上的点
+#[derive(Debug, Clone, Serialize)]
+pub struct CurvePoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// RTPC：由控制输入驱动的属性
+#[derive(Debug, Clone, Serialize)]
+pub struct RtpcBinding {
+    /// 被控制的属性
+    pub property: String,
+    /// 控制输入（Game Parameter、LFO 等）
+    pub control_input: Option<ObjectReference>,
+    pub points: Vec<CurvePoint>,
+}
+
+/// 某个 State 下的属性值
+#[derive(Debug, Clone, Serialize)]
+pub struct StatePropertyValue {
+    pub state_group: String,
+    pub state: String,
+    pub state_guid: String,
+    pub property: String,
+    pub value: String,
+}
+
+/// 直接子对象
+#[derive(Debug, Clone, Serialize)]
+pub struct ChildObject {
+    pub name: String,
+    pub object_type: String,
+    pub guid: String,
+    pub short_id: String,
+}
+
+/// 音频源：AudioFileSource 或插件源
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceInfo {
+    pub name: String,
+    pub object_type: String,
+    pub guid: String,
+    /// 语言，非语音对象为 `SFX`
+    pub language: String,
+    pub audio_file: String,
+    pub media_id: String,
+    /// 插件源的插件名称
+    pub plugin: String,
+    /// 是否为该语言当前使用的源
+    pub active: bool,
+}
+
+/// .wwu 中为一个对象定义的全部内容
+#[derive(Debug, Clone, Serialize)]
+pub struct ObjectDetails {
+    pub object: IndexedObject,
+    /// 定义该对象的 .wwu 文件
+    pub work_unit_file: String,
+    pub properties: Vec<PropertyValue>,
+    pub references: Vec<ObjectReference>,
+    /// 考虑上级对象继承后实际使用的属性和引用
+    pub effective_values: Vec<EffectiveValue>,
+    pub rtpcs: Vec<RtpcBinding>,
+    /// 使用的 State Group
+    pub state_groups: Vec<ObjectReference>,
+    pub state_properties: Vec<StatePropertyValue>,
+    pub children: Vec<ChildObject>,
+    pub notes: String,
+    pub sources: Vec<SourceInfo>,
+}
+
+/// 按 GUID 查找工程索引中的对象
+pub struct ObjectLookup<'a> {
+    by_guid: HashMap<&'a str, &'a IndexedObject>,
+}
+
+impl<'a> ObjectLookup<'a> {
+    pub fn new(objects: &'a [IndexedObject]) -> Self {
+        let mut by_guid = HashMap::with_capacity(objects.len());
+        for object in objects {
+            by_guid.entry(object.guid.as_str()).or_insert(object);
+        }
+        ObjectLookup { by_guid }
+    }
+
+    pub fn get(&self, guid: &str) -> Option<&'a IndexedObject> {
+        self.by_guid.get(canonical_guid(guid).as_str()).copied()
+    }
+}
+
+/// 读取对象在 .wwu 中的详细定义
+pub fn read_object_details(
+    documents: &ProjectDocuments,
+    objects: &[IndexedObject],
+    guid: &str,
+) -> Result<ObjectDetails, String> {
+    if !is_valid_guid(guid) {
+        return Err(format!("无效的 GUID: {}", guid));
+    }
+    let guid = canonical_guid(guid);
+    let lookup = ObjectLookup::new(objects);
+    let object = lookup.get(&guid).ok_or_else(|| format!("工程中未找到对象 {}", guid))?;
+
+    for file in documents.files.iter().filter(|f| f.contents.contains(guid.as_str())) {
+        let Ok(doc) = Document::parse(&file.contents) else {
+            continue;
+        };
+        if let Some(node) = find_definition(&doc, &guid) {
+            return Ok(collect_details(&node, object, &file.path, &lookup));
+        }
+    }
+    Err(format!("未找到对象 {} 的定义", guid))
+}
+
+/// 查找 GUID 对应的定义节点
+pub fn find_definition<'a, 'input>(doc: &'a Document<'input>, guid: &str) -> Option<Node<'a, 'input>> {
+    doc.descendants()
+        .find(|n| is_definition_node(n) && n.attribute("ID").is_some_and(|id| id.eq_ignore_ascii_case(guid)))
+}
+
+fn collect_details(node: &Node, object: &IndexedObject, file: &Path, lookup: &ObjectLookup) -> ObjectDetails {
+    let (state_groups, state_properties) = read_state_info(node, lookup);
+    let active_sources: Vec<&str> = child(node, "ActiveSourceList")
+        .map(|list| elements(&list, "ActiveSource").filter_map(|n| n.attribute("ID")).collect())
+        .unwrap_or_default();
+
+    let mut children = Vec::new();
+    let mut sources = Vec::new();
+    if let Some(list) = child(node, "ChildrenList") {
+        for item in list.children().filter(is_definition_node) {
+            if is_source(&item) {
+                sources.push(read_source(&item, &active_sources));
+            } else {
+                children.push(ChildObject {
+                    name: item.attribute("Name").unwrap_or("").to_string(),
+                    object_type: item.tag_name().name().to_string(),
+                    guid: item.attribute("ID").unwrap_or("").to_string(),
+                    short_id: item.attribute("ShortID").unwrap_or("").to_string(),
+                });
+            }
+        }
+    }
+
+    let notes = ["Notes", "Comment"]
+        .iter()
+        .find_map(|tag| child(node, tag))
+        .and_then(|n| n.text())
+        .unwrap_or("")
+        .trim()
+        .to_string();
+
+    ObjectDetails {
+        object: object.clone(),
+        work_unit_file: file.to_string_lossy().to_string(),
+        properties: read_properties(node),
+        references: read_references(node, lookup),
+        effective_values: evaluate_effective_values(node, lookup),
+        rtpcs: read_rtpcs(node, lookup),
+        state_groups,
+        state_properties,
+        children,
+        notes,
+        sources,
+    }
+}
+
+/// 直接子元素
+pub fn child<'a, 'input>(node: &Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.has_tag_name(tag))
+}
+
+/// 指定名称的所有直接子元素
+pub fn elements<'a, 'input: 'a>(node: &Node<'a, 'input>, tag: &'a str) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children().filter(move |n| n.has_tag_name(tag))
+}
+
+/// 读取 PropertyList 中的属性
+pub fn read_properties(node: &Node) -> Vec<PropertyValue> {
+    let Some(list) = child(node, "PropertyList") else {
+        return Vec::new();
+    };
+    elements(&list, "Property").map(|p| read_property(&p)).collect()
+}
+
+fn read_property(property: &Node) -> PropertyValue {
+    let mut value = property.attribute("Value").unwrap_or("").to_string();
+    let mut platform_values = Vec::new();
+    if let Some(list) = child(property, "ValueList") {
+        for item in elements(&list, "Value") {
+            let text = item.text().unwrap_or("").trim().to_string();
+            match item.attribute("Platform") {
+                Some(platform) => platform_values.push(PlatformValue { platform: platform.to_string(), value: text }),
+                None if value.is_empty() => value = text,
+                None => {}
+            }
+        }
+    }
+    PropertyValue {
+        name: property.attribute("Name").unwrap_or("").to_string(),
+        value_type: property.attribute("Type").unwrap_or("").to_string(),
+        value,
+        platform_values,
+    }
+}
+
+/// 读取 ReferenceList 中的引用
+pub fn read_references(node: &Node, lookup: &ObjectLookup) -> Vec<ObjectReference> {
+    let Some(list) = child(node, "ReferenceList") else {
+        return Vec::new();
+    };
+    elements(&list, "Reference")
+        .filter_map(|reference| read_reference(&reference, lookup))
+        .collect()
+}
+
+fn read_reference(reference: &Node, lookup: &ObjectLookup) -> Option<ObjectReference> {
+    let name = reference.attribute("Name").unwrap_or("");
+    if let Some(target) = child(reference, "ObjectRef") {
+        return Some(resolve_ref(name, &target, lookup));
+    }
+    // 自定义对象直接定义在引用中，如 <Custom><Effect .../></Custom>
+    let custom = child(reference, "Custom")?;
+    let target = custom.children().find(is_definition_node)?;
+    Some(ObjectReference {
+        name: name.to_string(),
+        target_name: target.attribute("Name").unwrap_or("").to_string(),
+        target_type: target.tag_name().name().to_string(),
+        target_guid: target.attribute("ID").unwrap_or("").to_string(),
+        target_path: String::new(),
+        resolved: true,
+        embedded: true,
+    })
+}
+
+/// 在工程索引中解析 ObjectRef、StateRef 等引用节点
+pub fn resolve_ref(name: &str, target: &Node, lookup: &ObjectLookup) -> ObjectReference {
+    let guid = target.attribute("ID").unwrap_or("");
+    match lookup.get(guid) {
+        Some(object) => ObjectReference {
+            name: name.to_string(),
+            target_name: object.name.clone(),
+            target_type: object.object_type.clone(),
+            target_guid: object.guid.clone(),
+            target_path: object.path.clone(),
+            resolved: true,
+            embedded: false,
+        },
+        None => ObjectReference {
+            name: name.to_string(),
+            target_name: target.attribute("Name").unwrap_or("").to_string(),
+            target_type: String::new(),
+            target_guid: guid.to_string(),
+            target_path: String::new(),
+            resolved: false,
+            embedded: false,
+        },
+    }
+}
+
+/// 读取 RTPC
+///
+/// 2017.1 之后 RTPC 位于 `ObjectLists/ObjectList[@Name="RTPC"]`，
+/// 更早的版本位于被控制属性的 `RTPCList` 中。
+pub fn read_rtpcs(node: &Node, lookup: &ObjectLookup) -> Vec<RtpcBinding> {
+    let mut rtpcs = Vec::new();
+    if let Some(lists) = child(node, "ObjectLists") {
+        for list in elements(&lists, "ObjectList").filter(|l| l.attribute("Name") == Some("RTPC")) {
+            for rtpc in list.descendants().filter(|n| n.has_tag_name("RTPC")) {
+                let property = read_properties(&rtpc)
+                    .into_iter()
+                    .find(|p| p.name == "PropertyName")
+                    .map(|p| p.value)
+                    .unwrap_or_default();
+                rtpcs.push(read_rtpc(&rtpc, property, lookup));
+            }
+        }
+    }
+    if let Some(list) = child(node, "PropertyList") {
+        for property in elements(&list, "Property") {
+            let Some(rtpc_list) = child(&property, "RTPCList") else {
+                continue;
+            };
+            let name = property.attribute("Name").unwrap_or("");
+            for rtpc in elements(&rtpc_list, "RTPC") {
+                rtpcs.push(read_rtpc(&rtpc, name.to_string(), lookup));
+            }
+        }
+    }
+    rtpcs
+}
+
+fn read_rtpc(rtpc: &Node, property: String, lookup: &ObjectLookup) -> RtpcBinding {
+    let control_input = child(rtpc, "ReferenceList")
+        .and_then(|list| elements(&list, "Reference").find(|r| r.attribute("Name") == Some("ControlInput")))
+        .and_then(|reference| read_reference(&reference, lookup));
+    let points = rtpc
+        .descendants()
+        .filter(|n| n.has_tag_name("Point"))
+        .filter_map(|point| {
+            let coordinate = |tag| child(&point, tag)?.text()?.trim().parse::<f64>().ok();
+            Some(CurvePoint { x: coordinate("XPos")?, y: coordinate("YPos")? })
+        })
+        .collect();
+    RtpcBinding { property, control_input, points }
+}
+
+/// 读取 StateInfo：使用的 State Group 和各 State 下的属性值
+pub fn read_state_info(node: &Node, lookup: &ObjectLookup) -> (Vec<ObjectReference>, Vec<StatePropertyValue>) {
+    let Some(info) = child(node, "StateInfo") else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let state_groups = info
+        .descendants()
+        .filter(|n| n.has_tag_name("StateGroupRef"))
+        .map(|group| resolve_ref("StateGroup", &group, lookup))
+        .collect();
+
+    let mut state_properties = Vec::new();
+    if let Some(list) = child(&info, "CustomStateList") {
+        for custom in elements(&list, "CustomState") {
+            let Some(state) = child(&custom, "StateRef") else {
+                continue;
+            };
+            let state = resolve_ref("State", &state, lookup);
+            let state_group = lookup.get(&state.target_guid).map(|s| s.parent.clone()).unwrap_or_default();
+            // 属性值位于内层的 CustomState 定义中
+            let Some(values) = elements(&custom, "CustomState").next() else {
+                continue;
+            };
+            for property in read_properties(&values) {
+                state_properties.push(StatePropertyValue {
+                    state_group: state_group.clone(),
+                    state: state.target_name.clone(),
+                    state_guid: state.target_guid.clone(),
+                    property: property.name,
+                    value: property.value,
+                });
+            }
+        }
+    }
+    (state_groups, state_properties)
+}
+
+fn is_source(node: &Node) -> bool {
+    matches!(node.tag_name().name(), "AudioFileSource" | "SourcePlugin" | "ExternalSource")
+}
+
+fn read_source(source: &Node, active_sources: &[&str]) -> SourceInfo {
+    let guid = source.attribute("ID").unwrap_or("");
+    let text = |tag| child(source, tag).and_then(|n| n.text()).unwrap_or("").trim().to_string();
+    SourceInfo {
+        name: source.attribute("Name").unwrap_or("").to_string(),
+        object_type: source.tag_name().name().to_string(),
+        guid: guid.to_string(),
+        language: text("Language"),
+        audio_file: text("AudioFile"),
+        media_id: child(source, "MediaIDList")
+            .and_then(|list| child(&list, "MediaID"))
+            .and_then(|n| n.attribute("ID"))
+            .unwrap_or("")
+            .to_string(),
+        plugin: source.attribute("PluginName").unwrap_or("").to_string(),
+        active: active_sources.iter().any(|id| id.eq_ignore_ascii_case(guid)),
+    }
+}
+
+/// 获取对象在 .wwu 中定义的全部内容：属性、引用、RTPC、State、子对象、注释和各语言的源
+#[tauri::command]
+pub fn get_object_details(directory: String, guid: String) -> Result<ObjectDetails, String> {
+    let documents = load_project_documents(Path::new(&directory))?;
+    let objects = index_project_documents(&documents);
+    read_object_details(&documents, &objects, &guid)
+}
@@ -0,0 +1,386 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// 当前的配置文件结构版本
+pub const PROFILE_SCHEMA_VERSION: u64 = 2;
+
+/// 应用标识，与 tauri.conf.json 中的 identifier 一致，用于定位应用配置目录
+const APP_IDENTIFIER: &str = "com.xmimu.wid";
+
+/// 应用配置目录中的配置文件名
+const PROFILES_FILE_NAME: &str = "profiles.toml";
+
+/// 便携模式的配置文件名：程序旁边存在该文件时使用它，而不是应用配置目录
+const PORTABLE_FILE_NAME: &str = "wid-profiles.toml";
+
+/// Wwise 工程设置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WwiseProfile {
+    pub proj_path: String,
+}
+
+/// WAAPI 连接设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WaapiProfile {
+    pub host: String,
+    pub port: u16,
+}
+
+impl Default for WaapiProfile {
+    fn default() -> Self {
+        WaapiProfile { host: "127.0.0.1".to_string(), port: 8080 }
+    }
+}
+
+/// Bank 目录设置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BankProfile {
+    pub dir_path: String,
+    pub ids_header_path: String,
+}
+
+/// 一个已保存的配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Profile {
+    pub wwise: WwiseProfile,
+    pub waapi: WaapiProfile,
+    pub bank: BankProfile,
+    /// 保存时间（ISO 8601）
+    pub saved_at: String,
+}
+
+/// 配置文件内容
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileStore {
+    pub schema_version: u64,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl Default for ProfileStore {
+    fn default() -> Self {
+        ProfileStore { schema_version: PROFILE_SCHEMA_VERSION, profiles: BTreeMap::new() }
+    }
+}
+
+/// 返回给前端的配置列表
+#[derive(Debug, Serialize)]
+pub struct ProfileList {
+    /// 配置文件路径
+    pub path: String,
+    /// 是否为程序旁边的便携配置文件
+    pub portable: bool,
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// 配置文件的位置：程序旁边有 `wid-profiles.toml` 时使用便携模式，否则使用应用配置目录
+pub fn profiles_path() -> Result<(PathBuf, bool), String> {
+    if let Some(exe_dir) = std::env::current_exe().ok().and_then(|exe| exe.parent().map(Path::to_path_buf)) {
+        let portable = exe_dir.join(PORTABLE_FILE_NAME);
+        if portable.is_file() {
+            return Ok((portable, true));
+        }
+    }
+
+    let config_dir = dirs::config_dir().ok_or_else(|| "无法确定应用配置目录".to_string())?;
+    Ok((config_dir.join(APP_IDENTIFIER).join(PROFILES_FILE_NAME), false))
+}
+
+/// 按扩展名解析 TOML 或 JSON 文本
+fn parse_document(contents: &str, path: &Path) -> Result<Value, String> {
+    let is_json = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    if is_json {
+        serde_json::from_str(contents).map_err(|e| format!("解析 JSON 文件失败: {}", e))
+    } else {
+        toml::from_str(contents).map_err(|e| format!("解析 TOML 文件失败: {}", e))
+    }
+}
+
+/// 将任意版本的配置数据迁移到当前版本
+///
+/// * 版本 1：前端 localStorage 中的 `saved_configs`，即 `{ 名称: 配置 }`，没有版本号，端口为字符串
+/// * 版本 2：`{ schemaVersion, profiles }`，端口为数字
+pub fn migrate(mut value: Value) -> Result<ProfileStore, String> {
+    let mut version = value.get("schemaVersion").and_then(Value::as_u64).unwrap_or(1);
+    if version > PROFILE_SCHEMA_VERSION {
+        return Err(format!(
+            "配置文件版本 {} 高于当前支持的版本 {}，请升级 wid",
+            version, PROFILE_SCHEMA_VERSION
+        ));
+    }
+
+    while version < PROFILE_SCHEMA_VERSION {
+        value = match version {
+            1 => migrate_v1_to_v2(value)?,
+            _ => return Err(format!("无法迁移版本 {} 的配置文件", version)),
+        };
+        version += 1;
+    }
+
+    serde_json::from_value(value).map_err(|e| format!("配置文件格式错误: {}", e))
+}
+
+fn migrate_v1_to_v2(value: Value) -> Result<Value, String> {
+    let Value::Object(mut profiles) = value else {
+        return Err("配置文件格式错误: 根节点必须是对象".to_string());
+    };
+    for profile in profiles.values_mut() {
+        let Some(waapi) = profile.get_mut("waapi").and_then(Value::as_object_mut) else {
+            continue;
+        };
+        if let Some(port) = waapi.get("port").and_then(Value::as_str) {
+            let port: u16 = port.trim().parse().unwrap_or(WaapiProfile::default().port);
+            waapi.insert("port".to_string(), json!(port));
+        }
+    }
+    Ok(json!({ "schemaVersion": 2, "profiles": profiles }))
+}
+
+impl ProfileStore {
+    /// 读取配置文件，不存在时返回空配置；旧版本会自动迁移
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.is_file() {
+            return Ok(ProfileStore::default());
+        }
+        let contents = fs::read_to_string(path).map_err(|e| format!("读取配置文件失败: {}", e))?;
+        if contents.trim().is_empty() {
+            return Ok(ProfileStore::default());
+        }
+        migrate(parse_document(&contents, path)?)
+    }
+
+    /// 读取默认位置的配置文件
+    pub fn load_default() -> Result<Self, String> {
+        let (path, _) = profiles_path()?;
+        Self::load(&path)
+    }
+
+    /// 写入配置文件（先写临时文件再替换，避免写到一半损坏原文件）
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("创建配置目录失败: {}", e))?;
+        }
+        let contents = self.render(path)?;
+        let temp_path = path.with_extension("tmp");
+        fs::write(&temp_path, contents).map_err(|e| format!("写入配置文件失败: {}", e))?;
+        fs::rename(&temp_path, path).map_err(|e| format!("写入配置文件失败: {}", e))
+    }
+
+    /// 按扩展名渲染为 TOML 或 JSON
+    fn render(&self, path: &Path) -> Result<String, String> {
+        let is_json = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if is_json {
+            serde_json::to_string_pretty(self).map_err(|e| format!("序列化配置失败: {}", e))
+        } else {
+            toml::to_string_pretty(self).map_err(|e| format!("序列化配置失败: {}", e))
+        }
+    }
+
+    /// 获取指定配置
+    pub fn get(&self, name: &str) -> Result<&Profile, String> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| format!("配置 \"{}\" 不存在", name))
+    }
+}
+
+/// 读取、修改并写回默认位置的配置文件
+fn update_store<T>(update: impl FnOnce(&mut ProfileStore) -> Result<T, String>) -> Result<T, String> {
+    let (path, _) = profiles_path()?;
+    let mut store = ProfileStore::load(&path)?;
+    let result = update(&mut store)?;
+    store.schema_version = PROFILE_SCHEMA_VERSION;
+    store.save(&path)?;
+    Ok(result)
+}
+
+fn validate_profile_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("请输入配置名称".to_string());
+    }
+    if name == "default" {
+        return Err("不能使用 \"default\" 作为配置名称".to_string());
+    }
+    Ok(())
+}
+
+/// 列出所有已保存的配置
+#[tauri::command]
+pub fn list_profiles() -> Result<ProfileList, String> {
+    let (path, portable) = profiles_path()?;
+    let store = ProfileStore::load(&path)?;
+    Ok(ProfileList {
+        path: path.to_string_lossy().to_string(),
+        portable,
+        profiles: store.profiles,
+    })
+}
+
+/// 保存配置，同名配置会被覆盖
+#[tauri::command]
+pub fn save_profile(name: String, profile: Profile) -> Result<(), String> {
+    validate_profile_name(&name)?;
+    update_store(|store| {
+        store.profiles.insert(name, profile);
+        Ok(())
+    })
+}
+
+/// 读取指定配置
+#[tauri::command]
+pub fn load_profile(name: String) -> Result<Profile, String> {
+    let store = ProfileStore::load_default()?;
+    store.get(&name).cloned()
+}
+
+/// 删除指定配置
+#[tauri::command]
+pub fn delete_profile(name: String) -> Result<(), String> {
+    update_store(|store| {
+        store
+            .profiles
+            .remove(&name)
+            .map(|_| ())
+            .ok_or_else(|| format!("配置 \"{}\" 不存在", name))
+    })
+}
+
+/// 从 TOML 或 JSON 文件导入配置（支持旧版本格式），同名配置会被覆盖
+///
+/// # 返回
+/// * `Ok(usize)` - 导入的配置数量，不包括跳过的 "default" 等无效名称
+/// * `Err(String)` - 导入失败，返回错误信息
+#[tauri::command]
+pub fn import_profiles(path: String) -> Result<usize, String> {
+    let path = Path::new(&path);
+    let contents = fs::read_to_string(path).map_err(|e| format!("读取文件失败: {}", e))?;
+    let imported = migrate(parse_document(&contents, path)?)?;
+    merge_profiles(imported)
+}
+
+/// 导入前端 localStorage 中旧的 `saved_configs`（版本 1 格式）
+#[tauri::command]
+pub fn import_legacy_profiles(profiles: Value) -> Result<usize, String> {
+    merge_profiles(migrate(profiles)?)
+}
+
+fn merge_profiles(imported: ProfileStore) -> Result<usize, String> {
+    update_store(|store| Ok(merge_into(store, imported)))
+}
+
+/// 将导入的配置合并到 `store`，跳过不能保存的名称（如 "default"），返回实际导入的数量
+fn merge_into(store: &mut ProfileStore, imported: ProfileStore) -> usize {
+    let mut count = 0;
+    for (name, profile) in imported.profiles {
+        if validate_profile_name(&name).is_ok() {
+            store.profiles.insert(name, profile);
+            count += 1;
+        }
+    }
+    count
+}
+
+/// 导出配置到 TOML 或 JSON 文件（格式由扩展名决定）
+///
+/// # 参数
+/// * `path` - 导出文件路径
+/// * `names` - 要导出的配置名称，为空时导出全部
+///
+/// # 返回
+/// * `Ok(usize)` - 导出的配置数量
+/// * `Err(String)` - 导出失败，返回错误信息
+#[tauri::command]
+pub fn export_profiles(path: String, names: Option<Vec<String>>) -> Result<usize, String> {
+    let store = ProfileStore::load_default()?;
+    let mut exported = ProfileStore::default();
+    match names {
+        Some(names) if !names.is_empty() => {
+            for name in names {
+                let profile = store.get(&name)?.clone();
+                exported.profiles.insert(name, profile);
+            }
+        }
+        _ => exported.profiles = store.profiles,
+    }
+
+    exported.save(Path::new(&path))?;
+    Ok(exported.profiles.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(host: &str) -> Profile {
+        Profile { waapi: WaapiProfile { host: host.to_string(), port: 8080 }, ..Default::default() }
+    }
+
+    #[test]
+    fn migrates_v1_saved_configs() {
+        let v1 = json!({
+            "Main": { "wwise": { "projPath": "C:/Game/Wwise" }, "waapi": { "host": "10.0.0.2", "port": " 8090 " } },
+            "Broken port": { "waapi": { "host": "localhost", "port": "abc" } },
+            "No waapi": { "bank": { "dirPath": "C:/Banks" } },
+        });
+        assert_eq!(migrate_v1_to_v2(v1.clone()).unwrap()["profiles"]["Main"]["waapi"]["port"], json!(8090));
+
+        let store = migrate(v1).unwrap();
+        assert_eq!(store.schema_version, PROFILE_SCHEMA_VERSION);
+        let main = store.get("Main").unwrap();
+        assert_eq!(main.wwise.proj_path, "C:/Game/Wwise");
+        assert_eq!((main.waapi.host.as_str(), main.waapi.port), ("10.0.0.2", 8090));
+        // 无法解析的端口使用默认值，缺少的部分使用默认设置
+        assert_eq!(store.get("Broken port").unwrap().waapi.port, 8080);
+        let no_waapi = store.get("No waapi").unwrap();
+        assert_eq!((no_waapi.bank.dir_path.as_str(), no_waapi.waapi.host.as_str()), ("C:/Banks", "127.0.0.1"));
+    }
+
+    #[test]
+    fn rejects_unknown_versions_and_malformed_documents() {
+        assert!(migrate_v1_to_v2(json!(["Main"])).unwrap_err().contains("根节点必须是对象"));
+        assert!(migrate(json!({ "schemaVersion": 3, "profiles": {} })).unwrap_err().contains("请升级 wid"));
+
+        let current = migrate(json!({ "schemaVersion": 2, "profiles": { "Main": { "waapi": { "port": 9000 } } } })).unwrap();
+        assert_eq!(current.get("Main").unwrap().waapi.port, 9000);
+    }
+
+    #[test]
+    fn counts_only_imported_profiles() {
+        let mut store = ProfileStore::default();
+        store.profiles.insert("Main".to_string(), profile("old"));
+        store.profiles.insert("Local".to_string(), profile("local"));
+
+        let mut imported = ProfileStore::default();
+        for (name, host) in [("Main", "new"), ("Remote", "remote"), ("default", "ignored"), (" ", "blank")] {
+            imported.profiles.insert(name.to_string(), profile(host));
+        }
+
+        assert_eq!(merge_into(&mut store, imported), 2);
+        let hosts: Vec<(&str, &str)> = store.profiles.iter().map(|(n, p)| (n.as_str(), p.waapi.host.as_str())).collect();
+        assert_eq!(hosts, [("Local", "local"), ("Main", "new"), ("Remote", "remote")]);
+    }
+
+    #[test]
+    fn round_trips_toml_and_json_files() {
+        let dir = std::env::temp_dir().join(format!("wid-profiles-{}", std::process::id()));
+        let mut store = ProfileStore::default();
+        store.profiles.insert("Main".to_string(), profile("10.0.0.2"));
+
+        for file in ["profiles.toml", "profiles.json"] {
+            let path = dir.join(file);
+            store.save(&path).unwrap();
+            let loaded = ProfileStore::load(&path).unwrap();
+            assert_eq!(loaded.get("Main").unwrap().waapi.host, "10.0.0.2", "{}", file);
+        }
+        assert!(ProfileStore::load(&dir.join("missing.toml")).unwrap().profiles.is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,490 @@
+use std::fmt;
+use crate::modules::id_input::{normalize_id_input, IdInterpretation};
+use crate::modules::id_resolver::parse_integer_token;
+use crate::modules::index::IndexedObject;
+use crate::modules::types::{SearchResponse, SearchResult};
+
+/// 查询字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Type,
+    Name,
+    Path,
+    Bank,
+    WorkUnit,
+    Parent,
+    Guid,
+    ShortId,
+    MediaId,
+    /// 没有字段名的条件，匹配名称或任意 ID
+    Any,
+}
+
+/// 字段名及其别名
+const FIELD_NAMES: &[(&str, Field)] = &[
+    ("type", Field::Type),
+    ("name", Field::Name),
+    ("path", Field::Path),
+    ("bank", Field::Bank),
+    ("wu", Field::WorkUnit),
+    ("workunit", Field::WorkUnit),
+    ("parent", Field::Parent),
+    ("guid", Field::Guid),
+    ("shortid", Field::ShortId),
+    ("mediaid", Field::MediaId),
+];
+
+impl Field {
+    fn parse(name: &str) -> Option<Field> {
+        let name = name.to_lowercase();
+        FIELD_NAMES.iter().find(|(n, _)| *n == name).map(|(_, f)| *f)
+    }
+
+    fn name(self) -> &'static str {
+        FIELD_NAMES
+            .iter()
+            .find(|(_, f)| *f == self)
+            .map(|(n, _)| *n)
+            .unwrap_or("")
+    }
+
+    fn is_numeric(self) -> bool {
+        matches!(self, Field::ShortId | Field::MediaId)
+    }
+
+    /// 只有部分索引才有的字段：工程对象没有 SoundBank（找不到 Bank 目录时），Bank 对象没有工作单元和路径
+    fn is_available(self, objects: &[IndexedObject]) -> bool {
+        match self {
+            Field::Bank => objects.iter().any(|o| !o.banks.is_empty()),
+            Field::WorkUnit => objects.iter().any(|o| !o.work_unit.is_empty()),
+            Field::Path => objects.iter().any(|o| !o.path.is_empty()),
+            _ => true,
+        }
+    }
+
+    /// 对象上对应字段的值
+    fn value(self, object: &IndexedObject) -> &str {
+        match self {
+            Field::Type => &object.object_type,
+            Field::Name => &object.name,
+            Field::Path => &object.path,
+            Field::Bank => object.banks.first().map(|b| b.name.as_str()).unwrap_or(""),
+            Field::WorkUnit => &object.work_unit,
+            Field::Parent => &object.parent,
+            Field::Guid => &object.guid,
+            Field::ShortId => &object.short_id,
+            Field::MediaId => object.media_ids.first().map(String::as_str).unwrap_or(""),
+            Field::Any => "",
+        }
+    }
+}
+
+/// 比较运算符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl Comparison {
+    fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Eq => "=",
+            Comparison::Gt => ">",
+            Comparison::Ge => ">=",
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+        }
+    }
+}
+
+/// 一个查询条件，例如 `type:Event`、`-bank:UI`、`shortid:>1000000`
+#[derive(Debug, Clone)]
+pub struct Condition {
+    pub field: Field,
+    pub negated: bool,
+    pub comparison: Comparison,
+    pub value: String,
+    /// 条件在查询中的位置（从 0 开始的字符下标，不含 `-` 前缀）
+    pub position: usize,
+    /// 数字字段的比较值（值中没有通配符时）
+    number: Option<u32>,
+}
+
+impl Condition {
+    fn matches(&self, object: &IndexedObject) -> bool {
+        self.matches_positive(object) != self.negated
+    }
+
+    fn matches_positive(&self, object: &IndexedObject) -> bool {
+        match self.field {
+            Field::Any => self.matches_any(object),
+            Field::Guid => wildcard_match(&trim_braces(&self.value), &trim_braces(&object.guid)),
+            // 对象在多个 SoundBank 中时，任意一个匹配即可
+            Field::Bank if !object.banks.is_empty() => {
+                object.banks.iter().any(|bank| wildcard_match(&self.value, &bank.name))
+            }
+            // 源有多个 MediaID 时，任意一个匹配即可
+            Field::MediaId => object.media_ids.iter().any(|id| self.matches_number(id)),
+            field if field.is_numeric() => self.matches_number(field.value(object)),
+            field => wildcard_match(&self.value, field.value(object)),
+        }
+    }
+
+    fn matches_number(&self, actual: &str) -> bool {
+        match self.number {
+            Some(expected) => actual.parse::<u32>().is_ok_and(|actual| match self.comparison {
+                Comparison::Eq => actual == expected,
+                Comparison::Gt => actual > expected,
+                Comparison::Ge => actual >= expected,
+                Comparison::Lt => actual < expected,
+                Comparison::Le => actual <= expected,
+            }),
+            None => wildcard_match(&self.value, actual),
+        }
+    }
+
+    /// 没有字段名时：名称匹配，或任意 ID 与输入的某种解释完全相同
+    fn matches_any(&self, object: &IndexedObject) -> bool {
+        if wildcard_match(&self.value, &object.name) {
+            return true;
+        }
+        normalize_id_input(&self.value).iter().any(|interpretation| {
+            let value = trim_braces(&interpretation.value);
+            let matches = |actual: &str| !actual.is_empty() && trim_braces(actual).eq_ignore_ascii_case(&value);
+            interpretation.id_types.iter().any(|id_type| match id_type.as_str() {
+                "GUID" => matches(&object.guid),
+                "ShortID" => matches(&object.short_id),
+                _ => object.media_ids.iter().any(|id| matches(id)),
+            })
+        })
+    }
+
+    fn describe(&self) -> String {
+        let not = if self.negated { "非 " } else { "" };
+        match self.field {
+            Field::Any => format!("{}名称或 ID {}", not, self.value),
+            field => format!("{}{} {} {}", not, field.name(), self.comparison.symbol(), self.value),
+        }
+    }
+}
+
+/// 解析后的结构化查询，所有条件同时满足才算匹配
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub conditions: Vec<Condition>,
+}
+
+/// 查询语法错误
+#[derive(Debug)]
+pub struct QueryError {
+    /// 出错位置（从 0 开始的字符下标）
+    pub position: usize,
+    pub message: String,
+    query: String,
+}
+
+impl fmt::Display for QueryError {
+    /// 输出错误信息、原查询以及指向出错位置的 `^`
+    ///
+    /// `^` 前的空白按显示宽度计算，中文等全角字符占两列，制表符原样保留。
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "查询语法错误（第 {} 个字符）: {}", self.position + 1, self.message)?;
+        writeln!(f, "{}", self.query)?;
+        let padding: String = self
+            .query
+            .chars()
+            .take(self.position)
+            .map(|c| if c == '\t' { "\t".to_string() } else { " ".repeat(display_width(c)) })
+            .collect();
+        write!(f, "{}^", padding)
+    }
+}
+
+/// 字符在等宽终端中的显示宽度：东亚宽字符和全角字符占两列，组合字符不占列
+fn display_width(c: char) -> usize {
+    match c as u32 {
+        0x0300..=0x036F | 0x200B..=0x200F | 0xFE00..=0xFE0F => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+/// 输入是否为结构化查询（至少一个条件形如 `字段:值`）
+pub fn is_structured_query(input: &str) -> bool {
+    input.split_whitespace().any(|term| {
+        let term = term.strip_prefix('-').unwrap_or(term);
+        term.split_once(':')
+            .is_some_and(|(field, _)| !field.is_empty() && field.chars().all(|c| c.is_ascii_alphabetic()))
+    })
+}
+
+/// 输入是否为使用了指定字段的结构化查询
+pub fn query_uses_field(input: &str, field: Field) -> bool {
+    is_structured_query(input) && Query::parse(input).is_ok_and(|query| query.uses_field(field))
+}
+
+/// 查询解析器，按字符下标记录位置以便报告错误
+struct Parser<'a> {
+    query: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, position: usize, message: String) -> QueryError {
+        QueryError { position, message, query: self.query.to_string() }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// 读取一个值：引号内的文本（支持 `\"` 转义）或到下一个空白为止的文本
+    fn read_value(&mut self) -> Result<String, QueryError> {
+        if self.peek() == Some('"') {
+            let start = self.pos;
+            self.pos += 1;
+            let mut value = String::new();
+            loop {
+                match self.peek() {
+                    None => return Err(self.error(start, "引号未闭合".to_string())),
+                    Some('"') => {
+                        self.pos += 1;
+                        break;
+                    }
+                    Some('\\') if self.chars.get(self.pos + 1) == Some(&'"') => {
+                        value.push('"');
+                        self.pos += 2;
+                    }
+                    Some(c) => {
+                        value.push(c);
+                        self.pos += 1;
+                    }
+                }
+            }
+            if self.peek().is_some_and(|c| !c.is_whitespace()) {
+                return Err(self.error(self.pos, "引号后面需要空格".to_string()));
+            }
+            Ok(value)
+        } else {
+            let mut value = String::new();
+            while let Some(c) = self.peek().filter(|c| !c.is_whitespace()) {
+                value.push(c);
+                self.pos += 1;
+            }
+            Ok(value)
+        }
+    }
+
+    fn read_comparison(&mut self) -> Comparison {
+        let next = self.chars.get(self.pos + 1).copied();
+        let (comparison, len) = match (self.peek(), next) {
+            (Some('>'), Some('=')) => (Comparison::Ge, 2),
+            (Some('<'), Some('=')) => (Comparison::Le, 2),
+            (Some('>'), _) => (Comparison::Gt, 1),
+            (Some('<'), _) => (Comparison::Lt, 1),
+            (Some('='), _) => (Comparison::Eq, 1),
+            _ => (Comparison::Eq, 0),
+        };
+        self.pos += len;
+        comparison
+    }
+
+    fn parse_condition(&mut self) -> Result<Condition, QueryError> {
+        // `-` 后面是数字时为负数（有符号 int32 形式的 ShortID），不是取反
+        let negated = self.peek() == Some('-')
+            && self.chars.get(self.pos + 1).is_some_and(|c| !c.is_whitespace() && !c.is_ascii_digit());
+        if negated {
+            self.pos += 1;
+        }
+
+        // 字段名由字母组成并以冒号结束，否则整个词作为无字段的条件
+        let field_start = self.pos;
+        let mut field_end = self.pos;
+        while self.chars.get(field_end).is_some_and(|c| c.is_ascii_alphabetic()) {
+            field_end += 1;
+        }
+        let has_field = field_end > field_start && self.chars.get(field_end) == Some(&':');
+        if !has_field {
+            let value_start = self.pos;
+            let value = self.read_value()?;
+            if value.is_empty() {
+                return Err(self.error(value_start, "缺少查询条件".to_string()));
+            }
+            return Ok(Condition {
+                field: Field::Any,
+                negated,
+                comparison: Comparison::Eq,
+                value,
+                position: value_start,
+                number: None,
+            });
+        }
+
+        let field_name: String = self.chars[field_start..field_end].iter().collect();
+        let field = Field::parse(&field_name).ok_or_else(|| {
+            let names: Vec<&str> = FIELD_NAMES.iter().map(|(n, _)| *n).collect();
+            self.error(field_start, format!("未知字段 {}，可选值: {}", field_name, names.join(", ")))
+        })?;
+        self.pos = field_end + 1;
+
+        let comparison_start = self.pos;
+        let comparison = self.read_comparison();
+        if comparison != Comparison::Eq && !field.is_numeric() {
+            return Err(self.error(
+                comparison_start,
+                format!("字段 {} 不支持比较运算符 {}", field.name(), comparison.symbol()),
+            ));
+        }
+
+        let value_start = self.pos;
+        let value = self.read_value()?;
+        if value.is_empty() {
+            return Err(self.error(value_start, format!("字段 {} 缺少值", field.name())));
+        }
+
+        let mut number = None;
+        if field.is_numeric() {
+            let has_wildcard = value.contains(['*', '?']);
+            if has_wildcard && comparison != Comparison::Eq {
+                return Err(self.error(value_start, "比较运算符的值不能包含通配符".to_string()));
+            }
+            if !has_wildcard {
+                let token = parse_integer_token(&value).ok_or_else(|| {
+                    self.error(value_start, format!("字段 {} 的值必须是 32 位整数: {}", field.name(), value))
+                })?;
+                number = token.value.parse::<u32>().ok();
+            }
+        }
+
+        Ok(Condition { field, negated, comparison, value, position: field_start, number })
+    }
+}
+
+impl Query {
+    /// 解析查询，如 `type:Event name:Play_* bank:UI shortid:>1000000 wu:Characters`
+    ///
+    /// 条件之间以空格分隔并且同时满足；`-` 前缀表示取反；值可以用双引号包含空格；
+    /// 文本字段支持 `*`、`?` 通配符并忽略大小写，ShortID/MediaID 支持 `>`、`>=`、`<`、`<=`、`=`。
+    pub fn parse(query: &str) -> Result<Query, QueryError> {
+        let mut parser = Parser { query, chars: query.chars().collect(), pos: 0 };
+        let mut conditions = Vec::new();
+
+        parser.skip_whitespace();
+        while parser.peek().is_some() {
+            conditions.push(parser.parse_condition()?);
+            parser.skip_whitespace();
+        }
+        if conditions.is_empty() {
+            return Err(parser.error(0, "查询为空".to_string()));
+        }
+
+        Ok(Query { conditions })
+    }
+
+    pub fn matches(&self, object: &IndexedObject) -> bool {
+        self.conditions.iter().all(|condition| condition.matches(object))
+    }
+
+    /// 是否有条件使用了指定字段
+    pub fn uses_field(&self, field: Field) -> bool {
+        self.conditions.iter().any(|c| c.field == field)
+    }
+
+    /// 检查条件使用的字段在索引中是否存在，不存在时指向第一个这样的条件
+    ///
+    /// 否则 `bank:` 在工程索引、`wu:` 在 Bank 索引中永远不会匹配，结果为空却没有任何提示。
+    pub fn check_fields(&self, query: &str, objects: &[IndexedObject]) -> Result<(), QueryError> {
+        if objects.is_empty() {
+            return Ok(());
+        }
+        match self.conditions.iter().find(|c| !c.field.is_available(objects)) {
+            Some(condition) => {
+                let reason = match condition.field {
+                    Field::Bank => "工程对象需要能通过 .wid.toml 或 .wproj 找到已生成的 Bank 目录",
+                    _ => "Bank 元数据中没有工作单元和工程路径，请在 Wwise 工程标签页中使用",
+                };
+                Err(QueryError {
+                    position: condition.position,
+                    message: format!("当前索引中没有字段 {}: {}", condition.field.name(), reason),
+                    query: query.to_string(),
+                })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// 供界面显示的查询说明
+    pub fn describe(&self) -> String {
+        let parts: Vec<String> = self.conditions.iter().map(|c| c.describe()).collect();
+        format!("结构化查询: {}", parts.join("，"))
+    }
+}
+
+/// 用结构化查询过滤索引
+pub fn search_query(objects: &[IndexedObject], input: &str) -> Result<SearchResponse, String> {
+    let query = Query::parse(input).map_err(|e| e.to_string())?;
+    query.check_fields(input, objects).map_err(|e| e.to_string())?;
+
+    let results = objects
+        .iter()
+        .filter(|object| query.matches(object))
+        .map(SearchResult::from)
+        .collect();
+
+    let interpretation = IdInterpretation::query(input.trim(), query.describe());
+    Ok(SearchResponse { interpretations: vec![interpretation], results, scan: None })
+}
+
+fn trim_braces(value: &str) -> String {
+    value.trim_start_matches('{').trim_end_matches('}').to_string()
+}
+
+/// 忽略大小写的通配符匹配，`*` 匹配任意个字符，`?` 匹配一个字符
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // 回溯：让上一个 * 多匹配一个字符
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
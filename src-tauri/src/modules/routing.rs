@@ -0,0 +1,298 @@
+use std::path::Path;
+use rayon::prelude::*;
+use roxmltree::{Document, Node};
+use crate::modules::graph::{Graph, GraphEdge, GraphNode};
+use crate::modules::hierarchy::ProjectHierarchy;
+use crate::modules::id_resolver::canonical_guid;
+use crate::modules::index::{index_project_documents, is_definition_node, load_project_documents, ProjectDocuments};
+use crate::modules::inheritance::{evaluate_named_values, EffectiveValue};
+use crate::modules::object_details::{child, read_properties, read_references, ObjectLookup, ObjectReference};
+
+/// 对象输出到总线
+pub const EDGE_OUTPUT_BUS: &str = "output_bus";
+/// 用户定义的辅助发送
+pub const EDGE_USER_AUX_SEND: &str = "user_aux_send";
+/// 游戏定义的辅助发送，目标总线在运行时决定
+pub const EDGE_GAME_AUX_SEND: &str = "game_aux_send";
+/// 子总线到上级总线
+pub const EDGE_PARENT_BUS: &str = "parent_bus";
+
+/// 游戏定义辅助发送的占位节点
+pub const GAME_AUX_NODE_ID: &str = "game-defined-aux-sends";
+
+/// 对象输出到总线的分类
+const ROUTED_CATEGORIES: &[&str] = &["AudioObjects", "InteractiveMusic"];
+
+const BUS_TYPES: &[&str] = &["Bus", "AuxBus"];
+
+/// 不单独路由的对象：工作单元、文件夹和源
+const UNROUTED_TYPES: &[&str] =
+    &["WorkUnit", "Folder", "PhysicalFolder", "AudioFileSource", "SourcePlugin", "ExternalSource"];
+
+const USER_AUX_SENDS: &[&str] = &["UserAuxSend0", "UserAuxSend1", "UserAuxSend2", "UserAuxSend3"];
+
+/// 没有设置 OutputBus 的顶层对象输出到的总线；Wwise 2022.1 起改名为 Main Audio Bus
+const MASTER_BUS_NAMES: &[&str] = &["Main Audio Bus", "Master Audio Bus"];
+
+/// 路由边；目标为空表示输出到主总线
+struct RoutingEdge {
+    source: String,
+    target: Option<ObjectReference>,
+    kind: &'static str,
+    label: String,
+}
+
+/// 单个 .wwu 文件中的路由
+#[derive(Default)]
+struct FileRouting {
+    objects: Vec<String>,
+    edges: Vec<RoutingEdge>,
+    /// 工作单元或文件夹下的顶层总线：GUID 和名称
+    top_busses: Vec<(String, String)>,
+}
+
+/// 从 .wwu 文件构建总线路由图
+///
+/// 节点为对象和总线，边包括对象考虑继承后的 OutputBus、用户定义和游戏定义的辅助发送，以及总线到上级总线的连接。
+pub fn build_routing_graph(documents: &ProjectDocuments) -> Graph {
+    let objects = index_project_documents(documents);
+    let lookup = ObjectLookup::new(&objects);
+    let hierarchy = ProjectHierarchy::build(documents);
+    let files: Vec<FileRouting> =
+        documents.files.par_iter().map(|file| read_file(&file.contents, &lookup)).collect();
+
+    let top_busses: Vec<&(String, String)> = files.iter().flat_map(|f| &f.top_busses).collect();
+    let master = top_busses
+        .iter()
+        .find(|(_, name)| MASTER_BUS_NAMES.contains(&name.as_str()))
+        .or_else(|| top_busses.first())
+        .map(|(guid, _)| guid.clone());
+
+    let mut graph = Graph::default();
+    for guid in files.iter().flat_map(|f| &f.objects) {
+        if let Some(node) = hierarchy.node(guid) {
+            graph.add_node(graph_node(node.guid, node.name, node.object_type, node.short_id, node.path));
+        }
+    }
+
+    for edge in files.into_iter().flat_map(|f| f.edges) {
+        let target = match (&edge.target, &master) {
+            (Some(reference), _) => {
+                // 游戏定义的辅助发送指向占位节点，其余目标在工程层级中查找
+                let guid = match edge.kind {
+                    EDGE_GAME_AUX_SEND => reference.target_guid.clone(),
+                    _ => canonical_guid(&reference.target_guid),
+                };
+                let node = match hierarchy.node(&guid) {
+                    Some(node) => graph_node(node.guid, node.name, node.object_type, node.short_id, node.path),
+                    None => {
+                        let (name, object_type) = (reference.target_name.clone(), reference.target_type.clone());
+                        graph_node(guid.clone(), name, object_type, String::new(), String::new())
+                    }
+                };
+                graph.add_node(node);
+                guid
+            }
+            (None, Some(master)) => master.clone(),
+            (None, None) => continue,
+        };
+        graph.add_edge(GraphEdge { source: edge.source, target, kind: edge.kind.to_string(), label: edge.label });
+    }
+    graph
+}
+
+fn graph_node(id: String, name: String, object_type: String, short_id: String, path: String) -> GraphNode {
+    GraphNode { id, name, object_type, short_id, path }
+}
+
+fn read_file(contents: &str, lookup: &ObjectLookup) -> FileRouting {
+    let mut routing = FileRouting::default();
+    let Ok(doc) = Document::parse(contents) else {
+        return routing;
+    };
+    for category in doc.root_element().children().filter(|n| n.is_element()) {
+        let name = category.tag_name().name();
+        for root in category.children().filter(is_definition_node) {
+            if ROUTED_CATEGORIES.contains(&name) {
+                read_object(&root, lookup, &mut routing);
+            } else if name == "Busses" {
+                read_bus(&root, None, lookup, &mut routing);
+            }
+        }
+    }
+    routing
+}
+
+fn children<'a, 'input>(node: &Node<'a, 'input>) -> Vec<Node<'a, 'input>> {
+    child(node, "ChildrenList")
+        .map(|list| list.children().filter(is_definition_node).collect())
+        .unwrap_or_default()
+}
+
+fn read_object(node: &Node, lookup: &ObjectLookup, routing: &mut FileRouting) {
+    if !UNROUTED_TYPES.contains(&node.tag_name().name()) {
+        let guid = canonical_guid(node.attribute("ID").unwrap_or(""));
+        let mut names = vec!["OutputBus", "UseGameAuxSends"];
+        names.extend_from_slice(USER_AUX_SENDS);
+
+        for value in evaluate_named_values(node, &names, lookup) {
+            let edge = match value.name.as_str() {
+                "OutputBus" => RoutingEdge {
+                    source: guid.clone(),
+                    target: value.reference.clone(),
+                    kind: EDGE_OUTPUT_BUS,
+                    label: edge_label(&value),
+                },
+                "UseGameAuxSends" if value.value.eq_ignore_ascii_case("true") => {
+                    game_aux_edge(&guid, edge_label(&value))
+                }
+                _ => match value.reference.clone() {
+                    Some(reference) => RoutingEdge {
+                        source: guid.clone(),
+                        target: Some(reference),
+                        kind: EDGE_USER_AUX_SEND,
+                        label: edge_label(&value),
+                    },
+                    None => continue,
+                },
+            };
+            routing.edges.push(edge);
+        }
+        routing.objects.push(guid);
+    }
+    for item in children(node) {
+        read_object(&item, lookup, routing);
+    }
+}
+
+fn read_bus(node: &Node, parent: Option<&str>, lookup: &ObjectLookup, routing: &mut FileRouting) {
+    if !BUS_TYPES.contains(&node.tag_name().name()) {
+        for item in children(node) {
+            read_bus(&item, parent, lookup, routing);
+        }
+        return;
+    }
+
+    let guid = canonical_guid(node.attribute("ID").unwrap_or(""));
+    match parent {
+        Some(parent) => routing.edges.push(RoutingEdge {
+            source: guid.clone(),
+            target: Some(bus_reference(parent)),
+            kind: EDGE_PARENT_BUS,
+            label: "Parent".to_string(),
+        }),
+        None => routing.top_busses.push((guid.clone(), node.attribute("Name").unwrap_or("").to_string())),
+    }
+
+    // 总线的辅助发送不从上级总线继承
+    let game_aux = read_properties(node)
+        .iter()
+        .any(|p| p.name == "UseGameAuxSends" && p.value.eq_ignore_ascii_case("true"));
+    if game_aux {
+        routing.edges.push(game_aux_edge(&guid, "UseGameAuxSends".to_string()));
+    }
+    let sends = read_references(node, lookup).into_iter().filter(|r| USER_AUX_SENDS.contains(&r.name.as_str()));
+    for reference in sends {
+        routing.edges.push(RoutingEdge {
+            source: guid.clone(),
+            label: reference.name.clone(),
+            target: Some(reference),
+            kind: EDGE_USER_AUX_SEND,
+        });
+    }
+
+    routing.objects.push(guid.clone());
+    for item in children(node) {
+        read_bus(&item, Some(&guid), lookup, routing);
+    }
+}
+
+/// 上级总线，名称等信息在构建图时从工程层级中读取
+fn bus_reference(guid: &str) -> ObjectReference {
+    ObjectReference {
+        name: "Parent".to_string(),
+        target_name: String::new(),
+        target_type: String::new(),
+        target_guid: guid.to_string(),
+        target_path: String::new(),
+        resolved: true,
+        embedded: false,
+    }
+}
+
+fn game_aux_edge(source: &str, label: String) -> RoutingEdge {
+    RoutingEdge {
+        source: source.to_string(),
+        target: Some(ObjectReference {
+            name: "UseGameAuxSends".to_string(),
+            target_name: "Game-Defined Aux Sends".to_string(),
+            target_type: "GameDefinedAuxSends".to_string(),
+            target_guid: GAME_AUX_NODE_ID.to_string(),
+            target_path: String::new(),
+            resolved: true,
+            embedded: false,
+        }),
+        kind: EDGE_GAME_AUX_SEND,
+        label,
+    }
+}
+
+/// 边的说明：设置名称，继承或默认时注明来源
+fn edge_label(value: &EffectiveValue) -> String {
+    if value.is_default {
+        format!("{}（默认）", value.name)
+    } else if value.inherited {
+        format!("{}（继承自 {}）", value.name, value.source.name)
+    } else {
+        value.name.clone()
+    }
+}
+
+/// 按 GUID、ShortID 或名称查找总线
+pub fn find_bus<'a>(graph: &'a Graph, query: &str) -> Option<&'a GraphNode> {
+    let query = query.trim();
+    let guid = canonical_guid(query);
+    graph.nodes.iter().filter(|n| BUS_TYPES.contains(&n.object_type.as_str())).find(|n| {
+        n.id == guid || (!n.short_id.is_empty() && n.short_id == query) || n.name.eq_ignore_ascii_case(query)
+    })
+}
+
+/// 经过指定总线的所有对象和总线：沿 OutputBus、辅助发送或上级总线能到达该总线的部分
+pub fn routed_through(graph: &Graph, bus: &str) -> Result<Graph, String> {
+    let node = find_bus(graph, bus).ok_or_else(|| format!("工程中未找到总线 {}", bus))?;
+    Ok(graph.upstream(&node.id))
+}
+
+fn load_routing(directory: &str, through: Option<String>) -> Result<Graph, String> {
+    let documents = load_project_documents(Path::new(directory))?;
+    let graph = build_routing_graph(&documents);
+    match through.filter(|b| !b.trim().is_empty()) {
+        Some(bus) => routed_through(&graph, &bus),
+        None => Ok(graph),
+    }
+}
+
+/// 获取工程的总线路由图，`through` 不为空时只保留经过该总线的部分
+#[tauri::command]
+pub fn get_bus_routing(directory: String, through: Option<String>) -> Result<Graph, String> {
+    load_routing(&directory, through)
+}
+
+/// 导出总线路由图
+///
+/// # 参数
+/// * `format` - 导出格式，可选值: "json", "dot", "mermaid"；为空时根据扩展名推断
+///
+/// # 返回
+/// * `Ok(usize)` - 导出的边数
+#[tauri::command]
+pub fn export_bus_routing(
+    directory: String,
+    through: Option<String>,
+    format: Option<String>,
+    path: String,
+) -> Result<usize, String> {
+    let graph = load_routing(&directory, through)?;
+    graph.write(format.as_deref(), Path::new(&path), "routing")?;
+    Ok(graph.edges.len())
+}
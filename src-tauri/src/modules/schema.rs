@@ -0,0 +1,191 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::OnceLock;
+use regex::Regex;
+use roxmltree::Node;
+use serde::Serialize;
+use serde_json::Value;
+
+/// 已知的 `WwiseDocument SchemaVersion` 与 Wwise 版本的对应关系（按版本号升序）
+const KNOWN_DOCUMENT_SCHEMAS: &[(u32, &str)] = &[
+    (97, "2019.2"),
+    (110, "2021.1"),
+    (119, "2023.1"),
+    (123, "2024.1"),
+];
+
+/// SoundbanksInfo 的结构
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MetadataLayout {
+    /// 2021.1 及更早：Bank 下为 `IncludedEvents`、`IncludedMemoryFiles`，流媒体位于根节点的
+    /// `StreamedFiles`，JSON 中的 `Id` 为字符串
+    Legacy,
+    /// 2022.1 起：Bank 下为 `Events`、`Media` 等，JSON 中的 `Id` 为数字
+    Current,
+}
+
+/// 旧结构中特有的容器名称
+const LEGACY_CONTAINERS: &[&str] = &[
+    "IncludedEvents",
+    "IncludedMemoryFiles",
+    "IncludedPrefetchFiles",
+    "ReferencedStreamedFiles",
+    "StreamedFiles",
+    "MediaFilesNotInAnyBank",
+];
+
+/// 只读取版本时每次读取的字节数，版本号位于第一块中
+const CHUNK_SIZE: usize = 64 * 1024;
+/// 相邻两块重叠的字节数，容器名称可能跨越两块
+const CHUNK_OVERLAP: usize = 64;
+
+/// Bank 元数据文件的版本信息
+#[derive(Debug, Clone, Serialize)]
+pub struct MetadataSchema {
+    /// 根节点的 `SchemaVersion`
+    pub schema_version: Option<u32>,
+    /// 根节点的 `SoundBankVersion`（旧版本为 `SoundbankVersion`），即 Bank 文件格式版本
+    pub soundbank_version: Option<u32>,
+    pub layout: MetadataLayout,
+}
+
+/// 版本号可能是字符串也可能是数字
+fn json_version(value: Option<&Value>) -> Option<u32> {
+    match value? {
+        Value::String(s) => s.trim().parse().ok(),
+        Value::Number(n) => n.as_u64().and_then(|n| u32::try_from(n).ok()),
+        _ => None,
+    }
+}
+
+/// 检测 JSON 元数据（`SoundBanksInfo` 等根对象）的版本和结构
+pub fn detect_json_schema(root: &Value) -> MetadataSchema {
+    MetadataSchema {
+        schema_version: json_version(root.get("SchemaVersion")),
+        soundbank_version: json_version(root.get("SoundBankVersion").or_else(|| root.get("SoundbankVersion"))),
+        layout: if json_has_legacy_container(root) {
+            MetadataLayout::Legacy
+        } else {
+            MetadataLayout::Current
+        },
+    }
+}
+
+fn json_has_legacy_container(value: &Value) -> bool {
+    match value {
+        Value::Object(obj) => obj
+            .iter()
+            .any(|(key, val)| LEGACY_CONTAINERS.contains(&key.as_str()) || json_has_legacy_container(val)),
+        Value::Array(arr) => arr.iter().any(json_has_legacy_container),
+        _ => false,
+    }
+}
+
+/// 检测 XML 元数据根节点的版本和结构
+pub fn detect_xml_schema(root: &Node) -> MetadataSchema {
+    let version = |name: &str| root.attribute(name).and_then(|v| v.trim().parse().ok());
+    let is_legacy = root
+        .descendants()
+        .any(|n| n.is_element() && LEGACY_CONTAINERS.contains(&n.tag_name().name()));
+    MetadataSchema {
+        schema_version: version("SchemaVersion"),
+        soundbank_version: version("SoundBankVersion").or_else(|| version("SoundbankVersion")),
+        layout: if is_legacy { MetadataLayout::Legacy } else { MetadataLayout::Current },
+    }
+}
+
+/// 不解析整个文件，只读取元数据的版本和结构，根节点不是 `root_name` 时返回 None
+///
+/// 版本号取自文件开头；结构按原始文本中是否出现旧结构的容器名称判断，找到后即停止读取。
+/// 文件内容是否完整有效要到真正解析时才能发现。
+pub fn read_metadata_schema(file_path: &Path, root_name: &str) -> Result<Option<MetadataSchema>, String> {
+    let file_name = file_path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let read_error = |e: std::io::Error| format!("读取文件 {} 失败: {}", file_name, e);
+    let mut file = File::open(file_path).map_err(read_error)?;
+
+    let mut chunk = vec![0; CHUNK_SIZE];
+    let mut window = Vec::new();
+    let mut versions = None;
+    let mut is_legacy = false;
+    loop {
+        let read = file.read(&mut chunk).map_err(read_error)?;
+        if read == 0 {
+            break;
+        }
+        window.extend_from_slice(&chunk[..read]);
+        let text = String::from_utf8_lossy(&window);
+        if versions.is_none() {
+            if !text.contains(root_name) {
+                return Ok(None);
+            }
+            versions = Some(header_versions(&text));
+        }
+        if legacy_container_regex().is_match(&text) {
+            is_legacy = true;
+            break;
+        }
+        let keep = window.len().saturating_sub(CHUNK_OVERLAP);
+        window.drain(..keep);
+    }
+
+    let Some((schema_version, soundbank_version)) = versions else {
+        return Ok(None);
+    };
+    Ok(Some(MetadataSchema {
+        schema_version,
+        soundbank_version,
+        layout: if is_legacy { MetadataLayout::Legacy } else { MetadataLayout::Current },
+    }))
+}
+
+/// 文件开头的 `SchemaVersion` 和 `SoundBankVersion`，JSON 和 XML 写法都支持
+fn header_versions(text: &str) -> (Option<u32>, Option<u32>) {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let regex = RE.get_or_init(|| {
+        Regex::new(r#"\b(SchemaVersion|(?i:SoundBankVersion))"?\s*[:=]\s*"?(\d+)"#).expect("版本正则表达式无效")
+    });
+    let mut versions = (None, None);
+    for captures in regex.captures_iter(text) {
+        let value = captures[2].parse().ok();
+        if &captures[1] == "SchemaVersion" {
+            versions.0 = versions.0.or(value);
+        } else {
+            versions.1 = versions.1.or(value);
+        }
+    }
+    versions
+}
+
+/// 匹配 JSON 键或 XML 元素形式的旧结构容器名称
+fn legacy_container_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(&format!(r#"["<]({})["\s>/]"#, LEGACY_CONTAINERS.join("|"))).expect("容器正则表达式无效")
+    })
+}
+
+/// `WwiseDocument` 根节点（.wproj、.wwu）的 `SchemaVersion`
+///
+/// 只用于显示 Wwise 版本，.wwu 的解析不按版本区分：支持的版本（2019.2 起）中对象、属性、引用和
+/// 源的结构相同。已知的唯一差异是 2017.1 之前的 RTPC 位于属性的 `RTPCList` 中，
+/// `object_details::read_rtpcs()` 两种位置都会读取。
+pub fn document_schema_version(root: &Node) -> Option<u32> {
+    root.attribute("SchemaVersion").and_then(|v| v.trim().parse().ok())
+}
+
+/// 根据 `WwiseDocument SchemaVersion` 推测 Wwise 版本，如 `2023.1`；
+/// 介于两个已知版本之间时返回 `2021.1+`
+pub fn wwise_version_label(schema_version: u32) -> Option<String> {
+    KNOWN_DOCUMENT_SCHEMAS
+        .iter()
+        .rev()
+        .find(|(version, _)| *version <= schema_version)
+        .map(|(version, label)| {
+            if *version == schema_version {
+                label.to_string()
+            } else {
+                format!("{}+", label)
+            }
+        })
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::modules::utils::resolve_path;
+
+/// 团队共享配置的文件名，放在 .wproj 旁边并随工程一起提交
+pub const TEAM_CONFIG_FILE_NAME: &str = ".wid.toml";
+
+/// 团队配置中的 WAAPI 连接设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeamWaapi {
+    pub host: String,
+    pub port: u16,
+}
+
+impl Default for TeamWaapi {
+    fn default() -> Self {
+        TeamWaapi { host: "127.0.0.1".to_string(), port: 8080 }
+    }
+}
+
+/// 检查规则的级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Off,
+    #[serde(alias = "warn")]
+    Warning,
+    Error,
+}
+
+impl LintLevel {
+    /// 配置文件中使用的名称
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LintLevel::Off => "off",
+            LintLevel::Warning => "warning",
+            LintLevel::Error => "error",
+        }
+    }
+}
+
+/// `.wid.toml` 的内容
+///
+/// ```toml
+/// bank_output_root = "../GeneratedSoundBanks"
+/// ids_header = "../GeneratedSoundBanks/Wwise_IDs.h"
+/// platforms = ["Windows", "Android"]
+/// ignore = ["Backup/**", "*.bak"]
+///
+/// [waapi]
+/// host = "127.0.0.1"
+/// port = 8080
+///
+/// [lint]
+/// unused-event = "warning"
+/// missing-media = "error"
+/// ```
+///
+/// 加载后 `bank_output_root` 和 `ids_header` 已按工程目录解析为绝对路径。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeamConfig {
+    /// SoundBank 输出根目录，各平台的 Bank 位于其下的同名子目录
+    pub bank_output_root: Option<String>,
+    /// Wwise_IDs.h 路径
+    pub ids_header: Option<String>,
+    /// 平台列表，第一个为默认平台
+    pub platforms: Vec<String>,
+    pub waapi: Option<TeamWaapi>,
+    /// 扫描工程时忽略的路径（glob，相对工程目录）
+    pub ignore: Vec<String>,
+    /// 检查规则名称 → 级别
+    pub lint: BTreeMap<String, LintLevel>,
+}
+
+/// 工程目录中加载到的团队配置
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadedTeamConfig {
+    /// `.wid.toml` 的路径
+    pub path: String,
+    pub config: TeamConfig,
+    /// 默认平台的 Bank 目录
+    pub bank_directory: Option<String>,
+}
+
+impl TeamConfig {
+    /// 指定平台的 Bank 目录；未指定时使用第一个平台，没有平台时为输出根目录本身
+    pub fn bank_directory(&self, platform: Option<&str>) -> Option<PathBuf> {
+        let root = PathBuf::from(self.bank_output_root.as_ref()?);
+        match platform.or(self.platforms.first().map(String::as_str)) {
+            Some(platform) => {
+                let dir = root.join(platform);
+                // 输出根目录本身就是某个平台的目录时，平台子目录不存在
+                Some(if dir.is_dir() || !root.is_dir() { dir } else { root })
+            }
+            None => Some(root),
+        }
+    }
+
+    /// 将相对路径按工程目录解析为绝对路径
+    fn resolve_paths(&mut self, project_dir: &Path) {
+        for path in [&mut self.bank_output_root, &mut self.ids_header].into_iter().flatten() {
+            *path = resolve_path(project_dir, path).to_string_lossy().to_string();
+        }
+    }
+}
+
+/// 读取工程目录中的 `.wid.toml`，不存在时返回 `None`
+///
+/// # 参数
+/// * `project_dir` - Wwise 工程目录
+///
+/// # 返回
+/// * `Ok(Some(LoadedTeamConfig))` - 已加载，相对路径已按工程目录解析
+/// * `Ok(None)` - 工程目录中没有团队配置
+/// * `Err(String)` - 文件存在但无法读取或格式错误
+pub fn load_team_config(project_dir: &Path) -> Result<Option<LoadedTeamConfig>, String> {
+    let path = project_dir.join(TEAM_CONFIG_FILE_NAME);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("读取 {} 失败: {}", TEAM_CONFIG_FILE_NAME, e))?;
+    let mut config: TeamConfig = toml::from_str(&contents)
+        .map_err(|e| format!("解析 {} 失败: {}", TEAM_CONFIG_FILE_NAME, e))?;
+    config.resolve_paths(project_dir);
+
+    let bank_directory = config
+        .bank_directory(None)
+        .map(|dir| dir.to_string_lossy().to_string());
+    Ok(Some(LoadedTeamConfig {
+        path: path.to_string_lossy().to_string(),
+        config,
+        bank_directory,
+    }))
+}
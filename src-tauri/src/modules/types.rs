@@ -1,11 +1,61 @@
 use serde::{Deserialize, Serialize};
+use crate::modules::id_input::IdInterpretation;
+use crate::modules::schema::MetadataSchema;
+use crate::modules::team_config::LoadedTeamConfig;
+use crate::modules::walker::ScanStats;
+use crate::modules::wproj::ProjectSettings;
 
 /// 搜索结果结构
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
     pub name: String,
     pub object_type: String,
     pub guid: String,
     pub short_id: String,
     pub media_id: String,
-}
\ No newline at end of file
+    /// 包含该对象的 SoundBank；工程对象为空
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub banks: Vec<OwningBank>,
+}
+
+/// 包含对象的 SoundBank
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwningBank {
+    pub name: String,
+    pub id: String,
+    /// 相对于 Bank 目录的 .bnk 路径
+    pub path: String,
+    pub language: String,
+}
+
+/// 搜索响应：输入的解释和搜索结果
+#[derive(Debug, Serialize)]
+pub struct SearchResponse {
+    /// 输入被规范化后的所有解释，例如 `0x1A2B3C4D → 439041101`
+    pub interpretations: Vec<IdInterpretation>,
+    pub results: Vec<SearchResult>,
+    /// 工程目录的扫描统计，仅工程搜索时有值
+    pub scan: Option<ScanStats>,
+}
+
+/// Wwise 工程目录的验证结果
+#[derive(Debug, Serialize)]
+pub struct WwiseProjectInfo {
+    /// .wproj 中的工程设置
+    pub project: ProjectSettings,
+    /// 工程目录中的团队配置 `.wid.toml`
+    pub team_config: Option<LoadedTeamConfig>,
+    /// 自动定位到的 Bank 目录
+    pub bank_directory: Option<String>,
+}
+
+/// Bank 目录的验证结果
+#[derive(Debug, Serialize)]
+pub struct BankDirectoryInfo {
+    /// SoundbanksInfo 汇总文件路径
+    pub soundbanks_info: Option<String>,
+    /// 单独的 Bank 元数据文件数量（存在汇总文件时为 0）
+    pub bank_file_count: usize,
+    /// 元数据的版本和结构
+    pub schema: Option<MetadataSchema>,
+}
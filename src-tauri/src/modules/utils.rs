@@ -1,3 +1,5 @@
+use std::path::{Component, Path, PathBuf};
+
 /// 验证是否为有效的GUID格式
 /// GUID格式: {XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX} 或 XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX
 pub fn is_valid_guid(s: &str) -> bool {
@@ -32,4 +34,22 @@ pub fn is_valid_guid(s: &str) -> bool {
     }
     
     true
-}
\ No newline at end of file
+}
+
+/// 按 `base` 解析相对路径，并去掉其中的 `.` 和 `..`
+pub fn resolve_path(base: &Path, path: &str) -> PathBuf {
+    let joined = base.join(path.trim());
+    let mut resolved = PathBuf::new();
+    for component in joined.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !resolved.pop() {
+                    resolved.push(component);
+                }
+            }
+            _ => resolved.push(component),
+        }
+    }
+    resolved
+}
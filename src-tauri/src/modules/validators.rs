@@ -1,9 +1,15 @@
 use std::path::Path;
-use std::fs;
+use crate::modules::bank_metadata::discover_bank_metadata;
+use crate::modules::team_config::load_team_config;
+use crate::modules::schema::read_metadata_schema;
+use crate::modules::types::{BankDirectoryInfo, WwiseProjectInfo};
+use crate::modules::wproj::{find_wproj, parse_wproj};
 
-/// 验证 Wwise 工程目录（必须包含 .wproj 文件）
+/// 验证 Wwise 工程目录（必须包含 .wproj 文件），解析工程设置并加载团队配置 `.wid.toml`
+///
+/// 返回的 `bank_directory` 优先使用团队配置（输出根目录或默认平台），其次为 .wproj 中第一个已生成 SoundBank 的平台目录。
 #[tauri::command]
-pub fn validate_wwise_directory(path: String) -> Result<bool, String> {
+pub fn validate_wwise_directory(path: String) -> Result<WwiseProjectInfo, String> {
     let dir_path = Path::new(&path);
     
     if !dir_path.exists() {
@@ -14,25 +20,27 @@ pub fn validate_wwise_directory(path: String) -> Result<bool, String> {
         return Err("路径不是目录".to_string());
     }
     
-    // 检查目录中是否有 .wproj 文件
-    match fs::read_dir(dir_path) {
-        Ok(entries) => {
-            for entry in entries.flatten() {
-                if let Some(ext) = entry.path().extension() {
-                    if ext == "wproj" {
-                        return Ok(true);
-                    }
-                }
-            }
-            Err("目录中未找到 .wproj 文件".to_string())
-        }
-        Err(e) => Err(format!("无法读取目录: {}", e)),
-    }
+    let wproj_path = find_wproj(dir_path)?.ok_or_else(|| "目录中未找到 .wproj 文件".to_string())?;
+    let project = parse_wproj(&wproj_path)?;
+    let team_config = load_team_config(dir_path)?;
+
+    let bank_directory = team_config
+        .as_ref()
+        .and_then(|team| {
+            team.bank_directory.clone().or_else(|| {
+                // 团队配置只指定了平台时，使用 .wproj 中该平台的输出目录
+                let platform = team.config.platforms.first()?;
+                project.bank_directory(platform).map(str::to_string)
+            })
+        })
+        .or_else(|| project.default_bank_directory().map(str::to_string));
+
+    Ok(WwiseProjectInfo { project, team_config, bank_directory })
 }
 
-/// 验证 Bank 目录（必须包含 SoundbanksInfo.xml 或 SoundbanksInfo.json）
+/// 验证 Bank 目录（必须包含 SoundbanksInfo.xml / .json，或每个 Bank 单独的元数据文件），并检测元数据的版本
 #[tauri::command]
-pub fn validate_bank_directory(path: String) -> Result<bool, String> {
+pub fn validate_bank_directory(path: String) -> Result<BankDirectoryInfo, String> {
     let dir_path = Path::new(&path);
     
     if !dir_path.exists() {
@@ -43,13 +51,24 @@ pub fn validate_bank_directory(path: String) -> Result<bool, String> {
         return Err("路径不是目录".to_string());
     }
     
-    // 检查是否存在 SoundbanksInfo.xml 或 SoundbanksInfo.json
-    let xml_path = dir_path.join("SoundbanksInfo.xml");
-    let json_path = dir_path.join("SoundbanksInfo.json");
+    // 检查是否存在 SoundbanksInfo 或单独的 Bank 元数据文件
+    let files = discover_bank_metadata(dir_path)?;
     
-    if xml_path.exists() || json_path.exists() {
-        Ok(true)
-    } else {
-        Err("目录中未找到 SoundbanksInfo.xml 或 SoundbanksInfo.json".to_string())
+    if !files.has_bank_metadata() {
+        return Err("目录中未找到 SoundbanksInfo 或单独的 Bank 元数据文件".to_string());
     }
-}
\ No newline at end of file
+
+    // 单独的 Bank 文件由同一次生成产生，版本一致，检测第一个即可
+    let metadata_path = files
+        .soundbanks_info
+        .as_ref()
+        .or_else(|| files.bank_files.first())
+        .ok_or_else(|| "目录中未找到 SoundbanksInfo 或单独的 Bank 元数据文件".to_string())?;
+    let schema = read_metadata_schema(metadata_path, "SoundBanksInfo")?;
+
+    Ok(BankDirectoryInfo {
+        soundbanks_info: files.soundbanks_info.as_ref().map(|p| p.to_string_lossy().to_string()),
+        bank_file_count: files.bank_files.len(),
+        schema,
+    })
+}
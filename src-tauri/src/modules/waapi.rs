@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use serde_json::{json, Value};
+use crate::modules::id_input::{normalize_id_input, IdInterpretation, InputForm};
+use crate::modules::index::IndexedObject;
+use crate::modules::matcher::{search_objects, IdMatcher, MatchMode};
+use crate::modules::query::is_structured_query;
+use crate::modules::types::SearchResponse;
+use crate::modules::waapi_client::{with_shared_client, WaapiClient, WaapiError, DEFAULT_CALL_TIMEOUT};
+
+/// 查询对象时返回的属性
+pub const RETURN_PROPERTIES: &[&str] = &["id", "name", "type", "shortId", "path"];
+
+/// 一条 WAQL 查询
+struct WaqlQuery {
+    waql: String,
+    /// 按 MediaID 查询时为查询的值，WAAPI 不返回 MediaID
+    media_id: Option<String>,
+}
+
+/// 将一种解释转换为 WAQL 查询
+///
+/// WAQL 无法对数字做部分匹配，数字 ID 只支持精确查询；GUID 的部分匹配使用正则表达式，
+/// 最终的匹配等级仍由 [`search_objects`] 计算。
+fn build_queries(
+    interpretation: &IdInterpretation,
+    id_types: &[String],
+    match_mode: MatchMode,
+    first_error: &mut Option<String>,
+) -> Result<Vec<WaqlQuery>, String> {
+    let matcher = IdMatcher::new(&interpretation.value, match_mode)?;
+    let value = interpretation.value.trim();
+    let mut queries = Vec::new();
+
+    for id_type in id_types.iter().filter(|t| interpretation.id_types.contains(t)) {
+        if let Err(e) = matcher.check(id_type) {
+            first_error.get_or_insert(e);
+            continue;
+        }
+        let numeric_mode = matches!(match_mode, MatchMode::Auto | MatchMode::Exact);
+        let query = match id_type.as_str() {
+            "GUID" => {
+                let guid = value.trim_start_matches('{').trim_end_matches('}').to_uppercase();
+                let waql = match match_mode {
+                    MatchMode::Regex => format!("$ where id : \"{}\"", escape_waql(value)),
+                    MatchMode::Exact if interpretation.form == InputForm::Guid => {
+                        format!("$ \"{{{}}}\"", guid)
+                    }
+                    // 非完整 GUID 不会完全匹配任何对象
+                    MatchMode::Exact => continue,
+                    MatchMode::Prefix => format!("$ where id : \"^\\\\{{{}\"", escape_waql(&guid)),
+                    MatchMode::Auto | MatchMode::Contains => format!("$ where id : \"{}\"", escape_waql(&guid)),
+                };
+                WaqlQuery { waql, media_id: None }
+            }
+            "ShortID" if numeric_mode => WaqlQuery { waql: format!("$ where shortId = {}", value), media_id: None },
+            "MediaID" if numeric_mode => WaqlQuery {
+                waql: format!("$ where mediaId = {}", value),
+                media_id: Some(value.to_string()),
+            },
+            _ => {
+                first_error.get_or_insert(format!("WAAPI 查询中 {} 只支持精确匹配", id_type));
+                continue;
+            }
+        };
+        queries.push(query);
+    }
+    Ok(queries)
+}
+
+/// 转义 WAQL 字符串中的反斜杠和引号
+fn escape_waql(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// 将 WAAPI 返回的对象转换为索引对象，`media_id` 为按 MediaID 查询时的值
+pub fn to_indexed_object(object: &Value, media_id: Option<&str>) -> Option<IndexedObject> {
+    let text = |key: &str| object.get(key).and_then(Value::as_str).unwrap_or_default().to_string();
+    let guid = text("id");
+    if guid.is_empty() {
+        return None;
+    }
+    let path = text("path");
+    Some(IndexedObject {
+        name: text("name"),
+        object_type: text("type"),
+        guid,
+        short_id: object.get("shortId").and_then(Value::as_u64).map(|id| id.to_string()).unwrap_or_default(),
+        media_ids: media_id.map(str::to_string).into_iter().collect(),
+        parent: path.rsplit('\\').nth(1).unwrap_or_default().to_string(),
+        path,
+        work_unit: String::new(),
+        banks: Vec::new(),
+    })
+}
+
+/// 使用指定的客户端通过 WAAPI 搜索 ID
+///
+/// 每种解释适用的 ID 类型各执行一次 `ak.wwise.core.object.get`，
+/// 多个查询返回的同一对象按 GUID 合并，再交给 [`search_objects`] 排序。
+pub fn search_with_client(
+    client: &mut WaapiClient,
+    id_string: &str,
+    id_types: &[String],
+    match_mode: MatchMode,
+    timeout: Duration,
+) -> Result<SearchResponse, String> {
+    if match_mode != MatchMode::Regex && is_structured_query(id_string) {
+        return Err("WAAPI 查询不支持结构化查询，请在 Wwise 工程或 Bank 标签页中使用".to_string());
+    }
+
+    let interpretations = if match_mode == MatchMode::Regex {
+        vec![IdInterpretation::partial(id_string.trim())]
+    } else {
+        normalize_id_input(id_string)
+    };
+
+    let mut queries = Vec::new();
+    let mut first_error = None;
+    for interpretation in &interpretations {
+        queries.extend(build_queries(interpretation, id_types, match_mode, &mut first_error)?);
+    }
+    if queries.is_empty() {
+        return match first_error {
+            Some(e) => Err(e),
+            None => Ok(SearchResponse { interpretations, results: Vec::new(), scan: None }),
+        };
+    }
+
+    let mut candidates: Vec<IndexedObject> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for query in &queries {
+        let result = client.call(
+            "ak.wwise.core.object.get",
+            json!({ "waql": query.waql }),
+            json!({ "return": RETURN_PROPERTIES }),
+            timeout,
+        )?;
+        let objects = result.get("return").and_then(Value::as_array).cloned().unwrap_or_default();
+        for object in objects.iter().filter_map(|o| to_indexed_object(o, query.media_id.as_deref())) {
+            match positions.get(&object.guid) {
+                Some(&i) => {
+                    for media_id in object.media_ids {
+                        if !candidates[i].media_ids.contains(&media_id) {
+                            candidates[i].media_ids.push(media_id);
+                        }
+                    }
+                }
+                None => {
+                    positions.insert(object.guid.clone(), candidates.len());
+                    candidates.push(object);
+                }
+            }
+        }
+    }
+
+    let mut response = search_objects(&candidates, id_string, id_types, match_mode)?;
+    response.interpretations = interpretations;
+    Ok(response)
+}
+
+/// 通过 WAAPI 在正在运行的 Wwise 中搜索 ID
+///
+/// # 参数
+/// * `host`、`port` - WAAPI 地址，地址不变时复用同一个连接
+/// * `id_string` - 要搜索的 ID
+/// * `id_types` - 要搜索的 ID 类型
+/// * `match_mode` - 匹配方式，见 [`MatchMode::parse`]
+/// * `timeout_secs` - 每次调用的超时秒数，默认 30 秒
+///
+/// # 返回
+/// * `Ok(SearchResponse)` - 输入的解释和搜索结果
+/// * `Err(String)` - 连接或查询失败
+#[tauri::command]
+pub fn search_waapi(
+    host: String,
+    port: u16,
+    id_string: String,
+    id_types: Vec<String>,
+    match_mode: Option<String>,
+    timeout_secs: Option<u64>,
+) -> Result<SearchResponse, String> {
+    let match_mode = MatchMode::parse(match_mode.as_deref())?;
+    let timeout = timeout_secs.map(Duration::from_secs).unwrap_or(DEFAULT_CALL_TIMEOUT);
+    with_shared_client(&host, port, |client| {
+        search_with_client(client, &id_string, &id_types, match_mode, timeout)
+    })
+}
+
+/// 测试 WAAPI 连接，返回 Wwise 版本信息
+#[tauri::command]
+pub fn test_waapi_connection(host: String, port: u16) -> Result<String, String> {
+    with_shared_client(&host, port, |client| {
+        let info = client.call("ak.wwise.core.getInfo", json!({}), json!({}), DEFAULT_CALL_TIMEOUT)?;
+        let version = info
+            .get("version")
+            .and_then(|v| v.get("displayName"))
+            .and_then(Value::as_str)
+            .unwrap_or("未知版本");
+        Ok(format!("连接成功！Wwise 版本: {}", version))
+    })
+}
+
+/// 在 Wwise 中对选中对象执行的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShowAction {
+    /// 在 Project Explorer 中定位
+    FindInProjectExplorer,
+    /// 在 Property Editor 中查看
+    Inspect,
+    /// 在 Schematic View 中选中
+    Schematic,
+}
+
+impl ShowAction {
+    /// 解析操作名称，可选值: "find", "inspect", "schematic"
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "find" => Ok(ShowAction::FindInProjectExplorer),
+            "inspect" => Ok(ShowAction::Inspect),
+            "schematic" => Ok(ShowAction::Schematic),
+            other => Err(format!("不支持的操作: {}，可选值: find, inspect, schematic", other)),
+        }
+    }
+
+    /// `ak.wwise.ui.commands.execute` 的命令，按顺序尝试；
+    /// 2021.1 起 `SyncGroup` 更名为 `SelectionChannel`
+    fn commands(self) -> &'static [&'static str] {
+        match self {
+            ShowAction::FindInProjectExplorer => {
+                &["FindInProjectExplorerSyncGroup1", "FindInProjectExplorerSelectionChannel1"]
+            }
+            ShowAction::Inspect => &["Inspect"],
+            ShowAction::Schematic => &["ShowSchematicView"],
+        }
+    }
+}
+
+/// 在 Wwise 打开的工程中查找对象的 GUID
+///
+/// 有 GUID 时确认对象存在；没有 GUID（如 Wwise_IDs.h 中的结果）时按 ShortID 查找。
+fn locate_object(client: &mut WaapiClient, guid: &str, short_id: &str, timeout: Duration) -> Result<String, String> {
+    let guid = guid.trim();
+    let short_id = short_id.trim();
+    let waql = if !guid.is_empty() {
+        format!("$ \"{{{}}}\"", guid.trim_start_matches('{').trim_end_matches('}').to_uppercase())
+    } else if !short_id.is_empty() && short_id.chars().all(|c| c.is_ascii_digit()) {
+        format!("$ where shortId = {}", short_id)
+    } else {
+        return Err("该结果没有 GUID 或 ShortID，无法在 Wwise 中定位".to_string());
+    };
+
+    let result = client.call(
+        "ak.wwise.core.object.get",
+        json!({ "waql": waql }),
+        json!({ "return": ["id"] }),
+        timeout,
+    )?;
+    result
+        .get("return")
+        .and_then(|r| r.get(0))
+        .and_then(|o| o.get("id"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| "Wwise 当前打开的工程中没有该对象，请确认打开的是同一个工程".to_string())
+}
+
+/// 使用指定的客户端在 Wwise 中显示对象，返回定位到的 GUID
+pub fn show_with_client(
+    client: &mut WaapiClient,
+    guid: &str,
+    short_id: &str,
+    actions: &[ShowAction],
+    timeout: Duration,
+) -> Result<String, String> {
+    let guid = locate_object(client, guid, short_id, timeout)?;
+    for action in actions {
+        let commands = action.commands();
+        for (i, command) in commands.iter().enumerate() {
+            let result = client.call(
+                "ak.wwise.ui.commands.execute",
+                json!({ "command": command, "objects": [guid] }),
+                json!({}),
+                timeout,
+            );
+            match result {
+                Ok(_) => break,
+                // 命令不存在时尝试其他版本的命令名称
+                Err(WaapiError::Call { .. }) if i + 1 < commands.len() => continue,
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+    }
+    // 旧版本不支持时忽略
+    let _ = client.call("ak.wwise.ui.bringToForeground", json!({}), json!({}), timeout);
+    Ok(guid)
+}
+
+/// 在正在运行的 Wwise 中显示对象，适用于所有标签页的搜索结果
+///
+/// # 参数
+/// * `host`、`port` - WAAPI 地址
+/// * `guid` - 对象 GUID，为空时按 `short_id` 查找
+/// * `short_id` - 对象 ShortID
+/// * `actions` - 要执行的操作，可选值: "find", "inspect", "schematic"；为空时为 find 和 inspect
+///
+/// # 返回
+/// * `Ok(String)` - 定位到的对象 GUID
+/// * `Err(String)` - 连接失败、对象不存在或命令执行失败
+#[tauri::command]
+pub fn show_in_wwise(
+    host: String,
+    port: u16,
+    guid: String,
+    short_id: Option<String>,
+    actions: Option<Vec<String>>,
+) -> Result<String, String> {
+    let actions = match actions {
+        Some(names) if !names.is_empty() => {
+            names.iter().map(|n| ShowAction::parse(n)).collect::<Result<Vec<_>, _>>()?
+        }
+        _ => vec![ShowAction::FindInProjectExplorer, ShowAction::Inspect],
+    };
+    with_shared_client(&host, port, |client| {
+        show_with_client(client, &guid, short_id.as_deref().unwrap_or_default(), &actions, DEFAULT_CALL_TIMEOUT)
+    })
+}
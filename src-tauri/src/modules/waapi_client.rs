@@ -0,0 +1,401 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io::ErrorKind;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use serde_json::{json, Value};
+use tungstenite::client::IntoClientRequest;
+use tungstenite::http::HeaderValue;
+use tungstenite::{Message, WebSocket};
+
+/// WAAPI 使用的 WAMP 子协议和 realm
+const WAMP_SUBPROTOCOL: &str = "wamp.2.json";
+const WAAPI_REALM: &str = "realm1";
+
+/// 默认的调用超时
+pub const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(30);
+/// 建立连接和握手的超时
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// 等待响应时读取的间隔，超过截止时间即超时
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// WAMP 消息类型
+const HELLO: u64 = 1;
+const WELCOME: u64 = 2;
+const ABORT: u64 = 3;
+const GOODBYE: u64 = 6;
+const ERROR: u64 = 8;
+const SUBSCRIBE: u64 = 32;
+const SUBSCRIBED: u64 = 33;
+const EVENT: u64 = 36;
+const CALL: u64 = 48;
+const RESULT: u64 = 50;
+
+/// WAAPI 调用错误
+#[derive(Debug)]
+pub enum WaapiError {
+    /// 无法连接或连接已断开，重连后可以重试
+    Connection(String),
+    /// 等待响应超时
+    Timeout(Duration),
+    /// WAAPI 返回的错误
+    Call { uri: String, message: String },
+    /// 消息不符合 WAMP 协议
+    Protocol(String),
+}
+
+impl fmt::Display for WaapiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaapiError::Connection(reason) => write!(
+                f,
+                "无法连接到 WAAPI（{}）。请检查 Wwise 是否已启动并开启了 WAAPI，以及 Host 和 Port 是否正确",
+                reason
+            ),
+            WaapiError::Timeout(timeout) => write!(f, "WAAPI 响应超时（{} 秒）", timeout.as_secs()),
+            WaapiError::Call { uri, message } => write!(f, "WAAPI 调用失败: {}（{}）", message, uri),
+            WaapiError::Protocol(reason) => write!(f, "WAAPI 协议错误: {}", reason),
+        }
+    }
+}
+
+impl From<WaapiError> for String {
+    fn from(error: WaapiError) -> Self {
+        error.to_string()
+    }
+}
+
+fn connection_error(error: impl fmt::Display) -> WaapiError {
+    WaapiError::Connection(error.to_string())
+}
+
+/// 订阅的主题发布的事件
+#[derive(Debug, Clone)]
+pub struct WaapiEvent {
+    /// 主题 URI，如 `ak.wwise.core.object.nameChanged`
+    pub topic: String,
+    /// 事件内容（WAMP 的 kwargs）
+    pub kwargs: Value,
+}
+
+/// 一个 WAMP 会话
+pub struct WaapiSession {
+    socket: WebSocket<TcpStream>,
+    session_id: u64,
+    next_request_id: u64,
+    /// 订阅 ID 与主题的对应关系
+    subscriptions: HashMap<u64, String>,
+    /// 已收到尚未取走的事件
+    events: VecDeque<WaapiEvent>,
+}
+
+impl WaapiSession {
+    /// 连接 `ws://host:port/waapi` 并加入 realm
+    pub fn connect(host: &str, port: u16) -> Result<Self, WaapiError> {
+        let address = (host, port)
+            .to_socket_addrs()
+            .map_err(connection_error)?
+            .next()
+            .ok_or_else(|| WaapiError::Connection(format!("无法解析地址 {}", host)))?;
+        let stream = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT).map_err(connection_error)?;
+        stream.set_nodelay(true).map_err(connection_error)?;
+        stream.set_read_timeout(Some(CONNECT_TIMEOUT)).map_err(connection_error)?;
+        stream.set_write_timeout(Some(CONNECT_TIMEOUT)).map_err(connection_error)?;
+
+        let mut request = format!("ws://{}:{}/waapi", host, port)
+            .into_client_request()
+            .map_err(connection_error)?;
+        request
+            .headers_mut()
+            .insert("Sec-WebSocket-Protocol", HeaderValue::from_static(WAMP_SUBPROTOCOL));
+        let (socket, _) = tungstenite::client(request, stream).map_err(connection_error)?;
+        socket
+            .get_ref()
+            .set_read_timeout(Some(POLL_INTERVAL))
+            .map_err(connection_error)?;
+
+        let mut session = WaapiSession {
+            socket,
+            session_id: 0,
+            next_request_id: 1,
+            subscriptions: HashMap::new(),
+            events: VecDeque::new(),
+        };
+        session.send(json!([HELLO, WAAPI_REALM, { "roles": { "caller": {}, "subscriber": {} } }]))?;
+
+        let welcome = session.receive(Instant::now() + CONNECT_TIMEOUT, CONNECT_TIMEOUT)?;
+        match message_code(&welcome) {
+            Some(WELCOME) => {
+                session.session_id = welcome.get(1).and_then(Value::as_u64).unwrap_or_default();
+                Ok(session)
+            }
+            Some(ABORT) => Err(WaapiError::Connection(format!(
+                "会话被拒绝: {}",
+                welcome.get(2).and_then(Value::as_str).unwrap_or("未知原因")
+            ))),
+            _ => Err(WaapiError::Protocol(format!("期望 WELCOME，收到 {:?}", welcome))),
+        }
+    }
+
+    /// 服务器分配的会话 ID
+    pub fn session_id(&self) -> u64 {
+        self.session_id
+    }
+
+    /// 调用 WAAPI 函数
+    ///
+    /// # 参数
+    /// * `procedure` - 函数 URI，如 `ak.wwise.core.object.get`
+    /// * `args` - 参数对象
+    /// * `options` - 选项对象，如 `{ "return": ["id", "name"] }`
+    /// * `timeout` - 等待响应的超时
+    ///
+    /// # 返回
+    /// * `Ok(Value)` - 返回的对象（WAMP 的 kwargs）
+    /// * `Err(WaapiError)` - 调用失败
+    pub fn call(&mut self, procedure: &str, args: Value, options: Value, timeout: Duration) -> Result<Value, WaapiError> {
+        let request_id = self.next_request_id();
+        self.send(json!([CALL, request_id, options, procedure, [], args]))?;
+        let reply = self.wait_for_reply(CALL, request_id, timeout)?;
+        Ok(reply.get(4).cloned().unwrap_or_else(|| json!({})))
+    }
+
+    /// 订阅主题，之后发布的事件通过 [`WaapiSession::poll_events`] 取走
+    ///
+    /// # 参数
+    /// * `topic` - 主题 URI，如 `ak.wwise.core.object.nameChanged`
+    /// * `options` - 选项对象，如 `{ "return": ["id", "name"] }`
+    pub fn subscribe(&mut self, topic: &str, options: Value, timeout: Duration) -> Result<(), WaapiError> {
+        let request_id = self.next_request_id();
+        self.send(json!([SUBSCRIBE, request_id, options, topic]))?;
+        let reply = self.wait_for_reply(SUBSCRIBE, request_id, timeout)?;
+        let subscription_id = reply
+            .get(2)
+            .and_then(Value::as_u64)
+            .ok_or_else(|| WaapiError::Protocol(format!("SUBSCRIBED 中缺少订阅 ID: {:?}", reply)))?;
+        self.subscriptions.insert(subscription_id, topic.to_string());
+        Ok(())
+    }
+
+    /// 等待最多 `wait`，返回期间（以及之前调用时）收到的所有事件
+    pub fn poll_events(&mut self, wait: Duration) -> Result<Vec<WaapiEvent>, WaapiError> {
+        let deadline = Instant::now() + wait;
+        loop {
+            match self.receive(deadline, wait) {
+                Ok(message) => self.handle_unsolicited(&message)?,
+                Err(WaapiError::Timeout(_)) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self.events.drain(..).collect())
+    }
+
+    fn next_request_id(&mut self) -> u64 {
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+        request_id
+    }
+
+    /// 等待指定请求的响应，期间收到的事件放入队列
+    fn wait_for_reply(&mut self, request_type: u64, request_id: u64, timeout: Duration) -> Result<Vec<Value>, WaapiError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let message = self.receive(deadline, timeout)?;
+            let is_reply = message.get(1).and_then(Value::as_u64) == Some(request_id);
+            match message_code(&message) {
+                Some(RESULT | SUBSCRIBED) if is_reply => return Ok(message),
+                Some(ERROR)
+                    if message.get(1).and_then(Value::as_u64) == Some(request_type)
+                        && message.get(2).and_then(Value::as_u64) == Some(request_id) =>
+                {
+                    let uri = message.get(4).and_then(Value::as_str).unwrap_or_default().to_string();
+                    let message = message
+                        .get(6)
+                        .and_then(|kwargs| kwargs.get("message"))
+                        .and_then(Value::as_str)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| uri.clone());
+                    return Err(WaapiError::Call { uri, message });
+                }
+                // 事件放入队列，超时后才到达的其他请求的响应忽略
+                _ => self.handle_unsolicited(&message)?,
+            }
+        }
+    }
+
+    /// 处理不是当前请求响应的消息：事件放入队列，会话关闭时返回连接错误
+    fn handle_unsolicited(&mut self, message: &[Value]) -> Result<(), WaapiError> {
+        match message_code(message) {
+            Some(EVENT) => {
+                let topic = message
+                    .get(1)
+                    .and_then(Value::as_u64)
+                    .and_then(|id| self.subscriptions.get(&id));
+                if let Some(topic) = topic {
+                    self.events.push_back(WaapiEvent {
+                        topic: topic.clone(),
+                        kwargs: message.get(5).cloned().unwrap_or_else(|| json!({})),
+                    });
+                }
+                Ok(())
+            }
+            Some(GOODBYE | ABORT) => Err(WaapiError::Connection("会话已被服务器关闭".to_string())),
+            _ => Ok(()),
+        }
+    }
+
+    /// 离开 realm 并关闭连接
+    pub fn close(mut self) {
+        let _ = self.send(json!([GOODBYE, {}, "wamp.close.normal"]));
+        let _ = self.socket.close(None);
+        let _ = self.socket.flush();
+    }
+
+    fn send(&mut self, message: Value) -> Result<(), WaapiError> {
+        self.socket
+            .send(Message::text(message.to_string()))
+            .map_err(connection_error)
+    }
+
+    /// 读取下一条 WAMP 消息，超过截止时间返回超时
+    fn receive(&mut self, deadline: Instant, timeout: Duration) -> Result<Vec<Value>, WaapiError> {
+        loop {
+            match self.socket.read() {
+                Ok(Message::Text(text)) => {
+                    return serde_json::from_str(text.as_str())
+                        .map_err(|e| WaapiError::Protocol(format!("无法解析消息: {}", e)));
+                }
+                Ok(Message::Close(_)) => {
+                    return Err(WaapiError::Connection("连接已关闭".to_string()));
+                }
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(e))
+                    if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+                {
+                    if Instant::now() >= deadline {
+                        return Err(WaapiError::Timeout(timeout));
+                    }
+                }
+                Err(e) => return Err(connection_error(e)),
+            }
+        }
+    }
+}
+
+fn message_code(message: &[Value]) -> Option<u64> {
+    message.first().and_then(Value::as_u64)
+}
+
+/// 保持连接的 WAAPI 客户端，第一次调用时连接，连接断开后自动重连并恢复订阅
+pub struct WaapiClient {
+    host: String,
+    port: u16,
+    session: Option<WaapiSession>,
+    /// 已订阅的主题和选项，重连后重新订阅
+    subscriptions: Vec<(String, Value)>,
+    /// 累计建立的连接数
+    connections: usize,
+}
+
+impl WaapiClient {
+    pub fn new(host: &str, port: u16) -> Self {
+        WaapiClient { host: host.to_string(), port, session: None, subscriptions: Vec::new(), connections: 0 }
+    }
+
+    /// 是否连接到指定的地址
+    pub fn is_for(&self, host: &str, port: u16) -> bool {
+        self.host == host && self.port == port
+    }
+
+    /// 累计建立的连接数
+    pub fn connections(&self) -> usize {
+        self.connections
+    }
+
+    /// 调用 WAAPI 函数；已有连接断开时重连一次再重试
+    pub fn call(&mut self, procedure: &str, args: Value, options: Value, timeout: Duration) -> Result<Value, WaapiError> {
+        if let Some(session) = &mut self.session {
+            match session.call(procedure, args.clone(), options.clone(), timeout) {
+                Err(WaapiError::Connection(_)) => self.session = None,
+                result => return result,
+            }
+        }
+
+        self.connect(timeout)?.call(procedure, args, options, timeout)
+    }
+
+    /// 订阅主题，已订阅的主题直接返回
+    pub fn subscribe(&mut self, topic: &str, options: Value, timeout: Duration) -> Result<(), WaapiError> {
+        if self.subscriptions.iter().any(|(t, _)| t == topic) {
+            return Ok(());
+        }
+        let session = match self.session.as_mut() {
+            Some(session) => session,
+            None => self.connect(timeout)?,
+        };
+        match session.subscribe(topic, options.clone(), timeout) {
+            Err(WaapiError::Connection(e)) => {
+                self.session = None;
+                return Err(WaapiError::Connection(e));
+            }
+            result => result?,
+        }
+        self.subscriptions.push((topic.to_string(), options));
+        Ok(())
+    }
+
+    /// 等待最多 `wait` 并返回收到的事件；没有连接时先重连并恢复订阅
+    pub fn poll_events(&mut self, wait: Duration) -> Result<Vec<WaapiEvent>, WaapiError> {
+        let session = match self.session.as_mut() {
+            Some(session) => session,
+            None => self.connect(wait.max(CONNECT_TIMEOUT))?,
+        };
+        let result = session.poll_events(wait);
+        if matches!(result, Err(WaapiError::Connection(_))) {
+            self.session = None;
+        }
+        result
+    }
+
+    /// 是否已连接
+    pub fn is_connected(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// 建立新连接并重新订阅所有主题
+    fn connect(&mut self, timeout: Duration) -> Result<&mut WaapiSession, WaapiError> {
+        self.session = None;
+        let mut session = WaapiSession::connect(&self.host, self.port)?;
+        self.connections += 1;
+        for (topic, options) in &self.subscriptions {
+            session.subscribe(topic, options.clone(), timeout)?;
+        }
+        Ok(self.session.insert(session))
+    }
+
+    /// 关闭连接，下次调用时重新连接
+    pub fn disconnect(&mut self) {
+        if let Some(session) = self.session.take() {
+            session.close();
+        }
+    }
+}
+
+/// 应用共享的 WAAPI 客户端，地址不变时复用同一个连接
+static SHARED_CLIENT: Mutex<Option<WaapiClient>> = Mutex::new(None);
+
+/// 使用共享的 WAAPI 客户端，地址改变时关闭旧连接
+pub fn with_shared_client<T>(
+    host: &str,
+    port: u16,
+    f: impl FnOnce(&mut WaapiClient) -> Result<T, String>,
+) -> Result<T, String> {
+    let mut shared = SHARED_CLIENT.lock().unwrap_or_else(|e| e.into_inner());
+    if !shared.as_ref().is_some_and(|client| client.is_for(host, port)) {
+        if let Some(mut old) = shared.take() {
+            old.disconnect();
+        }
+    }
+    f(shared.get_or_insert_with(|| WaapiClient::new(host, port)))
+}
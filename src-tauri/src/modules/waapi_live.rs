@@ -0,0 +1,310 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tauri::{AppHandle, Emitter};
+use crate::modules::index::IndexedObject;
+use crate::modules::waapi::{to_indexed_object, RETURN_PROPERTIES};
+use crate::modules::waapi_client::{WaapiClient, WaapiError, WaapiEvent, DEFAULT_CALL_TIMEOUT};
+
+pub const NAME_CHANGED_TOPIC: &str = "ak.wwise.core.object.nameChanged";
+pub const CREATED_TOPIC: &str = "ak.wwise.core.object.created";
+pub const PRE_DELETED_TOPIC: &str = "ak.wwise.core.object.preDeleted";
+pub const PROJECT_SAVED_TOPIC: &str = "ak.wwise.core.project.saved";
+
+/// 实时同步订阅的主题
+pub const LIVE_TOPICS: &[&str] = &[NAME_CHANGED_TOPIC, CREATED_TOPIC, PRE_DELETED_TOPIC, PROJECT_SAVED_TOPIC];
+
+/// 发送给前端的事件名称
+pub const LIVE_CHANGE_EVENT: &str = "waapi-live-change";
+pub const LIVE_STATUS_EVENT: &str = "waapi-live-status";
+
+/// 等待事件的间隔，也是停止同步的最长响应时间
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// 断线后重连的间隔
+const RETRY_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Wwise 中发生的一次修改
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LiveChange {
+    Renamed {
+        guid: String,
+        old_name: String,
+        new_name: String,
+        /// 重命名后的对象路径
+        path: String,
+    },
+    Created { object: IndexedObject },
+    Deleted { guid: String, name: String, object_type: String },
+    /// 工程已保存，.wwu 文件与 Wwise 中的内容一致
+    ProjectSaved,
+}
+
+/// 实时同步的连接状态
+#[derive(Debug, Clone, Serialize)]
+pub struct LiveStatus {
+    pub connected: bool,
+    pub message: String,
+    /// Wwise 打开的工程所在目录
+    pub project_dir: Option<String>,
+}
+
+/// 将订阅的事件转换为修改，无法识别时返回 None
+pub fn parse_event(event: &WaapiEvent) -> Option<LiveChange> {
+    let object = event.kwargs.get("object");
+    let text = |value: Option<&Value>, key: &str| {
+        value.and_then(|v| v.get(key)).and_then(Value::as_str).unwrap_or_default().to_string()
+    };
+    match event.topic.as_str() {
+        NAME_CHANGED_TOPIC => Some(LiveChange::Renamed {
+            guid: text(object, "id"),
+            old_name: text(Some(&event.kwargs), "oldName"),
+            new_name: text(Some(&event.kwargs), "newName"),
+            path: text(object, "path"),
+        })
+        .filter(|change| matches!(change, LiveChange::Renamed { guid, .. } if !guid.is_empty())),
+        CREATED_TOPIC => to_indexed_object(object?, None).map(|object| LiveChange::Created { object }),
+        PRE_DELETED_TOPIC => Some(LiveChange::Deleted {
+            guid: text(object, "id"),
+            name: text(object, "name"),
+            object_type: text(object, "type"),
+        })
+        .filter(|change| matches!(change, LiveChange::Deleted { guid, .. } if !guid.is_empty())),
+        PROJECT_SAVED_TOPIC => Some(LiveChange::ProjectSaved),
+        _ => None,
+    }
+}
+
+/// 上次保存以来 Wwise 中尚未写入 .wwu 的修改
+///
+/// 工程搜索从磁盘上的 .wwu 构建索引，实时同步期间在索引上叠加这些修改，工程保存后清空。
+#[derive(Debug, Default)]
+pub struct LiveOverlay {
+    /// Wwise 打开的工程目录，只对该目录的搜索生效
+    project_dir: Option<String>,
+    /// 重命名的对象：新名称和新路径
+    renamed: HashMap<String, (String, String)>,
+    created: Vec<IndexedObject>,
+    deleted: HashSet<String>,
+}
+
+impl LiveOverlay {
+    pub fn new(project_dir: Option<&str>) -> Self {
+        LiveOverlay { project_dir: project_dir.map(normalize_dir), ..Default::default() }
+    }
+
+    /// 记录一次修改
+    pub fn apply(&mut self, change: &LiveChange) {
+        match change {
+            LiveChange::Renamed { guid, new_name, path, .. } => {
+                match self.created.iter_mut().find(|o| &o.guid == guid) {
+                    Some(object) => {
+                        object.name = new_name.clone();
+                        object.path = path.clone();
+                    }
+                    None => {
+                        self.renamed.insert(guid.clone(), (new_name.clone(), path.clone()));
+                    }
+                }
+            }
+            LiveChange::Created { object } => {
+                self.deleted.remove(&object.guid);
+                self.created.retain(|o| o.guid != object.guid);
+                self.created.push(object.clone());
+            }
+            LiveChange::Deleted { guid, .. } => {
+                self.renamed.remove(guid);
+                let created = self.created.len();
+                self.created.retain(|o| &o.guid != guid);
+                if self.created.len() == created {
+                    self.deleted.insert(guid.clone());
+                }
+            }
+            LiveChange::ProjectSaved => {
+                self.renamed.clear();
+                self.created.clear();
+                self.deleted.clear();
+            }
+        }
+    }
+
+    /// 重新连接后更新叠加层
+    ///
+    /// Wwise 打开的还是同一个工程时保留已记录的修改（断线期间的修改仍无法得知），
+    /// 工程改变或之前没有叠加层时从空的叠加层开始。
+    pub fn reconnect(overlay: &mut Option<LiveOverlay>, project_dir: Option<&str>) {
+        let project_dir = project_dir.map(normalize_dir);
+        if overlay.as_ref().is_none_or(|o| o.project_dir != project_dir) {
+            *overlay = Some(LiveOverlay { project_dir, ..Default::default() });
+        }
+    }
+
+    /// 是否应用于指定工程目录的搜索；不知道 Wwise 打开的工程时不应用
+    pub fn applies_to(&self, directory: &Path) -> bool {
+        self.project_dir
+            .as_deref()
+            .is_some_and(|dir| dir == normalize_dir(&directory.to_string_lossy()))
+    }
+
+    /// 在工程索引上叠加修改
+    pub fn apply_to(&self, objects: &mut Vec<IndexedObject>) {
+        objects.retain(|o| !self.deleted.contains(&o.guid));
+        for object in objects.iter_mut() {
+            if let Some((name, path)) = self.renamed.get(&object.guid) {
+                object.name = name.clone();
+                if !path.is_empty() {
+                    object.path = path.clone();
+                }
+            }
+        }
+        for object in &self.created {
+            if !objects.iter().any(|o| o.guid == object.guid) {
+                objects.push(object.clone());
+            }
+        }
+    }
+}
+
+/// 统一目录的分隔符、大小写和结尾的分隔符，用于比较 Wwise 返回的路径和配置的路径
+fn normalize_dir(dir: &str) -> String {
+    dir.replace('\\', "/").trim_end_matches('/').to_lowercase()
+}
+
+/// 当前的叠加修改，实时同步停止时清空
+static LIVE_OVERLAY: Mutex<Option<LiveOverlay>> = Mutex::new(None);
+/// 每次启动或停止实时同步时递增，旧的同步线程发现不一致后退出
+static LIVE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 在工程索引上叠加实时同步记录的修改
+pub fn apply_live_overlay(directory: &Path, objects: &mut Vec<IndexedObject>) {
+    let overlay = LIVE_OVERLAY.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(overlay) = overlay.as_ref().filter(|o| o.applies_to(directory)) {
+        overlay.apply_to(objects);
+    }
+}
+
+/// 查询 Wwise 打开的工程所在目录
+pub fn query_project_dir(client: &mut WaapiClient, timeout: Duration) -> Result<Option<String>, WaapiError> {
+    let result = client.call(
+        "ak.wwise.core.object.get",
+        json!({ "waql": "$ from type project" }),
+        json!({ "return": ["filePath"] }),
+        timeout,
+    )?;
+    Ok(result
+        .get("return")
+        .and_then(|r| r.get(0))
+        .and_then(|p| p.get("filePath"))
+        .and_then(Value::as_str)
+        .and_then(|path| Path::new(&path.replace('\\', "/")).parent().map(|p| p.to_string_lossy().to_string())))
+}
+
+/// 订阅实时同步的所有主题
+pub fn subscribe_live_topics(client: &mut WaapiClient, timeout: Duration) -> Result<(), WaapiError> {
+    for topic in LIVE_TOPICS {
+        client.subscribe(topic, json!({ "return": RETURN_PROPERTIES }), timeout)?;
+    }
+    Ok(())
+}
+
+/// 运行实时同步，直到 `keep_running` 返回 false
+///
+/// 连接失败或断开时每隔 `RETRY_INTERVAL` 重连，重连后自动恢复订阅。
+/// `on_status` 在连接状态变化时调用，`on_change` 对每次修改调用。
+pub fn run_live_sync(
+    client: &mut WaapiClient,
+    keep_running: impl Fn() -> bool,
+    mut on_change: impl FnMut(&LiveChange),
+    mut on_status: impl FnMut(LiveStatus),
+) {
+    let mut connected = None;
+    let mut subscribed = false;
+    while keep_running() {
+        let result = if subscribed {
+            client.poll_events(POLL_INTERVAL)
+        } else {
+            subscribe_live_topics(client, DEFAULT_CALL_TIMEOUT).map(|()| {
+                subscribed = true;
+                Vec::new()
+            })
+        };
+
+        match result {
+            Ok(events) => {
+                if connected != Some(true) {
+                    connected = Some(true);
+                    // 每次连接时重新确认 Wwise 打开的工程
+                    let project_dir = query_project_dir(client, DEFAULT_CALL_TIMEOUT).ok().flatten();
+                    on_status(LiveStatus { connected: true, message: "实时同步已连接".to_string(), project_dir });
+                }
+                for change in events.iter().filter_map(parse_event) {
+                    on_change(&change);
+                }
+            }
+            Err(e) => {
+                if connected != Some(false) {
+                    connected = Some(false);
+                    on_status(LiveStatus { connected: false, message: e.to_string(), project_dir: None });
+                }
+                let mut waited = Duration::ZERO;
+                while waited < RETRY_INTERVAL && keep_running() {
+                    thread::sleep(POLL_INTERVAL);
+                    waited += POLL_INTERVAL;
+                }
+            }
+        }
+    }
+}
+
+/// 开始实时同步：订阅 Wwise 中对象的重命名、创建、删除和工程保存
+///
+/// 修改记录在工程搜索使用的叠加层中，并以 `waapi-live-change` 事件发送给前端，
+/// 连接状态以 `waapi-live-status` 事件发送。再次调用时替换之前的同步并清空记录的修改。
+#[tauri::command]
+pub fn start_live_sync(app: AppHandle, host: String, port: u16) -> Result<(), String> {
+    let generation = LIVE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    *LIVE_OVERLAY.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    thread::spawn(move || {
+        let is_current = || LIVE_GENERATION.load(Ordering::SeqCst) == generation;
+        let mut client = WaapiClient::new(&host, port);
+        run_live_sync(
+            &mut client,
+            is_current,
+            |change| {
+                // 停止或替换同步后，旧线程可能还在处理已收到的事件
+                if !is_current() {
+                    return;
+                }
+                let mut overlay = LIVE_OVERLAY.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(overlay) = overlay.as_mut() {
+                    overlay.apply(change);
+                }
+                let _ = app.emit(LIVE_CHANGE_EVENT, change.clone());
+            },
+            |status| {
+                if !is_current() {
+                    return;
+                }
+                if status.connected {
+                    let mut overlay = LIVE_OVERLAY.lock().unwrap_or_else(|e| e.into_inner());
+                    LiveOverlay::reconnect(&mut overlay, status.project_dir.as_deref());
+                }
+                let _ = app.emit(LIVE_STATUS_EVENT, status);
+            },
+        );
+        client.disconnect();
+    });
+    Ok(())
+}
+
+/// 停止实时同步并清空记录的修改
+#[tauri::command]
+pub fn stop_live_sync() {
+    LIVE_GENERATION.fetch_add(1, Ordering::SeqCst);
+    *LIVE_OVERLAY.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
@@ -0,0 +1,118 @@
+use std::path::{Path, PathBuf};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::WalkBuilder;
+use serde::Serialize;
+use crate::modules::team_config::load_team_config;
+
+/// wid 专用的忽略文件名，语法与 .gitignore 相同，可放在工程中的任意目录
+pub const WID_IGNORE_FILE_NAME: &str = ".widignore";
+
+/// 默认跳过的目录：Wwise 的缓存、备份目录和版本控制目录
+const SKIPPED_DIRECTORIES: &[&str] = &[".cache", ".backup", ".git"];
+
+/// 目录遍历的统计
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ScanStats {
+    /// 访问过的文件数（不含被忽略的目录中的文件）
+    pub files_visited: usize,
+    /// 匹配扩展名的文件数
+    pub files_matched: usize,
+    /// 无法读取的目录项数
+    pub errors: usize,
+}
+
+/// 目录遍历的结果
+#[derive(Debug, Default)]
+pub struct ProjectFiles {
+    pub files: Vec<PathBuf>,
+    pub stats: ScanStats,
+}
+
+/// 遍历工程目录，查找指定扩展名的文件
+///
+/// 路径按字面处理，不受 `[`、`]`、`*` 等字符影响。遵循 `.gitignore`、`.widignore`
+/// 以及 `.wid.toml` 中的 `ignore` 规则，并默认跳过 `.cache`、`.backup` 和 `.git` 目录。
+/// 跟随符号链接，链接成环时跳过。结果按路径排序，保证多次扫描顺序一致。
+///
+/// # 参数
+/// * `directory` - 工程目录
+/// * `extension` - 扩展名（不含点，不区分大小写），如 `wwu`
+///
+/// # 返回
+/// * `Ok(ProjectFiles)` - 匹配的文件和遍历统计
+/// * `Err(String)` - 目录不存在或忽略规则无效，返回错误信息
+pub fn walk_project_files(directory: &Path, extension: &str) -> Result<ProjectFiles, String> {
+    if !directory.is_dir() {
+        return Err("目录不存在".to_string());
+    }
+
+    let team_ignore = team_ignore_rules(directory)?;
+    let mut builder = WalkBuilder::new(directory);
+    builder
+        .hidden(false)
+        .parents(false)
+        .git_ignore(true)
+        .git_global(false)
+        .git_exclude(false)
+        .require_git(false)
+        .follow_links(true)
+        .add_custom_ignore_filename(WID_IGNORE_FILE_NAME)
+        .filter_entry(move |entry| {
+            if entry.depth() == 0 {
+                return true;
+            }
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            if is_dir && is_skipped_directory(entry.file_name()) {
+                return false;
+            }
+            !team_ignore
+                .matched_path_or_any_parents(entry.path(), is_dir)
+                .is_ignore()
+        });
+
+    let mut result = ProjectFiles::default();
+    for entry in builder.build() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(_) => {
+                result.stats.errors += 1;
+                continue;
+            }
+        };
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+
+        result.stats.files_visited += 1;
+        let matches = entry
+            .path()
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(extension));
+        if matches {
+            result.files.push(entry.into_path());
+        }
+    }
+
+    result.files.sort();
+    result.stats.files_matched = result.files.len();
+    Ok(result)
+}
+
+fn is_skipped_directory(name: &std::ffi::OsStr) -> bool {
+    SKIPPED_DIRECTORIES
+        .iter()
+        .any(|skipped| name.eq_ignore_ascii_case(skipped))
+}
+
+/// `.wid.toml` 中的 `ignore` 规则，按 .gitignore 语法相对工程目录解析
+fn team_ignore_rules(directory: &Path) -> Result<Gitignore, String> {
+    let mut builder = GitignoreBuilder::new(directory);
+    if let Some(team) = load_team_config(directory)? {
+        for pattern in &team.config.ignore {
+            builder
+                .add_line(None, pattern)
+                .map_err(|e| format!("忽略规则 \"{}\" 无效: {}", pattern, e))?;
+        }
+    }
+    builder.build().map_err(|e| format!("忽略规则无效: {}", e))
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use roxmltree::{Document, Node};
+use serde::Serialize;
+use crate::modules::schema::{document_schema_version, wwise_version_label};
+use crate::modules::utils::resolve_path;
+
+/// Wwise 默认的 SoundBank 输出目录（相对工程目录），其下为各平台子目录
+const DEFAULT_SOUNDBANK_ROOT: &str = "GeneratedSoundBanks";
+/// Wwise 默认的 Originals 目录
+const DEFAULT_ORIGINALS_PATH: &str = "Originals";
+/// Wwise 默认的 Cache 目录
+const DEFAULT_CACHE_PATH: &str = ".cache";
+
+/// 工程中的平台
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectPlatform {
+    pub name: String,
+    pub guid: String,
+    /// 该平台的 SoundBank 输出目录（绝对路径）
+    pub bank_directory: String,
+    /// 输出目录是否已存在（即是否已生成过 SoundBank）
+    pub bank_directory_exists: bool,
+}
+
+/// 从 .wproj 中读取的工程设置，路径均已按工程目录解析为绝对路径
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectSettings {
+    pub name: String,
+    pub wproj_path: String,
+    /// `WwiseDocument` 的 `SchemaVersion`
+    pub schema_version: Option<u32>,
+    /// 根据 `SchemaVersion` 推测的 Wwise 版本
+    pub wwise_version: Option<String>,
+    pub platforms: Vec<ProjectPlatform>,
+    pub languages: Vec<String>,
+    pub default_language: Option<String>,
+    pub originals_directory: String,
+    pub cache_directory: String,
+    /// Wwise_IDs.h 所在目录
+    pub header_directory: Option<String>,
+}
+
+impl ProjectSettings {
+    /// 默认平台的 Bank 目录：第一个已生成 SoundBank 的平台
+    pub fn default_bank_directory(&self) -> Option<&str> {
+        self.platforms
+            .iter()
+            .find(|platform| platform.bank_directory_exists)
+            .map(|platform| platform.bank_directory.as_str())
+    }
+
+    /// 指定平台的 Bank 目录（不区分大小写）
+    pub fn bank_directory(&self, platform: &str) -> Option<&str> {
+        self.platforms
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(platform))
+            .map(|p| p.bank_directory.as_str())
+    }
+}
+
+/// 查找工程目录中的 .wproj 文件
+pub fn find_wproj(project_dir: &Path) -> Result<Option<PathBuf>, String> {
+    let entries = fs::read_dir(project_dir).map_err(|e| format!("无法读取目录: {}", e))?;
+    Ok(entries
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "wproj")))
+}
+
+/// 解析 .wproj 文件
+///
+/// 读取 SoundBank 输出路径（按平台）、Originals 和 Cache 目录、语言和平台。
+/// 不同 Wwise 版本中属性可能直接写在 `Value` 属性上，也可能按平台写在 `ValueList` 中，
+/// 平台可能以名称或 GUID 引用，这里都做了兼容；缺少的设置使用 Wwise 的默认值。
+///
+/// # 参数
+/// * `wproj_path` - .wproj 文件路径
+///
+/// # 返回
+/// * `Ok(ProjectSettings)` - 工程设置
+/// * `Err(String)` - 读取或解析失败，返回错误信息
+pub fn parse_wproj(wproj_path: &Path) -> Result<ProjectSettings, String> {
+    let content = fs::read_to_string(wproj_path).map_err(|e| format!("读取 .wproj 失败: {}", e))?;
+    let doc = Document::parse(&content).map_err(|e| format!("解析 .wproj 失败: {}", e))?;
+    let project_dir = wproj_path.parent().unwrap_or(Path::new("."));
+    let root = doc.root_element();
+
+    let project = root.descendants().find(|n| n.has_tag_name("Project"));
+    let name = project
+        .and_then(|p| p.attribute("Name"))
+        .map(str::to_string)
+        .or_else(|| wproj_path.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_default();
+    let schema_version = document_schema_version(&root);
+
+    // 平台 GUID → 名称，用于解析以 GUID 引用平台的设置
+    let platform_nodes: Vec<Node> = root
+        .descendants()
+        .filter(|n| n.has_tag_name("Platform") && n.attribute("Name").is_some())
+        .collect();
+    let platform_names: HashMap<String, String> = platform_nodes
+        .iter()
+        .filter_map(|n| Some((n.attribute("ID")?.to_uppercase(), n.attribute("Name")?.to_string())))
+        .collect();
+    let platform_name = |reference: &str| {
+        platform_names
+            .get(&reference.to_uppercase())
+            .cloned()
+            .unwrap_or_else(|| reference.to_string())
+    };
+
+    // 各平台的 SoundBank 输出路径
+    let mut bank_paths: HashMap<String, String> = HashMap::new();
+    for property in properties(root, "SoundBankPaths") {
+        for value in property.descendants().filter(|n| n.has_tag_name("Value")) {
+            if let (Some(platform), Some(path)) = (value.attribute("Platform"), value.text()) {
+                bank_paths.insert(platform_name(platform).to_lowercase(), path.trim().to_string());
+            }
+        }
+    }
+
+    let platforms = platform_nodes
+        .iter()
+        .filter_map(|node| {
+            let name = node.attribute("Name")?.to_string();
+            let relative = bank_paths
+                .get(&name.to_lowercase())
+                .cloned()
+                .unwrap_or_else(|| format!("{}/{}", DEFAULT_SOUNDBANK_ROOT, name));
+            let bank_directory = resolve_project_path(project_dir, &relative);
+            Some(ProjectPlatform {
+                guid: node.attribute("ID").unwrap_or_default().to_string(),
+                bank_directory_exists: bank_directory.is_dir(),
+                bank_directory: bank_directory.to_string_lossy().to_string(),
+                name,
+            })
+        })
+        .collect();
+
+    // 语言：<Language Name="..." ID="..."/>
+    let language_nodes: Vec<Node> = root
+        .descendants()
+        .filter(|n| n.has_tag_name("Language") && n.attribute("Name").is_some())
+        .collect();
+    let mut languages: Vec<String> = Vec::new();
+    for node in &language_nodes {
+        let name = node.attribute("Name").unwrap_or_default().to_string();
+        if !languages.contains(&name) {
+            languages.push(name);
+        }
+    }
+    let default_language = property_value(root, "DefaultLanguage").map(|value| {
+        language_nodes
+            .iter()
+            .find(|n| n.attribute("ID").is_some_and(|id| id.eq_ignore_ascii_case(&value)))
+            .and_then(|n| n.attribute("Name"))
+            .map(str::to_string)
+            .unwrap_or(value)
+    });
+
+    let directory = |property: &str, default: &str| {
+        let relative = property_value(root, property).unwrap_or_else(|| default.to_string());
+        resolve_project_path(project_dir, &relative).to_string_lossy().to_string()
+    };
+
+    Ok(ProjectSettings {
+        name,
+        wproj_path: wproj_path.to_string_lossy().to_string(),
+        schema_version,
+        wwise_version: schema_version.and_then(wwise_version_label),
+        platforms,
+        languages,
+        default_language,
+        originals_directory: directory("OriginalsPath", DEFAULT_ORIGINALS_PATH),
+        cache_directory: directory("CacheFilePath", DEFAULT_CACHE_PATH),
+        header_directory: property_value(root, "SoundBankHeaderFilePath")
+            .map(|path| resolve_project_path(project_dir, &path).to_string_lossy().to_string()),
+    })
+}
+
+/// 名称为 `name` 的所有 `<Property>` 节点
+fn properties<'a, 'input>(root: Node<'a, 'input>, name: &'a str) -> impl Iterator<Item = Node<'a, 'input>> {
+    root.descendants()
+        .filter(move |n| n.has_tag_name("Property") && n.attribute("Name") == Some(name))
+}
+
+/// 属性的值：`Value` 属性，或 `ValueList` 中第一个非空的值
+fn property_value(root: Node, name: &str) -> Option<String> {
+    properties(root, name).find_map(|property| {
+        property
+            .attribute("Value")
+            .map(str::to_string)
+            .or_else(|| {
+                property
+                    .descendants()
+                    .filter(|n| n.has_tag_name("Value"))
+                    .find_map(|n| n.text().map(str::trim).filter(|t| !t.is_empty()).map(str::to_string))
+            })
+            .filter(|value| !value.trim().is_empty())
+    })
+}
+
+/// .wproj 中的路径使用 Windows 分隔符，且相对工程目录
+fn resolve_project_path(project_dir: &Path, path: &str) -> PathBuf {
+    resolve_path(project_dir, &path.replace('\\', "/"))
+}
@@ -0,0 +1,83 @@
+//! SoundBank 包含计算测试，使用 `tests/fixtures/bank_project/`
+
+mod common;
+
+use common::{bank_project, bank_project_banks, fixture_dir};
+use wid_lib::modules::bank_inclusion::BankContents;
+use wid_lib::modules::{find_object_banks, get_bank_inclusions};
+
+fn bank<'a>(banks: &'a [BankContents], name: &str) -> &'a BankContents {
+    banks.iter().find(|b| b.name == name).unwrap()
+}
+
+fn names<T>(items: &[T], name: fn(&T) -> &str) -> Vec<&str> {
+    items.iter().map(name).collect()
+}
+
+#[test]
+fn resolves_events_structures_and_media_by_filter() {
+    let report = get_bank_inclusions(bank_project(), None).unwrap();
+    assert_eq!(names(&report.banks, |b| &b.name), ["Common", "Weapons"]);
+    assert!(report.differences.is_empty());
+
+    // Play_Pistol 包含全部内容，Stop_Pistol 只包含 Event
+    let weapons = bank(&report.banks, "Weapons");
+    assert_eq!(names(&weapons.events, |e| &e.name), ["Play_Pistol", "Stop_Pistol"]);
+    assert_eq!(names(&weapons.events, |e| &e.via), ["Play_Pistol", "Stop_Pistol"]);
+    // 目标 Pistol 及其上级 Weapons；SetState 的目标不是层级结构
+    assert_eq!(names(&weapons.structures, |s| &s.name), ["Weapons", "Pistol"]);
+    assert_eq!(weapons.structures[1].path, "\\Actor-Mixer Hierarchy\\Default Work Unit\\Weapons\\Pistol");
+    // 每种语言各自的激活源
+    let media: Vec<(&str, &str)> = weapons.media.iter().map(|m| (m.id.as_str(), m.language.as_str())).collect();
+    assert_eq!(media, [("200012", "English(US)"), ("200013", "French(France)")]);
+    assert_eq!(weapons.media[0].name, "Pistol_EN.wav");
+
+    // 包含工作单元等于包含其下的所有 Event
+    let common = bank(&report.banks, "Common");
+    assert_eq!(names(&common.events, |e| &e.name), ["Play_Pistol", "Stop_Pistol"]);
+    assert_eq!(names(&common.events, |e| &e.via), ["Default Work Unit", "Default Work Unit"]);
+    assert!(common.structures.is_empty() && common.media.is_empty());
+}
+
+#[test]
+fn answers_which_banks_contain_an_object() {
+    let banks = find_object_banks(bank_project(), "Play_Pistol".to_string()).unwrap();
+    let found: Vec<(&str, &str)> = banks.iter().map(|b| (b.bank.as_str(), b.kind.as_str())).collect();
+    assert_eq!(found, [("Common", "event"), ("Weapons", "event")]);
+
+    let by_short_id = find_object_banks(bank_project(), "100011".to_string()).unwrap();
+    assert_eq!(by_short_id.len(), 1);
+    assert_eq!((by_short_id[0].bank.as_str(), by_short_id[0].kind.as_str()), ("Weapons", "structure"));
+
+    let media = find_object_banks(bank_project(), "200013".to_string()).unwrap();
+    assert_eq!((media[0].name.as_str(), media[0].via.as_str()), ("Pistol_FR.wav", "Play_Pistol"));
+
+    assert!(find_object_banks(bank_project(), "Engine".to_string()).unwrap().is_empty());
+}
+
+#[test]
+fn compares_with_soundbanks_info() {
+    let report = get_bank_inclusions(bank_project(), Some(bank_project_banks())).unwrap();
+
+    // Weapons 一致：French(France) 没有生成，不比较该语言的媒体
+    assert_eq!(report.differences.len(), 1);
+    let common = &report.differences[0];
+    assert_eq!(common.bank, "Common");
+    assert!(common.generated);
+    assert_eq!(common.missing_events, ["Stop_Pistol"]);
+    assert!(common.extra_events.is_empty() && common.missing_media.is_empty());
+    assert_eq!(common.extra_media, ["200012"]);
+}
+
+#[test]
+fn compares_streamed_media_from_legacy_soundbanks_info() {
+    // 旧版 XML 中 French(France) 的媒体流播放，只出现在 ReferencedStreamedFiles 中；
+    // 生成了 French(France) 的 VO，因此比较该语言的媒体
+    let report = get_bank_inclusions(bank_project(), Some(fixture_dir("streamed_banks"))).unwrap();
+    let differences: Vec<(&str, &[String], &[String])> = report
+        .differences
+        .iter()
+        .map(|d| (d.bank.as_str(), d.missing_media.as_slice(), d.extra_media.as_slice()))
+        .collect();
+    assert!(differences.is_empty(), "{:?}", differences);
+}
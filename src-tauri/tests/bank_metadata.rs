@@ -0,0 +1,87 @@
+//! 每个 Bank 单独的元数据文件和 ProjectInfo / PlatformInfo / PluginInfo 测试，使用 `tests/fixtures/per_bank/`
+//!
+//! `Windows/` 中没有 SoundbanksInfo，`English(US)/VO.json` 是语言子目录中的本地化 Bank，
+//! ProjectInfo.json 位于上一级的 GeneratedSoundBanks 目录。
+
+mod common;
+
+use common::{fixture_dir, fixture_path};
+use wid_lib::modules::bank_metadata::discover_bank_metadata;
+use wid_lib::modules::index::read_metadata_file;
+use wid_lib::modules::search_bank_directory;
+use wid_lib::modules::types::SearchResult;
+use wid_lib::modules::validate_bank_directory;
+
+fn search(id: &str) -> Vec<SearchResult> {
+    let types = vec!["GUID".to_string(), "ShortID".to_string(), "MediaID".to_string()];
+    search_bank_directory(fixture_dir("per_bank/GeneratedSoundBanks/Windows"), id.to_string(), types, None)
+        .unwrap()
+        .results
+}
+
+#[test]
+fn discovers_per_bank_and_info_files() {
+    let root = fixture_path("per_bank/GeneratedSoundBanks");
+    let windows = root.join("Windows");
+    let files = discover_bank_metadata(&windows).unwrap();
+
+    assert!(files.soundbanks_info.is_none());
+    assert_eq!(files.bank_files, [windows.join("English(US)/VO.json"), windows.join("Main.json")]);
+    // 平台目录中没有 ProjectInfo 时在上一级目录中查找
+    assert_eq!(files.project_info, Some(root.join("ProjectInfo.json")));
+    assert_eq!(files.platform_info, Some(windows.join("PlatformInfo.json")));
+    assert_eq!(files.plugin_info, Some(windows.join("PluginInfo.json")));
+
+    let roots: Vec<&str> = files.info_files().iter().map(|(_, root)| *root).collect();
+    assert_eq!(roots, ["ProjectInfo", "PlatformInfo", "PluginInfo"]);
+    for (path, root) in files.info_files() {
+        assert!(read_metadata_file(path, root).unwrap().schema.is_some(), "{}", path.display());
+    }
+
+    let info = validate_bank_directory(fixture_dir("per_bank/GeneratedSoundBanks/Windows")).unwrap();
+    assert_eq!((info.soundbanks_info, info.bank_file_count), (None, 2));
+}
+
+#[test]
+fn searches_localized_per_bank_files() {
+    // 本地化 Bank 自身和只在其中的媒体
+    let vo = search("1102221223");
+    assert_eq!((vo[0].name.as_str(), vo[0].object_type.as_str()), ("VO", "SoundBank"));
+
+    let greeting = search("500004");
+    assert_eq!(greeting.len(), 1);
+    assert_eq!(greeting[0].name, "Greeting.wav");
+    assert_eq!(greeting[0].banks[0].path, "English(US)/VO.bnk");
+    assert_eq!(greeting[0].banks[0].language, "English(US)");
+
+    // 同一媒体在两个单独的 Bank 文件中，合并为一条结果
+    let footstep = search("500001");
+    let banks: Vec<&str> = footstep[0].banks.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(banks, ["VO", "Main"]);
+}
+
+#[test]
+fn searches_project_platform_and_plugin_info() {
+    // ProjectInfo 中的语言和平台
+    let language = search("{6B000000-0000-4000-8000-0000000000E1}");
+    assert_eq!((language[0].name.as_str(), language[0].object_type.as_str()), ("English(US)", "Language"));
+    let platform = search("{6B000000-0000-4000-8000-0000000000F1}");
+    assert_eq!((platform[0].name.as_str(), platform[0].object_type.as_str()), ("Windows", "Platform"));
+
+    // PluginInfo 中的自定义插件、ShareSet 和音频设备
+    let plugins: Vec<(String, String)> = ["652831249", "1017222595", "3859886410"]
+        .iter()
+        .map(|id| {
+            let result = &search(id)[0];
+            (result.name.clone(), result.object_type.clone())
+        })
+        .collect();
+    assert_eq!(
+        plugins,
+        [
+            ("Hall_Large".to_string(), "CustomPlugin".to_string()),
+            ("Room_Small".to_string(), "ShareSet".to_string()),
+            ("System".to_string(), "AudioDevice".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,52 @@
+//! Bank 目录搜索测试，使用 `tests/fixtures/bank_project/GeneratedSoundBanks/`
+
+mod common;
+
+use common::bank_project_banks;
+use wid_lib::modules::search_bank_directory;
+use wid_lib::modules::types::{OwningBank, SearchResult};
+
+fn search(id: &str) -> Vec<SearchResult> {
+    let types = vec!["GUID".to_string(), "ShortID".to_string(), "MediaID".to_string()];
+    search_bank_directory(bank_project_banks(), id.to_string(), types, None).unwrap().results
+}
+
+fn bank_names(result: &SearchResult) -> Vec<&str> {
+    result.banks.iter().map(|b| b.name.as_str()).collect()
+}
+
+#[test]
+fn lists_every_bank_containing_an_object() {
+    // Play_Pistol 同时在 Weapons 和 Common 中，合并为一条结果
+    let events = search("100060");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].name, "Play_Pistol");
+    assert_eq!(
+        events[0].banks[0],
+        OwningBank {
+            name: "Weapons".to_string(),
+            id: "1559875400".to_string(),
+            path: "Weapons.bnk".to_string(),
+            language: "SFX".to_string(),
+        }
+    );
+    assert_eq!(bank_names(&events[0]), ["Weapons", "Common"]);
+
+    let media: Vec<SearchResult> = search("200012").into_iter().filter(|r| r.object_type == "Media").collect();
+    assert_eq!(media.len(), 1);
+    assert_eq!(bank_names(&media[0]), ["Weapons", "Common"]);
+
+    // 只在一个 SoundBank 中的 Event
+    assert_eq!(bank_names(&search("100063")[0]), ["Weapons"]);
+}
+
+#[test]
+fn bank_queries_match_any_containing_bank() {
+    let results = search("type:Event bank:Common");
+    let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["Play_Pistol"]);
+
+    let only_weapons = search("type:Event -bank:Common");
+    let names: Vec<&str> = only_weapons.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["Stop_Pistol"]);
+}
@@ -0,0 +1,51 @@
+//! 集成测试共用的夹具路径和夹具工程中对象的 GUID
+//!
+//! - `sample_project/`：属性、引用、RTPC、State、多语言源、嵌套工作单元和总线路由
+//! - `bank_project/`：在 sample_project 的基础上增加 Event 动作、Switch Container、SoundBank 和 SoundbanksInfo
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+pub const WEAPONS_GUID: &str = "{5A000000-0000-4000-8000-000000000010}";
+pub const PISTOL_GUID: &str = "{5A000000-0000-4000-8000-000000000011}";
+pub const PISTOL_EN_GUID: &str = "{5A000000-0000-4000-8000-000000000012}";
+pub const ENGINE_GUID: &str = "{5A000000-0000-4000-8000-000000000018}";
+pub const SWITCH_CONTAINER_GUID: &str = "{5A000000-0000-4000-8000-00000000001A}";
+pub const MASTER_GUID: &str = "{5A000000-0000-4000-8000-000000000020}";
+pub const SFX_GUID: &str = "{5A000000-0000-4000-8000-000000000021}";
+pub const REVERB_GUID: &str = "{5A000000-0000-4000-8000-000000000022}";
+pub const HEALTH_GUID: &str = "{5A000000-0000-4000-8000-000000000040}";
+pub const VORBIS_GUID: &str = "{5A000000-0000-4000-8000-000000000050}";
+pub const PLAY_PISTOL_GUID: &str = "{5A000000-0000-4000-8000-000000000060}";
+pub const PLAY_ACTION_GUID: &str = "{5A000000-0000-4000-8000-000000000061}";
+pub const WEAPONS_BANK_GUID: &str = "{5A000000-0000-4000-8000-000000000070}";
+pub const COMMON_BANK_GUID: &str = "{5A000000-0000-4000-8000-000000000071}";
+pub const AUDIO_WORK_UNIT_GUID: &str = "{5A000000-0000-4000-8000-0000000000A0}";
+pub const VEHICLES_GUID: &str = "{5A000000-0000-4000-8000-0000000000A1}";
+pub const EVENTS_WORK_UNIT_GUID: &str = "{5A000000-0000-4000-8000-0000000000F0}";
+/// 两个工程中都不存在的 GUID
+pub const UNKNOWN_GUID: &str = "{5A000000-0000-4000-8000-0000000000AA}";
+
+/// `tests/fixtures/` 下的路径
+pub fn fixture_path(relative: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(relative)
+}
+
+/// `tests/fixtures/` 下的路径，转换为命令参数使用的字符串
+pub fn fixture_dir(relative: &str) -> String {
+    fixture_path(relative).to_string_lossy().to_string()
+}
+
+pub fn sample_project() -> String {
+    fixture_dir("sample_project/Project")
+}
+
+pub fn bank_project() -> String {
+    fixture_dir("bank_project/Project")
+}
+
+/// bank_project 生成的 Windows SoundBank 目录
+pub fn bank_project_banks() -> String {
+    fixture_dir("bank_project/GeneratedSoundBanks/Windows")
+}
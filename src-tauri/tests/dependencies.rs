@@ -0,0 +1,144 @@
+//! 依赖图测试，使用 `tests/fixtures/bank_project/`
+
+mod common;
+
+use common::{
+    bank_project, COMMON_BANK_GUID, EVENTS_WORK_UNIT_GUID, HEALTH_GUID, PISTOL_EN_GUID, PISTOL_GUID, PLAY_ACTION_GUID,
+    PLAY_PISTOL_GUID, SWITCH_CONTAINER_GUID, VORBIS_GUID, WEAPONS_BANK_GUID, WEAPONS_GUID,
+};
+use wid_lib::modules::dependencies::{
+    EDGE_ACTION, EDGE_CHILD, EDGE_INCLUSION, EDGE_MEDIA, EDGE_SHARESET, EDGE_SWITCH_GROUP, EDGE_TARGET,
+};
+use wid_lib::modules::graph::{Graph, GraphEdge, GraphFormat, GraphNode};
+use wid_lib::modules::{find_dependency_paths, get_dependency_graph};
+
+/// 从指定对象出发的指定类型的边：(目标, 说明)
+fn edges<'a>(graph: &'a Graph, source: &str, kind: &str) -> Vec<(&'a str, &'a str)> {
+    graph
+        .edges
+        .iter()
+        .filter(|e| e.source == source && e.kind == kind)
+        .map(|e| (e.target.as_str(), e.label.as_str()))
+        .collect()
+}
+
+#[test]
+fn builds_typed_dependency_edges() {
+    let graph = get_dependency_graph(bank_project()).unwrap();
+
+    assert_eq!(edges(&graph, PLAY_PISTOL_GUID, EDGE_ACTION).len(), 2);
+    assert_eq!(edges(&graph, PLAY_ACTION_GUID, EDGE_TARGET), [(PISTOL_GUID, "Target")]);
+    assert_eq!(edges(&graph, WEAPONS_GUID, EDGE_CHILD), [(PISTOL_GUID, "")]);
+    assert_eq!(edges(&graph, SWITCH_CONTAINER_GUID, EDGE_SWITCH_GROUP), [(HEALTH_GUID, "SwitchGroupOrStateGroup")]);
+    assert_eq!(edges(&graph, PISTOL_EN_GUID, EDGE_MEDIA), [("media:200012", "")]);
+    assert_eq!(graph.node("media:200012").unwrap().name, "Pistol_EN.wav");
+
+    // 自定义 Effect 定义在对象内部，不是 ShareSet；工程中找不到的 Attenuation 保留引用中的名称
+    let sharesets = edges(&graph, PISTOL_GUID, EDGE_SHARESET);
+    assert_eq!(sharesets.len(), 2);
+    assert_eq!(sharesets[0], (VORBIS_GUID, "Conversion"));
+    let attenuation = graph.node(sharesets[1].0).unwrap();
+    assert_eq!((attenuation.name.as_str(), sharesets[1].1), ("Missing_Attenuation", "Attenuation"));
+
+    assert_eq!(
+        edges(&graph, WEAPONS_BANK_GUID, EDGE_INCLUSION),
+        [
+            (PLAY_PISTOL_GUID, "events, structures, media"),
+            ("{5A000000-0000-4000-8000-000000000063}", "events"),
+        ]
+    );
+    assert_eq!(edges(&graph, COMMON_BANK_GUID, EDGE_INCLUSION), [(EVENTS_WORK_UNIT_GUID, "events")]);
+}
+
+#[test]
+fn finds_how_an_event_reaches_media() {
+    let paths = find_dependency_paths(bank_project(), "Play_Pistol".to_string(), "200012".to_string()).unwrap();
+    assert_eq!(paths.len(), 1);
+    let kinds: Vec<&str> = paths[0].edges.iter().map(|e| e.kind.as_str()).collect();
+    assert_eq!(kinds, [EDGE_ACTION, EDGE_TARGET, EDGE_CHILD, EDGE_MEDIA]);
+    let names: Vec<&str> = paths[0].nodes.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(names, ["Play_Pistol", "", "Pistol", "Pistol_EN", "Pistol_EN.wav"]);
+
+    // SoundBank 通过包含的工作单元到达其中的两个 Event，两者都以 Pistol 为目标
+    let paths = find_dependency_paths(bank_project(), "3991942870".to_string(), "200013".to_string()).unwrap();
+    let events: Vec<&str> = paths.iter().map(|p| p.nodes[2].name.as_str()).collect();
+    assert_eq!(events, ["Play_Pistol", "Stop_Pistol"]);
+    assert!(paths.iter().all(|p| p.nodes[1].id == EVENTS_WORK_UNIT_GUID && p.nodes.len() == 7));
+
+    let none = find_dependency_paths(bank_project(), "Low".to_string(), "200012".to_string()).unwrap();
+    assert!(none.is_empty());
+    let missing = find_dependency_paths(bank_project(), "Play_Music".to_string(), "200012".to_string());
+    assert!(missing.unwrap_err().contains("未找到对象 Play_Music"));
+}
+
+#[test]
+fn exports_graphml() {
+    let graph = get_dependency_graph(bank_project()).unwrap();
+    let graphml = graph.render(GraphFormat::GraphMl, "dependencies").unwrap();
+    assert!(graphml.contains("<graph id=\"G\" edgedefault=\"directed\">"));
+    assert!(graphml.contains(&format!("<node id=\"{}\">", PISTOL_GUID)));
+    assert!(graphml.contains("<data key=\"path\">\\Actor-Mixer Hierarchy\\Default Work Unit\\Weapons\\Pistol</data>"));
+    assert!(graphml.contains(&format!(
+        "source=\"{}\" target=\"{}\">\n      <data key=\"kind\">switch_group</data>",
+        SWITCH_CONTAINER_GUID, HEALTH_GUID
+    )));
+    assert_eq!(GraphFormat::from_path(std::path::Path::new("deps.graphml")).unwrap(), GraphFormat::GraphMl);
+}
+
+fn ladder_graph(layers: usize) -> Graph {
+    let mut graph = Graph::default();
+    let mut add = |id: String| {
+        graph.add_node(GraphNode {
+            id: id.clone(),
+            name: id,
+            object_type: String::new(),
+            short_id: String::new(),
+            path: String::new(),
+        })
+    };
+    add("start".to_string());
+    add("end".to_string());
+    for layer in 0..layers {
+        add(format!("a{}", layer));
+        add(format!("b{}", layer));
+    }
+    let edge = |source: &str, target: &str| GraphEdge {
+        source: source.to_string(),
+        target: target.to_string(),
+        kind: EDGE_CHILD.to_string(),
+        label: String::new(),
+    };
+
+    // 每层两个节点都连到下一层的两个节点，从 start 到 end 共有 2^layers 条长路径
+    let mut previous = vec!["start".to_string()];
+    for layer in 0..layers {
+        let current = vec![format!("a{}", layer), format!("b{}", layer)];
+        for source in &previous {
+            for target in &current {
+                graph.add_edge(edge(source, target));
+            }
+        }
+        previous = current;
+    }
+    for source in &previous {
+        graph.add_edge(edge(source, "end"));
+    }
+    // 最后加入的捷径
+    graph.add_edge(edge("a0", "end"));
+    graph.add_edge(edge("start", "end"));
+    graph
+}
+
+#[test]
+fn returns_the_shortest_paths_first() {
+    let graph = ladder_graph(16);
+    let paths = graph.paths("start", "end", 3);
+    let lengths: Vec<usize> = paths.iter().map(|p| p.edges.len()).collect();
+    assert_eq!(lengths, [1, 2, 17]);
+    let names: Vec<&str> = paths[1].nodes.iter().map(|n| n.id.as_str()).collect();
+    assert_eq!(names, ["start", "a0", "end"]);
+
+    assert_eq!(graph.paths("start", "end", 100).len(), 100);
+    assert_eq!(ladder_graph(2).paths("start", "end", 100).len(), 6);
+    assert!(graph.paths("end", "start", 10).is_empty());
+}
@@ -0,0 +1,92 @@
+//! Event 动作报告测试，使用 `tests/fixtures/bank_project/` 的工程和 SoundbanksInfo
+
+mod common;
+
+use common::{bank_project, bank_project_banks, PLAY_PISTOL_GUID};
+use wid_lib::modules::event_report::EventReport;
+use wid_lib::modules::get_event_report;
+
+fn report(project: bool, banks: bool, event: &str) -> EventReport {
+    let project = project.then(bank_project);
+    let banks = banks.then(bank_project_banks);
+    get_event_report(project, banks, event.to_string()).expect("生成 Event 报告失败")
+}
+
+#[test]
+fn lists_actions_with_targets_delays_and_fades() {
+    let report = report(true, false, "100060");
+    assert_eq!(report.source, "project");
+    assert_eq!(report.event.name, "Play_Pistol");
+    assert!(report.banks.is_empty());
+    assert_eq!(report.actions.len(), 2);
+
+    let play = &report.actions[0];
+    assert_eq!(play.action_type, "Play");
+    let target = play.target.as_ref().unwrap();
+    assert_eq!(target.target_path, "\\Actor-Mixer Hierarchy\\Default Work Unit\\Weapons\\Pistol");
+    assert_eq!(play.scope.as_deref(), Some("GameObject"));
+    assert_eq!((play.delay, play.fade_time), (Some(0.25), Some(1.0)));
+    assert_eq!(play.fade_curve.as_deref(), Some("Sine"));
+    assert_eq!(play.properties.len(), 1);
+    assert_eq!((play.properties[0].name.as_str(), play.properties[0].value.as_str()), ("Probability", "80"));
+
+    let set_state = &report.actions[1];
+    assert_eq!(set_state.action_type, "SetState");
+    let target = set_state.target.as_ref().unwrap();
+    assert_eq!((target.target_name.as_str(), target.target_type.as_str()), ("Low", "State"));
+    assert_eq!(target.target_path, "\\States\\Default Work Unit\\Health\\Low");
+}
+
+#[test]
+fn finds_events_by_name_and_reads_scope() {
+    let report = report(true, false, "Stop_Pistol");
+    let stop = &report.actions[0];
+    assert_eq!(stop.action_type, "Stop");
+    assert_eq!(stop.scope.as_deref(), Some("Global"));
+    assert_eq!(stop.fade_curve.as_deref(), Some("Exp3"));
+    assert_eq!(stop.delay, Some(0.0));
+}
+
+#[test]
+fn adds_containing_banks_and_media_from_soundbanks_info() {
+    let report = report(true, true, PLAY_PISTOL_GUID);
+    assert_eq!(report.source, "project");
+    assert_eq!(report.actions.len(), 2);
+    let banks: Vec<&str> = report.banks.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(banks, ["Weapons", "Common"]);
+    assert_eq!(report.media.len(), 1);
+    assert_eq!((report.media[0].id.as_str(), report.media[0].name.as_str()), ("200012", "Pistol_EN.wav"));
+}
+
+#[test]
+fn falls_back_to_soundbanks_info_without_project_files() {
+    let report = report(false, true, PLAY_PISTOL_GUID);
+    assert_eq!(report.source, "soundbanks");
+    assert_eq!(report.event.short_id, "100060");
+    assert_eq!(report.event.path, "\\Events\\Default Work Unit\\Play_Pistol");
+    assert_eq!(report.actions.len(), 1);
+    let set_state = &report.actions[0];
+    assert_eq!(set_state.action_type, "SetState");
+    assert_eq!(set_state.delay, None);
+    let target = set_state.target.as_ref().unwrap();
+    assert_eq!((target.target_name.as_str(), target.target_path.as_str()), ("Low", "Health\\Low"));
+}
+
+#[test]
+fn falls_back_to_soundbanks_info_when_the_project_cannot_be_read() {
+    let missing = common::fixture_dir("missing_project");
+    let report = get_event_report(Some(missing.clone()), Some(bank_project_banks()), PLAY_PISTOL_GUID.to_string())
+        .expect("工程无法读取时应使用 SoundbanksInfo");
+    assert_eq!(report.source, "soundbanks");
+    assert_eq!(report.actions.len(), 1);
+
+    // 没有 Bank 目录时仍报告工程目录的错误
+    assert!(get_event_report(Some(missing), None, PLAY_PISTOL_GUID.to_string()).is_err());
+}
+
+#[test]
+fn reports_unknown_events_and_missing_directories() {
+    let missing = get_event_report(Some(bank_project()), None, "12345".to_string());
+    assert!(missing.unwrap_err().contains("未找到"));
+    assert!(get_event_report(None, None, "100060".to_string()).is_err());
+}
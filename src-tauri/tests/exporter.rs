@@ -0,0 +1,58 @@
+//! 导出表格测试：CSV 的引号转义和 Markdown 的竖线转义
+
+use serde_json::{json, Map, Value};
+use wid_lib::modules::exporter::{ExportFormat, ExportTable};
+
+fn table(rows: Value) -> ExportTable {
+    let rows: Vec<Map<String, Value>> = serde_json::from_value(rows).unwrap();
+    ExportTable::new(rows)
+}
+
+#[test]
+fn quotes_csv_fields_with_commas_quotes_and_newlines() {
+    let table = table(json!([
+        { "name": "Play, Pistol", "note": "say \"hi\"", "path": "line1\nline2" },
+        { "name": "Stop", "note": "cr\rlf", "banks": [{ "name": "Weapons" }, { "name": "Common" }] },
+        { "name": "Plain", "path": "\\Events\\Plain" },
+    ]));
+    // name 在前，其余字段按出现顺序追加
+    assert_eq!(table.columns, ["name", "note", "path", "banks"]);
+    assert_eq!(
+        table.render(ExportFormat::Csv).unwrap(),
+        concat!(
+            "name,note,path,banks\r\n",
+            "\"Play, Pistol\",\"say \"\"hi\"\"\",\"line1\nline2\",\r\n",
+            "Stop,\"cr\rlf\",,Weapons; Common\r\n",
+            "Plain,,\\Events\\Plain,\r\n",
+        )
+    );
+}
+
+#[test]
+fn escapes_pipes_and_line_breaks_in_markdown() {
+    let table = table(json!([
+        { "name": "A|B", "note": "first\r\nsecond\nthird" },
+        { "name": "C", "note": "\\Events\\Play" },
+    ]));
+    assert_eq!(
+        table.render(ExportFormat::Markdown).unwrap(),
+        concat!(
+            "| name | note |\n",
+            "| --- | --- |\n",
+            "| A\\|B | first<br>second<br>third |\n",
+            "| C | \\Events\\Play |\n",
+        )
+    );
+}
+
+#[test]
+fn writes_csv_with_bom_and_rejects_xlsx_text() {
+    let table = table(json!([{ "name": "Play_Pistol", "short_id": "100060" }]));
+    assert!(table.render(ExportFormat::Xlsx).is_err());
+
+    let path = std::env::temp_dir().join(format!("wid-exporter-{}.csv", std::process::id()));
+    table.write(ExportFormat::from_path(&path).unwrap(), &path).unwrap();
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(contents, "\u{feff}name,short_id\r\nPlay_Pistol,100060\r\n");
+}
@@ -0,0 +1,57 @@
+//! 工程层级浏览测试，使用 `tests/fixtures/sample_project/`
+
+mod common;
+
+use common::{sample_project, AUDIO_WORK_UNIT_GUID, ENGINE_GUID, PISTOL_GUID, UNKNOWN_GUID, VEHICLES_GUID};
+use wid_lib::modules::{get_children, get_parent, get_roots};
+
+#[test]
+fn lists_categories_in_project_explorer_order() {
+    let roots = get_roots(sample_project()).unwrap();
+    let names: Vec<&str> = roots.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["Actor-Mixer Hierarchy", "Master-Mixer Hierarchy", "Events", "States", "Game Parameters", "Conversion Settings"]
+    );
+
+    // 嵌套的工作单元不是顶层工作单元
+    let audio = &roots[0];
+    assert_eq!(audio.category, "AudioObjects");
+    assert_eq!(audio.children.len(), 1);
+    assert_eq!(audio.children[0].guid, AUDIO_WORK_UNIT_GUID);
+    assert_eq!(audio.children[0].child_count, 2);
+    assert_eq!(audio.children[0].parent_guid, "");
+}
+
+#[test]
+fn links_nested_work_units_across_files() {
+    let children = get_children(sample_project(), AUDIO_WORK_UNIT_GUID.to_string()).unwrap();
+    let names: Vec<(&str, &str, usize)> =
+        children.iter().map(|c| (c.name.as_str(), c.object_type.as_str(), c.child_count)).collect();
+    assert_eq!(names, [("Weapons", "ActorMixer", 1), ("Vehicles", "WorkUnit", 1)]);
+
+    let engine = &get_children(sample_project(), VEHICLES_GUID.to_string()).unwrap()[0];
+    assert_eq!(engine.guid, ENGINE_GUID);
+    assert_eq!(engine.path, "\\Actor-Mixer Hierarchy\\Default Work Unit\\Vehicles\\Engine");
+
+    let parent = get_parent(sample_project(), ENGINE_GUID.to_string()).unwrap().unwrap();
+    assert_eq!(parent.name, "Vehicles");
+    let parent = get_parent(sample_project(), parent.guid).unwrap().unwrap();
+    assert_eq!(parent.guid, AUDIO_WORK_UNIT_GUID);
+    assert!(get_parent(sample_project(), parent.guid).unwrap().is_none());
+}
+
+#[test]
+fn hides_sources_and_reports_unknown_objects() {
+    assert!(get_children(sample_project(), PISTOL_GUID.to_string()).unwrap().is_empty());
+
+    let events = get_roots(sample_project()).unwrap().into_iter().find(|c| c.category == "Events").unwrap();
+    let event = &get_children(sample_project(), events.children[0].guid.clone()).unwrap()[0];
+    assert_eq!(event.name, "Play_Pistol");
+    let actions = get_children(sample_project(), event.guid.clone()).unwrap();
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0].object_type, "Action");
+
+    let missing = get_children(sample_project(), UNKNOWN_GUID.to_string());
+    assert!(missing.unwrap_err().contains("未找到"));
+}
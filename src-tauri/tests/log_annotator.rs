@@ -0,0 +1,68 @@
+//! 日志注释测试，使用 `tests/fixtures/sample_project/`
+
+mod common;
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use common::sample_project;
+use wid_lib::modules::id_resolver::IdResolver;
+use wid_lib::modules::log_annotator::LogAnnotator;
+
+fn load_annotator(format: &str, patterns: &[&str]) -> Result<LogAnnotator, String> {
+    let resolver = IdResolver::load(Some(&sample_project()), None)?;
+    let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+    LogAnnotator::new(resolver, format, &patterns)
+}
+
+#[test]
+fn annotates_only_ids_captured_by_custom_patterns() {
+    let annotator = load_annotator("generic", &[r"evt=(?P<id>\d+)", r"bus:(?P<id>0x[0-9A-F]+)"]).unwrap();
+    let (line, count) = annotator.annotate_line("evt=100060 frame 100060 bus:0x186B4");
+    assert_eq!(line, "evt=100060 [wid: Play_Pistol (Event)] frame 100060 bus:0x186B4 [wid: Master Audio Bus (Bus)]");
+    assert_eq!(count, 2);
+
+    // 自定义规则追加在内置格式之后，两条规则命中同一个 ID 时只注释一次
+    let annotator = load_annotator("wwise", &[r"Event ID not found: (?P<id>\d+)"]).unwrap();
+    let (line, count) = annotator.annotate_line("Event ID not found: 100060");
+    assert_eq!((line.as_str(), count), ("Event ID not found: 100060 [wid: Play_Pistol (Event)]", 1));
+}
+
+#[test]
+fn rejects_invalid_patterns_and_formats() {
+    let missing_group = load_annotator("generic", &[r"evt=(\d+)"]).err().unwrap();
+    assert!(missing_group.contains("(?P<id>...)"), "{}", missing_group);
+    assert!(load_annotator("generic", &["evt=(?P<id>"]).err().unwrap().contains("无效"));
+    assert!(load_annotator("unity", &[]).err().unwrap().contains("generic, wwise"));
+}
+
+#[test]
+fn leaves_unresolved_ids_untouched() {
+    let annotator = load_annotator("wwise", &[]).unwrap();
+    let log = "Event ID not found: 999999\r\nMedia 100060 failed at 12:00 (retry 3)\nBank 7 loaded";
+    let annotated = annotator.annotate(log);
+    assert_eq!(
+        annotated.text,
+        "Event ID not found: 999999\r\nMedia 100060 [wid: Play_Pistol (Event)] failed at 12:00 (retry 3)\nBank 7 loaded"
+    );
+    assert_eq!(
+        (annotated.stats.lines, annotated.stats.annotated_lines, annotated.stats.annotations),
+        (3, 1, 1)
+    );
+}
+
+#[test]
+fn cli_annotates_standard_input() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wid-cli"))
+        .args(["--project", &sample_project(), "annotate", "--pattern", r"id=(?P<id>-?\d+)"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("启动 wid-cli 失败");
+    child.stdin.take().unwrap().write_all(b"post id=100060\npost id=42\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "post id=100060 [wid: Play_Pistol (Event)]\npost id=42\n");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("已处理 2 行，注释 1 行，共 1 处"));
+}
@@ -0,0 +1,84 @@
+//! 媒体重复和内存报告测试，使用 `tests/fixtures/media_report/` 和 `tests/fixtures/per_bank/`
+
+mod common;
+
+use common::fixture_dir;
+use wid_lib::modules::get_media_report;
+use wid_lib::modules::media_report::{parse_size, BankMemory, PlatformMediaReport};
+
+fn bank<'a>(report: &'a PlatformMediaReport, name: &str) -> &'a BankMemory {
+    report.banks.iter().find(|b| b.name == name).unwrap()
+}
+
+#[test]
+fn reports_duplicates_and_wasted_bytes_per_platform() {
+    let report = get_media_report(fixture_dir("media_report/GeneratedSoundBanks"), None).unwrap();
+    let platforms: Vec<&str> = report.platforms.iter().map(|p| p.platform.as_str()).collect();
+    assert_eq!(platforms, ["Switch", "Windows"]);
+
+    // 大小取自 .bnk 的 DIDX；Loose 流播放的 300003 只有 Weapons 引用，且不占内存
+    let windows = &report.platforms[1];
+    let duplicates: Vec<(&str, u64)> = windows.duplicates.iter().map(|d| (d.id.as_str(), d.wasted_bytes)).collect();
+    assert_eq!(duplicates, [("300001", 4000), ("300002", 1024)]);
+    assert_eq!(windows.duplicates[0].banks, ["Weapons", "Common"]);
+    assert_eq!(windows.duplicates[0].total_bytes, 8000);
+    assert_eq!(windows.wasted_bytes, 5024);
+
+    // 旧版 XML：没有 .bnk 时使用 .wem 文件大小
+    let switch = &report.platforms[0];
+    assert_eq!(switch.duplicates.len(), 1);
+    assert_eq!((switch.duplicates[0].id.as_str(), switch.duplicates[0].wasted_bytes), ("300001", 300));
+    assert_eq!(bank(switch, "Weapons").prefetch_bytes, 512);
+}
+
+#[test]
+fn sums_bank_memory_and_flags_budget() {
+    let report = get_media_report(fixture_dir("media_report/GeneratedSoundBanks/Windows"), Some(5000)).unwrap();
+    assert_eq!(report.platforms.len(), 1);
+    let windows = &report.platforms[0];
+    assert_eq!(windows.platform, "Windows");
+
+    let weapons = bank(windows, "Weapons");
+    assert_eq!((weapons.memory_bytes, weapons.prefetch_bytes), (5024, 1024));
+    assert_eq!((weapons.media_count, weapons.streamed_count), (3, 1));
+    assert!(weapons.over_budget);
+    assert!(bank(windows, "Common").over_budget);
+    assert_eq!(bank(windows, "Common").memory_bytes, 6500);
+
+    // UI.bnk 不存在：预取大小来自 PrefetchSize，内存媒体来自 .wem
+    let ui = bank(windows, "UI");
+    assert_eq!(ui.memory_bytes, 1624);
+    assert!(!ui.over_budget);
+
+    let vo = bank(windows, "VO");
+    assert_eq!((vo.language.as_str(), vo.memory_bytes, vo.unknown_sizes), ("English(US)", 0, 1));
+}
+
+#[test]
+fn reads_per_bank_metadata_in_language_subdirectories() {
+    let report = get_media_report(fixture_dir("per_bank/GeneratedSoundBanks/Windows"), Some(5000)).unwrap();
+    let windows = &report.platforms[0];
+    assert_eq!(windows.platform, "Windows");
+
+    // English(US)/VO.json 中的 .bnk 和 .wem 路径相对平台目录，而不是元数据文件所在的语言子目录
+    let vo = bank(windows, "VO");
+    assert_eq!((vo.language.as_str(), vo.path.as_str()), ("English(US)", "English(US)/VO.bnk"));
+    assert_eq!((vo.memory_bytes, vo.unknown_sizes), (5700, 0));
+    assert!(vo.over_budget);
+
+    let main = bank(windows, "Main");
+    assert_eq!((main.memory_bytes, main.streamed_count, main.unknown_sizes), (3000, 1, 0));
+
+    let duplicates: Vec<(&str, u64)> = windows.duplicates.iter().map(|d| (d.id.as_str(), d.wasted_bytes)).collect();
+    assert_eq!(duplicates, [("500001", 2000)]);
+    assert_eq!(windows.duplicates[0].banks, ["VO", "Main"]);
+}
+
+#[test]
+fn parses_budget_sizes() {
+    assert_eq!(parse_size("8MB"), Ok(8 * 1024 * 1024));
+    assert_eq!(parse_size("512 kb"), Ok(512 * 1024));
+    assert_eq!(parse_size("1.5MiB"), Ok(1572864));
+    assert_eq!(parse_size("1000"), Ok(1000));
+    assert!(parse_size("8 parsecs").is_err());
+}
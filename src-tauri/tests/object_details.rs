@@ -0,0 +1,154 @@
+//! 对象详情测试
+//!
+//! `tests/fixtures/sample_project/` 为包含属性、引用、RTPC、State 和多语言源的精简工程。
+
+mod common;
+
+use common::{sample_project, PISTOL_GUID, UNKNOWN_GUID, WEAPONS_GUID};
+use wid_lib::modules::get_object_details;
+use wid_lib::modules::inheritance::InheritanceRule;
+use wid_lib::modules::object_details::ObjectDetails;
+
+fn details(guid: &str) -> ObjectDetails {
+    get_object_details(sample_project(), guid.to_string()).expect("读取对象详情失败")
+}
+
+#[test]
+fn reads_properties_with_platform_values() {
+    let pistol = details(PISTOL_GUID);
+    assert_eq!(pistol.object.name, "Pistol");
+    assert!(pistol.work_unit_file.ends_with("Default Work Unit.wwu"));
+
+    let names: Vec<&str> = pistol.properties.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["IsVoice", "OverrideEffect", "Pitch", "Volume"]);
+
+    let volume = pistol.properties.iter().find(|p| p.name == "Volume").unwrap();
+    assert_eq!(volume.value_type, "Real64");
+    assert_eq!(volume.value, "-6");
+    assert_eq!(volume.platform_values.len(), 1);
+    assert_eq!(volume.platform_values[0].platform, "Windows");
+    assert_eq!(volume.platform_values[0].value, "-4.5");
+}
+
+#[test]
+fn resolves_references_to_project_objects() {
+    let pistol = details(PISTOL_GUID);
+    let reference = |name: &str| pistol.references.iter().find(|r| r.name == name).unwrap();
+
+    let bus = reference("OutputBus");
+    assert!(bus.resolved);
+    assert_eq!(bus.target_type, "Bus");
+    assert_eq!(bus.target_path, "\\Master-Mixer Hierarchy\\Default Work Unit\\Master Audio Bus");
+
+    let effect = reference("Effect0");
+    assert!(effect.embedded);
+    assert_eq!(effect.target_name, "Pistol_Delay");
+    assert_eq!(effect.target_type, "Effect");
+
+    // 引用的对象不在工程中时保留 ObjectRef 中的名称
+    let attenuation = reference("Attenuation");
+    assert!(!attenuation.resolved);
+    assert_eq!(attenuation.target_name, "Missing_Attenuation");
+}
+
+#[test]
+fn reads_rtpcs_and_state_properties() {
+    let pistol = details(PISTOL_GUID);
+
+    assert_eq!(pistol.rtpcs.len(), 1);
+    let rtpc = &pistol.rtpcs[0];
+    assert_eq!(rtpc.property, "Volume");
+    let input = rtpc.control_input.as_ref().unwrap();
+    assert_eq!(input.target_name, "Distance");
+    assert_eq!(input.target_type, "GameParameter");
+    let points: Vec<(f64, f64)> = rtpc.points.iter().map(|p| (p.x, p.y)).collect();
+    assert_eq!(points, [(0.0, 0.0), (100.0, -24.0)]);
+
+    assert_eq!(pistol.state_groups.len(), 1);
+    assert_eq!(pistol.state_groups[0].target_name, "Health");
+    assert_eq!(pistol.state_properties.len(), 1);
+    let state = &pistol.state_properties[0];
+    assert_eq!((state.state_group.as_str(), state.state.as_str()), ("Health", "Low"));
+    assert_eq!((state.property.as_str(), state.value.as_str()), ("Volume", "-12"));
+}
+
+#[test]
+fn lists_children_notes_and_language_sources() {
+    let weapons = details(WEAPONS_GUID);
+    assert_eq!(weapons.children.len(), 1);
+    assert_eq!(weapons.children[0].name, "Pistol");
+    assert!(weapons.sources.is_empty());
+
+    let pistol = details(PISTOL_GUID);
+    assert!(pistol.children.is_empty());
+    assert_eq!(pistol.notes, "Dry gunshot, distance handled by RTPC");
+
+    let sources: Vec<(&str, &str, &str, bool)> = pistol
+        .sources
+        .iter()
+        .map(|s| (s.language.as_str(), s.audio_file.as_str(), s.media_id.as_str(), s.active))
+        .collect();
+    assert_eq!(
+        sources,
+        [
+            ("English(US)", "Pistol_EN.wav", "200012", true),
+            ("French(France)", "Pistol_FR.wav", "200013", false),
+        ]
+    );
+}
+
+#[test]
+fn accepts_guids_without_braces_and_rejects_unknown_ones() {
+    let pistol = details("5a000000-0000-4000-8000-000000000011");
+    assert_eq!(pistol.object.guid, PISTOL_GUID);
+
+    let missing = get_object_details(sample_project(), UNKNOWN_GUID.to_string());
+    assert!(missing.unwrap_err().contains("未找到"));
+    assert!(get_object_details(sample_project(), "Pistol".to_string()).is_err());
+}
+
+#[test]
+fn sums_additive_properties_across_the_hierarchy() {
+    let pistol = details(PISTOL_GUID);
+    let volume = pistol.effective_values.iter().find(|v| v.name == "Volume").unwrap();
+    assert_eq!(volume.rule, InheritanceRule::Additive);
+    assert_eq!(volume.value, "-9");
+    assert!(volume.inherited);
+    let contributions: Vec<(&str, f64)> =
+        volume.contributions.iter().map(|c| (c.source.name.as_str(), c.value)).collect();
+    assert_eq!(contributions, [("Pistol", -6.0), ("Weapons", -3.0)]);
+}
+
+#[test]
+fn inherits_settings_unless_the_override_flag_is_set() {
+    let pistol = details(PISTOL_GUID);
+    let effective = |name: &str| pistol.effective_values.iter().find(|v| v.name == name).unwrap();
+
+    // Pistol 设置了 OutputBus 但没有打开 OverrideOutput，使用 Weapons 的设置
+    let bus = effective("OutputBus");
+    assert_eq!(bus.rule, InheritanceRule::Override);
+    assert_eq!(bus.override_flag, "OverrideOutput");
+    assert_eq!(bus.value, "SFX");
+    assert_eq!(bus.source.name, "Weapons");
+    assert!(bus.inherited && !bus.is_default);
+
+    // 顶层的 Weapons 没有设置 Attenuation，使用默认值
+    let attenuation = effective("Attenuation");
+    assert_eq!(attenuation.source.name, "Weapons");
+    assert!(attenuation.is_default);
+    assert_eq!(attenuation.value, "");
+
+    let effect = effective("Effect0");
+    assert_eq!(effect.value, "Pistol_Delay");
+    assert!(!effect.inherited);
+
+    let pitch = effective("Pitch");
+    assert_eq!((pitch.rule, pitch.value.as_str()), (InheritanceRule::Additive, "-200"));
+    // 不继承的属性只取对象自己的值
+    assert_eq!(effective("IsVoice").rule, InheritanceRule::Own);
+
+    let weapons = details(WEAPONS_GUID);
+    let bus = weapons.effective_values.iter().find(|v| v.name == "OutputBus").unwrap();
+    assert_eq!(bus.value, "SFX");
+    assert!(!bus.inherited);
+}
@@ -0,0 +1,46 @@
+//! 工程文件搜索测试，使用 `tests/fixtures/multi_media/` 中有多个 MediaID 的源
+
+mod common;
+
+use common::fixture_dir;
+use wid_lib::modules::search_wwise_project;
+use wid_lib::modules::types::SearchResult;
+
+const SOURCE_GUID: &str = "{6B000000-0000-4000-8000-000000000011}";
+
+fn search(id: &str, types: &[&str]) -> Vec<SearchResult> {
+    let types = types.iter().map(|t| t.to_string()).collect();
+    search_wwise_project(fixture_dir("multi_media/Project"), id.to_string(), types, None).unwrap().results
+}
+
+#[test]
+fn finds_sources_by_every_media_id() {
+    for media_id in ["300101", "300102", "300103"] {
+        let results = search(media_id, &["MediaID"]);
+        assert_eq!(results.len(), 1, "{}", media_id);
+        assert_eq!((results[0].name.as_str(), results[0].object_type.as_str()), ("Dialogue", "AudioFileSource"));
+        // 结果显示命中的 MediaID
+        assert_eq!(results[0].media_id, media_id);
+    }
+
+    let structured = search("type:AudioFileSource mediaid:>300102", &["MediaID"]);
+    assert_eq!(structured.len(), 1);
+    assert!(search("300104", &["MediaID"]).is_empty());
+}
+
+#[test]
+fn returns_multi_media_sources_once() {
+    let results = search(SOURCE_GUID, &["GUID", "ShortID", "MediaID"]);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].media_id, "300101");
+
+    let prefix = search_wwise_project(
+        fixture_dir("multi_media/Project"),
+        "3001".to_string(),
+        vec!["MediaID".to_string()],
+        Some("prefix".to_string()),
+    )
+    .unwrap();
+    let names: Vec<&str> = prefix.results.iter().map(|r| r.object_type.as_str()).collect();
+    assert_eq!(names, ["AudioFileSource"]);
+}
@@ -0,0 +1,141 @@
+//! 结构化查询测试：语法解析、错误位置、数字比较，以及 `bank:` / `wu:` 在工程和 Bank 索引中的行为
+
+mod common;
+
+use common::{bank_project, bank_project_banks};
+use wid_lib::modules::index::IndexedObject;
+use wid_lib::modules::query::{is_structured_query, search_query, Comparison, Field, Query};
+use wid_lib::modules::{search_bank_directory, search_wwise_project};
+
+fn object(name: &str, short_id: &str) -> IndexedObject {
+    IndexedObject {
+        name: name.to_string(),
+        object_type: "Event".to_string(),
+        guid: String::new(),
+        short_id: short_id.to_string(),
+        media_ids: Vec::new(),
+        path: format!("\\Events\\Default Work Unit\\{}", name),
+        parent: "Default Work Unit".to_string(),
+        work_unit: "Default Work Unit".to_string(),
+        banks: Vec::new(),
+    }
+}
+
+fn matching(query: &str, objects: &[IndexedObject]) -> Vec<String> {
+    search_query(objects, query).unwrap().results.into_iter().map(|r| r.name).collect()
+}
+
+fn all_types() -> Vec<String> {
+    vec!["GUID".to_string(), "ShortID".to_string(), "MediaID".to_string()]
+}
+
+#[test]
+fn parses_fields_negation_quotes_and_comparisons() {
+    let query = Query::parse(r#"type:Event  -name:"Play \"Loud\" *" shortid:>=1000 WU:Characters"#).unwrap();
+    let fields: Vec<(Field, bool, Comparison, &str)> =
+        query.conditions.iter().map(|c| (c.field, c.negated, c.comparison, c.value.as_str())).collect();
+    assert_eq!(
+        fields,
+        [
+            (Field::Type, false, Comparison::Eq, "Event"),
+            (Field::Name, true, Comparison::Eq, "Play \"Loud\" *"),
+            (Field::ShortId, false, Comparison::Ge, "1000"),
+            (Field::WorkUnit, false, Comparison::Eq, "Characters"),
+        ]
+    );
+    let positions: Vec<usize> = query.conditions.iter().map(|c| c.position).collect();
+    assert_eq!(positions, [0, 13, 36, 51]);
+    assert!(query.uses_field(Field::WorkUnit) && !query.uses_field(Field::Bank));
+}
+
+#[test]
+fn points_the_caret_at_the_error() {
+    let error = Query::parse("type:Event colour:red").unwrap_err();
+    assert_eq!(error.position, 11);
+    let text = error.to_string();
+    assert!(text.starts_with("查询语法错误（第 12 个字符）: 未知字段 colour"));
+    assert!(text.ends_with("type:Event colour:red\n           ^"));
+
+    // 中文和全角字符占两列，`^` 仍对准出错的字段
+    let error = Query::parse("name:脚步声 colour:red").unwrap_err();
+    assert_eq!(error.position, 9);
+    assert!(error.to_string().ends_with("name:脚步声 colour:red\n            ^"));
+    let error = Query::parse("name:ＡＢ\tcolour:red").unwrap_err();
+    assert!(error.to_string().ends_with("name:ＡＢ\tcolour:red\n         \t^"));
+
+    assert_eq!(Query::parse(r#"name:"Play"#).unwrap_err().position, 5);
+    assert_eq!(Query::parse("name:>Play").unwrap_err().position, 5);
+    assert_eq!(Query::parse("shortid:>12*").unwrap_err().position, 9);
+    assert_eq!(Query::parse("shortid:abc").unwrap_err().position, 8);
+    assert_eq!(Query::parse("type:").unwrap_err().position, 5);
+    assert_eq!(Query::parse("   ").unwrap_err().position, 0);
+}
+
+#[test]
+fn compares_numeric_ids() {
+    let objects = [object("Small", "999"), object("Exact", "1000"), object("Large", "4294967295")];
+    assert_eq!(matching("shortid:>1000", &objects), ["Large"]);
+    assert_eq!(matching("shortid:>=1000", &objects), ["Exact", "Large"]);
+    assert_eq!(matching("shortid:<1000", &objects), ["Small"]);
+    assert_eq!(matching("shortid:<=0x3E8", &objects), ["Small", "Exact"]);
+    assert_eq!(matching("shortid:=1000", &objects), ["Exact"]);
+    assert_eq!(matching("shortid:10*", &objects), ["Exact"]);
+    // 有符号 int32 按补码比较
+    assert_eq!(matching("shortid:-1", &objects), ["Large"]);
+    assert_eq!(matching("-shortid:<1000", &objects), ["Exact", "Large"]);
+}
+
+#[test]
+fn distinguishes_negation_from_negative_ids() {
+    let negative = Query::parse("-123").unwrap();
+    assert_eq!((negative.conditions[0].field, negative.conditions[0].negated), (Field::Any, false));
+    assert_eq!(negative.conditions[0].value, "-123");
+
+    let negated = Query::parse("-Play*").unwrap();
+    assert!(negated.conditions[0].negated);
+    assert_eq!(negated.conditions[0].value, "Play*");
+
+    let objects = [object("Large", "4294967173"), object("Other", "5")];
+    assert_eq!(matching("type:Event -123", &objects), ["Large"]);
+    assert_eq!(matching("type:Event -Large", &objects), ["Other"]);
+}
+
+#[test]
+fn detects_structured_queries() {
+    assert!(is_structured_query("type:Event"));
+    assert!(is_structured_query("Play_Pistol -bank:UI"));
+    assert!(is_structured_query("  name:\"Play Pistol\""));
+    assert!(!is_structured_query("123456"));
+    assert!(!is_structured_query("-123456"));
+    assert!(!is_structured_query("{5A000000-0000-4000-8000-000000000060}"));
+    assert!(!is_structured_query("1:2"));
+}
+
+#[test]
+fn combines_bank_and_work_unit_filters_in_project_search() {
+    // SoundBank 归属来自 .wproj 中的 Bank 目录
+    let query = "type:Event name:Play_* bank:Weapons shortid:>100000 wu:Default*";
+    let response = search_wwise_project(bank_project(), query.to_string(), all_types(), None).unwrap();
+    let names: Vec<&str> = response.results.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["Play_Pistol"]);
+    let banks: Vec<&str> = response.results[0].banks.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(banks, ["Weapons", "Common"]);
+
+    let response = search_wwise_project(bank_project(), "type:Event -bank:Common".to_string(), all_types(), None);
+    let names: Vec<String> = response.unwrap().results.into_iter().map(|r| r.name).collect();
+    assert_eq!(names, ["Stop_Pistol"]);
+}
+
+#[test]
+fn rejects_fields_missing_from_the_index() {
+    let error = search_bank_directory(bank_project_banks(), "type:Event wu:Default*".to_string(), all_types(), None);
+    let error = error.unwrap_err();
+    assert!(error.contains("当前索引中没有字段 wu"));
+    assert!(error.ends_with("type:Event wu:Default*\n           ^"));
+
+    // 工程对象没有 SoundBank 时同样报错，而不是返回空结果
+    let objects = [object("Play_Pistol", "100060")];
+    let error = search_query(&objects, "type:Event -bank:UI").unwrap_err();
+    assert!(error.contains("当前索引中没有字段 bank"));
+    assert!(error.ends_with("type:Event -bank:UI\n            ^"));
+}
@@ -0,0 +1,111 @@
+//! 总线路由图测试，使用 `tests/fixtures/sample_project/` 和 `tests/fixtures/routing_2023/`
+
+mod common;
+
+use common::{fixture_dir, sample_project, ENGINE_GUID, MASTER_GUID, PISTOL_GUID, REVERB_GUID, SFX_GUID, WEAPONS_GUID};
+use wid_lib::modules::get_bus_routing;
+use wid_lib::modules::graph::{Graph, GraphFormat};
+use wid_lib::modules::routing::{EDGE_GAME_AUX_SEND, EDGE_OUTPUT_BUS, EDGE_PARENT_BUS, EDGE_USER_AUX_SEND, GAME_AUX_NODE_ID};
+
+fn routing(through: Option<&str>) -> Graph {
+    get_bus_routing(sample_project(), through.map(str::to_string)).expect("读取总线路由失败")
+}
+
+/// 从指定对象出发的边：(类型, 目标, 说明)
+fn edges_from<'a>(graph: &'a Graph, source: &str) -> Vec<(&'a str, &'a str, &'a str)> {
+    graph
+        .edges
+        .iter()
+        .filter(|e| e.source == source)
+        .map(|e| (e.kind.as_str(), e.target.as_str(), e.label.as_str()))
+        .collect()
+}
+
+#[test]
+fn resolves_effective_output_busses() {
+    let graph = routing(None);
+
+    let output = |guid: &str| edges_from(&graph, guid).into_iter().find(|e| e.0 == EDGE_OUTPUT_BUS).unwrap();
+    assert_eq!(output(WEAPONS_GUID), (EDGE_OUTPUT_BUS, SFX_GUID, "OutputBus"));
+    // Pistol 没有打开 OverrideOutput，自己设置的 Master Audio Bus 不生效
+    assert_eq!(output(PISTOL_GUID), (EDGE_OUTPUT_BUS, SFX_GUID, "OutputBus（继承自 Weapons）"));
+    // 嵌套工作单元中的顶层对象没有设置 OutputBus，输出到主总线
+    assert_eq!(output(ENGINE_GUID), (EDGE_OUTPUT_BUS, MASTER_GUID, "OutputBus（默认）"));
+
+    assert_eq!(edges_from(&graph, SFX_GUID), [(EDGE_PARENT_BUS, MASTER_GUID, "Parent")]);
+    assert_eq!(edges_from(&graph, REVERB_GUID), [(EDGE_PARENT_BUS, MASTER_GUID, "Parent")]);
+    assert!(edges_from(&graph, MASTER_GUID).is_empty());
+
+    let reverb = graph.node(REVERB_GUID).unwrap();
+    assert_eq!((reverb.name.as_str(), reverb.object_type.as_str()), ("Reverb", "AuxBus"));
+    assert_eq!(reverb.path, "\\Master-Mixer Hierarchy\\Default Work Unit\\Master Audio Bus\\Reverb");
+}
+
+#[test]
+fn routes_to_the_main_audio_bus_of_newer_projects() {
+    // 2022.1 起主总线名为 Main Audio Bus；排在它前面的 Motion Factory Bus 也是顶层总线
+    let graph = get_bus_routing(fixture_dir("routing_2023/Project"), None).unwrap();
+    let main_bus = "{5D000000-0000-4000-8000-000000000020}";
+
+    assert_eq!(
+        edges_from(&graph, "{5D000000-0000-4000-8000-000000000001}"),
+        [(EDGE_OUTPUT_BUS, main_bus, "OutputBus（默认）")]
+    );
+    assert_eq!(
+        edges_from(&graph, "{5D000000-0000-4000-8000-000000000021}"),
+        [(EDGE_PARENT_BUS, main_bus, "Parent")]
+    );
+}
+
+#[test]
+fn inherits_user_and_game_defined_aux_sends() {
+    let graph = routing(None);
+
+    let sends: Vec<_> = edges_from(&graph, PISTOL_GUID).into_iter().filter(|e| e.0 != EDGE_OUTPUT_BUS).collect();
+    assert_eq!(
+        sends,
+        [
+            (EDGE_GAME_AUX_SEND, GAME_AUX_NODE_ID, "UseGameAuxSends（继承自 Weapons）"),
+            (EDGE_USER_AUX_SEND, REVERB_GUID, "UserAuxSend0（继承自 Weapons）"),
+        ]
+    );
+    assert_eq!(graph.node(GAME_AUX_NODE_ID).unwrap().object_type, "GameDefinedAuxSends");
+    assert_eq!(edges_from(&graph, ENGINE_GUID).len(), 1);
+}
+
+#[test]
+fn queries_everything_routed_through_a_bus() {
+    let through_reverb = routing(Some("Reverb"));
+    let mut ids: Vec<&str> = through_reverb.nodes.iter().map(|n| n.id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, [WEAPONS_GUID, PISTOL_GUID, REVERB_GUID]);
+    assert!(through_reverb.edges.iter().all(|e| e.target == REVERB_GUID));
+
+    // 按 ShortID 查询；所有对象最终都经过主总线
+    let through_master = routing(Some("100020"));
+    assert_eq!(through_master.nodes.len(), 6);
+    assert!(through_master.node(GAME_AUX_NODE_ID).is_none());
+
+    let missing = get_bus_routing(sample_project(), Some("Pistol".to_string()));
+    assert!(missing.unwrap_err().contains("未找到总线"));
+}
+
+#[test]
+fn exports_dot_and_mermaid() {
+    let graph = routing(Some(SFX_GUID));
+
+    let dot = graph.render(GraphFormat::Dot, "routing").unwrap();
+    assert!(dot.starts_with("digraph \"routing\" {"));
+    assert!(dot.contains(&format!("\"{}\" [label=\"SFX\\nBus\"];", SFX_GUID)));
+    assert!(dot.contains(&format!("\"{}\" -> \"{}\" [label=\"OutputBus（继承自 Weapons）\"];", PISTOL_GUID, SFX_GUID)));
+
+    let mermaid = graph.render(GraphFormat::Mermaid, "routing").unwrap();
+    assert!(mermaid.starts_with("flowchart LR\n"));
+    assert!(mermaid.contains("[\"SFX<br/>Bus\"]"));
+    assert!(mermaid.contains("-->|\"OutputBus\"|"));
+
+    let json: serde_json::Value = serde_json::from_str(&graph.render(GraphFormat::Json, "routing").unwrap()).unwrap();
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 3);
+    assert_eq!(GraphFormat::parse("gv").unwrap(), GraphFormat::Dot);
+    assert!(GraphFormat::parse("png").is_err());
+}
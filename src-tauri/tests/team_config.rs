@@ -0,0 +1,82 @@
+//! 团队配置 `.wid.toml` 测试，使用 `tests/fixtures/team_config/` 和 `tests/fixtures/wproj/`
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use common::{fixture_dir, fixture_path};
+use wid_lib::modules::team_config::{load_team_config, LintLevel};
+use wid_lib::modules::validators::validate_wwise_directory;
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+#[test]
+fn loads_the_team_config_next_to_the_wproj() {
+    let project = fixture_path("team_config/Project");
+    let team = load_team_config(&project).unwrap().unwrap();
+
+    assert_eq!(team.path, path_string(&project.join(".wid.toml")));
+    assert_eq!(team.config.platforms, ["Android", "Windows"]);
+    assert_eq!(team.config.ignore, ["Backup/**"]);
+    let waapi = team.config.waapi.as_ref().unwrap();
+    assert_eq!((waapi.host.as_str(), waapi.port), ("192.168.1.20", 8090));
+
+    // `warn` 是 `warning` 的别名
+    let lint: Vec<(&str, LintLevel)> = team.config.lint.iter().map(|(rule, level)| (rule.as_str(), *level)).collect();
+    assert_eq!(
+        lint,
+        [
+            ("empty-container", LintLevel::Warning),
+            ("missing-media", LintLevel::Error),
+            ("unused-event", LintLevel::Warning),
+        ]
+    );
+
+    assert!(load_team_config(&fixture_path("wproj/Project")).unwrap().is_none());
+}
+
+#[test]
+fn resolves_relative_paths_against_the_project_directory() {
+    let team = load_team_config(&fixture_path("team_config/Project")).unwrap().unwrap();
+    let output_root = fixture_path("team_config/GeneratedSoundBanks");
+
+    assert_eq!(team.config.bank_output_root, Some(path_string(&output_root)));
+    assert_eq!(team.config.ids_header, Some(path_string(&output_root.join("Wwise_IDs.h"))));
+    assert_eq!(team.bank_directory, Some(path_string(&output_root.join("Android"))));
+    assert_eq!(team.config.bank_directory(Some("Windows")), Some(output_root.join("Windows")));
+}
+
+#[test]
+fn validate_wwise_directory_selects_the_first_platform() {
+    let info = validate_wwise_directory(fixture_dir("team_config/Project")).unwrap();
+
+    assert_eq!(info.project.name, "Team");
+    assert!(info.team_config.is_some());
+    assert_eq!(
+        info.bank_directory,
+        Some(path_string(&fixture_path("team_config/GeneratedSoundBanks/Android")))
+    );
+}
+
+#[test]
+fn falls_back_to_wproj_platform_directories() {
+    let dir = std::env::temp_dir().join(format!("wid-team-config-{}", std::process::id()));
+    let project = dir.join("Project");
+    fs::create_dir_all(&project).unwrap();
+    fs::copy(fixture_path("wproj/Project/Settings.wproj"), project.join("Settings.wproj")).unwrap();
+
+    // 只指定平台时使用 .wproj 中该平台的输出目录
+    fs::write(project.join(".wid.toml"), "platforms = [\"Android\"]\n").unwrap();
+    let platform_only = validate_wwise_directory(path_string(&project));
+    fs::write(project.join(".wid.toml"), "platforms = \"Android\"\n").unwrap();
+    let invalid = validate_wwise_directory(path_string(&project));
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+        platform_only.unwrap().bank_directory,
+        Some(path_string(&dir.join("Banks/Mobile")))
+    );
+    assert!(invalid.unwrap_err().contains(".wid.toml"));
+}
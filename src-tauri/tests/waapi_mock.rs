@@ -0,0 +1,483 @@
+//! WAAPI 客户端测试
+//!
+//! 在本地启动一个简化的 WAMP 服务器，按 WAQL 在内存中的对象列表里查询，
+//! 验证会话复用、断线重连、按 GUID 去重、错误和超时的处理，实时同步的订阅和叠加修改，
+//! 以及在 Wwise 中显示对象。
+
+use std::collections::HashMap;
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use regex::Regex;
+use serde_json::{json, Value};
+use tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tungstenite::Message;
+use wid_lib::modules::matcher::MatchMode;
+use wid_lib::modules::waapi::{search_with_client, search_waapi, show_with_client, ShowAction};
+use wid_lib::modules::index::IndexedObject;
+use wid_lib::modules::waapi_client::{WaapiClient, WaapiError};
+use wid_lib::modules::waapi_live::{parse_event, subscribe_live_topics, LiveChange, LiveOverlay, LIVE_TOPICS};
+
+const SOUND_GUID: &str = "{3F2504E0-4F89-11D3-9A0C-583224111ABC}";
+const SOURCE_GUID: &str = "{6B29FC40-CA47-1067-B31D-00DD010662DA}";
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 模拟 Wwise 中的对象
+fn objects() -> Vec<Value> {
+    vec![
+        json!({
+            "id": SOUND_GUID,
+            "name": "Footstep",
+            "type": "Sound",
+            "shortId": 583224111u64,
+            "path": "\\Actor-Mixer Hierarchy\\Default Work Unit\\Footstep",
+        }),
+        json!({
+            "id": SOURCE_GUID,
+            "name": "Footstep",
+            "type": "AudioFileSource",
+            "shortId": 1020304u64,
+            "mediaId": 403870132u64,
+            "path": "\\Actor-Mixer Hierarchy\\Default Work Unit\\Footstep\\Footstep",
+        }),
+    ]
+}
+
+#[derive(Default)]
+struct MockState {
+    connections: AtomicUsize,
+    /// 收到的 WAQL
+    queries: Mutex<Vec<String>>,
+    /// 回复下一次调用后断开连接
+    drop_after_call: AtomicBool,
+    /// 收到的订阅请求数
+    subscriptions: AtomicUsize,
+    /// 执行的 UI 命令和对象
+    commands: Mutex<Vec<(String, Value)>>,
+    /// 模拟 2021.1 以后的 Wwise：不支持 `SyncGroup` 命令名称
+    selection_channels: AtomicBool,
+}
+
+struct MockServer {
+    port: u16,
+    state: Arc<MockState>,
+}
+
+impl MockServer {
+    fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let state = Arc::new(MockState::default());
+        let server_state = Arc::clone(&state);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                server_state.connections.fetch_add(1, Ordering::SeqCst);
+                let state = Arc::clone(&server_state);
+                thread::spawn(move || serve(stream, &state));
+            }
+        });
+        MockServer { port, state }
+    }
+
+    fn client(&self) -> WaapiClient {
+        WaapiClient::new("127.0.0.1", self.port)
+    }
+
+    fn connections(&self) -> usize {
+        self.state.connections.load(Ordering::SeqCst)
+    }
+}
+
+/// 握手时确认 WAMP 子协议，签名由 tungstenite 决定
+#[allow(clippy::result_large_err)]
+fn accept_wamp(_: &Request, mut response: Response) -> Result<Response, ErrorResponse> {
+    response
+        .headers_mut()
+        .insert("Sec-WebSocket-Protocol", "wamp.2.json".parse().unwrap());
+    Ok(response)
+}
+
+fn serve(stream: TcpStream, state: &MockState) {
+    let Ok(mut socket) = tungstenite::accept_hdr(stream, accept_wamp) else {
+        return;
+    };
+    // 本连接的订阅：主题 → 订阅 ID
+    let mut topics: HashMap<String, u64> = HashMap::new();
+
+    loop {
+        let text = match socket.read() {
+            Ok(Message::Text(text)) => text,
+            Ok(Message::Close(_)) | Err(_) => return,
+            Ok(_) => continue,
+        };
+        let message: Vec<Value> = serde_json::from_str(text.as_str()).unwrap();
+        let reply = match message[0].as_u64().unwrap() {
+            1 => json!([2, 7, { "roles": { "dealer": {}, "broker": {} } }]),
+            6 => return,
+            32 => {
+                state.subscriptions.fetch_add(1, Ordering::SeqCst);
+                let subscription_id = 100 + topics.len() as u64;
+                topics.insert(message[3].as_str().unwrap().to_string(), subscription_id);
+                json!([33, message[1], subscription_id])
+            }
+            48 => {
+                let request_id = &message[1];
+                match message[3].as_str().unwrap() {
+                    "ak.wwise.core.getInfo" => {
+                        json!([50, request_id, {}, [], { "version": { "displayName": "v2023.1.0" } }])
+                    }
+                    "ak.wwise.core.object.get" => {
+                        let waql = message[5]["waql"].as_str().unwrap().to_string();
+                        state.queries.lock().unwrap().push(waql.clone());
+                        match run_waql(&waql) {
+                            Some(found) => json!([50, request_id, {}, [], { "return": found }]),
+                            None => json!([8, 48, request_id, {}, "ak.wwise.query.invalid_waql", [],
+                                { "message": format!("无法解析 WAQL: {}", waql) }]),
+                        }
+                    }
+                    "ak.wwise.ui.commands.execute" => {
+                        let command = message[5]["command"].as_str().unwrap().to_string();
+                        let supported = !(command.contains("SyncGroup")
+                            && state.selection_channels.load(Ordering::SeqCst));
+                        state.commands.lock().unwrap().push((command.clone(), message[5]["objects"].clone()));
+                        if supported {
+                            json!([50, request_id, {}, [], {}])
+                        } else {
+                            json!([8, 48, request_id, {}, "ak.wwise.invalid_arguments", [],
+                                { "message": format!("Unknown command: {}", command) }])
+                        }
+                    }
+                    "ak.wwise.ui.bringToForeground" => json!([50, request_id, {}, [], {}]),
+                    // 不回复，用于测试超时
+                    "test.slow" => continue,
+                    // 回复后向订阅了该主题的本连接发布事件
+                    "test.publish" => {
+                        let kwargs = &message[5];
+                        let reply = json!([50, request_id, {}, [], {}]);
+                        if socket.send(Message::text(reply.to_string())).is_err() {
+                            return;
+                        }
+                        match topics.get(kwargs["topic"].as_str().unwrap()) {
+                            Some(subscription_id) => json!([36, subscription_id, 1, {}, [], kwargs["event"]]),
+                            None => continue,
+                        }
+                    }
+                    _ => json!([8, 48, request_id, {}, "ak.wwise.invalid_procedure", [],
+                        { "message": "The procedure URI is unknown." }]),
+                }
+            }
+            _ => continue,
+        };
+        if socket.send(Message::text(reply.to_string())).is_err() {
+            return;
+        }
+        if message[0] == 48 && state.drop_after_call.swap(false, Ordering::SeqCst) {
+            // 不发送关闭帧直接断开，模拟 Wwise 被关闭
+            return;
+        }
+    }
+}
+
+/// 支持客户端生成的几种 WAQL
+fn run_waql(waql: &str) -> Option<Vec<Value>> {
+    let objects = objects();
+    let number_field = |field: &str| -> Option<Vec<Value>> {
+        let value: u64 = waql.strip_prefix(&format!("$ where {} = ", field))?.parse().ok()?;
+        Some(objects.iter().filter(|o| o[field] == value).cloned().collect())
+    };
+    if let Some(found) = number_field("shortId").or_else(|| number_field("mediaId")) {
+        return Some(found);
+    }
+    if let Some(pattern) = waql.strip_prefix("$ where id : \"").and_then(|s| s.strip_suffix('"')) {
+        let regex = Regex::new(&pattern.replace("\\\\", "\\").replace("\\\"", "\"")).ok()?;
+        return Some(objects.iter().filter(|o| regex.is_match(o["id"].as_str().unwrap())).cloned().collect());
+    }
+    let guid = waql.strip_prefix("$ \"")?.strip_suffix('"')?;
+    Some(objects.iter().filter(|o| o["id"] == guid).cloned().collect())
+}
+
+fn all_types() -> Vec<String> {
+    vec!["GUID".to_string(), "ShortID".to_string(), "MediaID".to_string()]
+}
+
+#[test]
+fn merges_results_of_several_queries_by_guid() {
+    let server = MockServer::start();
+    let mut client = server.client();
+
+    // 十进制同时按 ShortID、MediaID 和部分 GUID 查询，Sound 被 ShortID 和 GUID 两个查询返回
+    let response = search_with_client(&mut client, "583224111", &all_types(), MatchMode::Auto, TIMEOUT).unwrap();
+    assert_eq!(server.state.queries.lock().unwrap().len(), 3);
+    assert_eq!(response.interpretations.len(), 2);
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].guid, SOUND_GUID);
+    assert_eq!(response.results[0].short_id, "583224111");
+}
+
+#[test]
+fn fills_media_id_from_media_query() {
+    let server = MockServer::start();
+    let mut client = server.client();
+
+    let response = search_with_client(&mut client, "403870132", &all_types(), MatchMode::Auto, TIMEOUT).unwrap();
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].guid, SOURCE_GUID);
+    assert_eq!(response.results[0].object_type, "AudioFileSource");
+    assert_eq!(response.results[0].media_id, "403870132");
+}
+
+#[test]
+fn searches_guids_by_exact_and_partial_waql() {
+    let server = MockServer::start();
+    let mut client = server.client();
+
+    let exact = search_with_client(&mut client, &SOURCE_GUID.to_lowercase(), &all_types(), MatchMode::Exact, TIMEOUT)
+        .unwrap();
+    assert_eq!(exact.results.len(), 1);
+    assert_eq!(exact.results[0].guid, SOURCE_GUID);
+
+    let prefix = search_with_client(&mut client, "3f2504e0-4f", &all_types(), MatchMode::Prefix, TIMEOUT).unwrap();
+    assert_eq!(prefix.results.len(), 1);
+    assert_eq!(prefix.results[0].guid, SOUND_GUID);
+
+    let queries = server.state.queries.lock().unwrap();
+    assert_eq!(queries[0], format!("$ \"{}\"", SOURCE_GUID));
+    assert_eq!(queries[1], "$ where id : \"^\\\\{3F2504E0-4F\"");
+}
+
+#[test]
+fn reuses_the_session_and_reconnects_after_disconnect() {
+    let server = MockServer::start();
+    let mut client = server.client();
+
+    for _ in 0..3 {
+        client.call("ak.wwise.core.getInfo", json!({}), json!({}), TIMEOUT).unwrap();
+    }
+    assert_eq!(server.connections(), 1);
+    assert_eq!(client.connections(), 1);
+
+    server.state.drop_after_call.store(true, Ordering::SeqCst);
+    client.call("ak.wwise.core.getInfo", json!({}), json!({}), TIMEOUT).unwrap();
+
+    // 服务器已断开，下一次调用自动重连
+    let info = client.call("ak.wwise.core.getInfo", json!({}), json!({}), TIMEOUT).unwrap();
+    assert_eq!(info["version"]["displayName"], "v2023.1.0");
+    assert_eq!(server.connections(), 2);
+    assert_eq!(client.connections(), 2);
+}
+
+#[test]
+fn reports_call_errors_and_timeouts() {
+    let server = MockServer::start();
+    let mut client = server.client();
+
+    match client.call("ak.wwise.core.unknown", json!({}), json!({}), TIMEOUT) {
+        Err(WaapiError::Call { uri, message }) => {
+            assert_eq!(uri, "ak.wwise.invalid_procedure");
+            assert_eq!(message, "The procedure URI is unknown.");
+        }
+        other => panic!("期望调用错误，得到 {:?}", other),
+    }
+
+    match client.call("test.slow", json!({}), json!({}), Duration::from_millis(300)) {
+        Err(WaapiError::Timeout(_)) => {}
+        other => panic!("期望超时，得到 {:?}", other),
+    }
+
+    // 超时不影响之后的调用
+    client.call("ak.wwise.core.getInfo", json!({}), json!({}), TIMEOUT).unwrap();
+    assert_eq!(server.connections(), 1);
+}
+
+#[test]
+fn rejects_structured_queries() {
+    let server = MockServer::start();
+    let mut client = server.client();
+
+    let error = search_with_client(&mut client, "type:Event", &all_types(), MatchMode::Auto, TIMEOUT).unwrap_err();
+    assert!(error.contains("结构化查询"), "{}", error);
+    assert_eq!(server.connections(), 0);
+}
+
+#[test]
+fn command_reports_unreachable_server() {
+    // 绑定后立即释放，得到一个没有服务监听的端口
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let error = search_waapi("127.0.0.1".to_string(), port, "583224111".to_string(), all_types(), None, Some(1))
+        .unwrap_err();
+    assert!(error.contains("无法连接到 WAAPI"), "{}", error);
+}
+
+#[test]
+fn receives_events_and_resubscribes_after_reconnect() {
+    let server = MockServer::start();
+    let mut client = server.client();
+    subscribe_live_topics(&mut client, TIMEOUT).unwrap();
+    assert_eq!(server.state.subscriptions.load(Ordering::SeqCst), LIVE_TOPICS.len());
+
+    let publish_rename = |client: &mut WaapiClient, new_name: &str| {
+        let event = json!({
+            "object": { "id": SOUND_GUID, "name": new_name, "type": "Sound",
+                        "path": format!("\\Actor-Mixer Hierarchy\\Default Work Unit\\{}", new_name) },
+            "oldName": "Footstep",
+            "newName": new_name,
+        });
+        client
+            .call("test.publish", json!({ "topic": "ak.wwise.core.object.nameChanged", "event": event }), json!({}), TIMEOUT)
+            .unwrap();
+    };
+
+    publish_rename(&mut client, "Footstep_Grass");
+    let events = client.poll_events(Duration::from_millis(300)).unwrap();
+    assert_eq!(events.len(), 1);
+    match parse_event(&events[0]) {
+        Some(LiveChange::Renamed { guid, old_name, new_name, path }) => {
+            assert_eq!(guid, SOUND_GUID);
+            assert_eq!(old_name, "Footstep");
+            assert_eq!(new_name, "Footstep_Grass");
+            assert!(path.ends_with("\\Footstep_Grass"));
+        }
+        other => panic!("期望重命名，得到 {:?}", other),
+    }
+
+    // 服务器断开后，下一次轮询报告连接错误，再下一次重连并恢复订阅
+    server.state.drop_after_call.store(true, Ordering::SeqCst);
+    client.call("ak.wwise.core.getInfo", json!({}), json!({}), TIMEOUT).unwrap();
+    assert!(matches!(client.poll_events(Duration::from_millis(300)), Err(WaapiError::Connection(_))));
+    assert!(client.poll_events(Duration::from_millis(100)).unwrap().is_empty());
+    assert_eq!(server.connections(), 2);
+    assert_eq!(server.state.subscriptions.load(Ordering::SeqCst), LIVE_TOPICS.len() * 2);
+
+    publish_rename(&mut client, "Footstep_Wood");
+    let events = client.poll_events(Duration::from_millis(300)).unwrap();
+    assert!(matches!(parse_event(&events[0]), Some(LiveChange::Renamed { new_name, .. }) if new_name == "Footstep_Wood"));
+}
+
+fn indexed(guid: &str, name: &str) -> IndexedObject {
+    IndexedObject {
+        name: name.to_string(),
+        object_type: "Sound".to_string(),
+        guid: guid.to_string(),
+        short_id: String::new(),
+        media_ids: Vec::new(),
+        path: format!("\\Actor-Mixer Hierarchy\\Default Work Unit\\{}", name),
+        parent: "Default Work Unit".to_string(),
+        work_unit: "Default Work Unit".to_string(),
+        banks: Vec::new(),
+    }
+}
+
+#[test]
+fn overlays_unsaved_changes_on_the_project_index() {
+    let mut overlay = LiveOverlay::new(Some("C:\\Projects\\Demo\\"));
+    assert!(overlay.applies_to(Path::new("c:/projects/demo")));
+    assert!(!overlay.applies_to(Path::new("c:/projects/other")));
+
+    overlay.apply(&LiveChange::Renamed {
+        guid: SOUND_GUID.to_string(),
+        old_name: "Footstep".to_string(),
+        new_name: "Footstep_Grass".to_string(),
+        path: "\\Actor-Mixer Hierarchy\\Default Work Unit\\Footstep_Grass".to_string(),
+    });
+    overlay.apply(&LiveChange::Deleted {
+        guid: SOURCE_GUID.to_string(),
+        name: "Footstep".to_string(),
+        object_type: "AudioFileSource".to_string(),
+    });
+    let created = "{11111111-2222-3333-4444-555555555555}";
+    overlay.apply(&LiveChange::Created { object: indexed(created, "New_Sound") });
+
+    let mut objects = vec![indexed(SOUND_GUID, "Footstep"), indexed(SOURCE_GUID, "Footstep")];
+    overlay.apply_to(&mut objects);
+    let names: Vec<(&str, &str)> = objects.iter().map(|o| (o.guid.as_str(), o.name.as_str())).collect();
+    assert_eq!(names, vec![(SOUND_GUID, "Footstep_Grass"), (created, "New_Sound")]);
+
+    // 保存后磁盘上的 .wwu 已包含这些修改
+    overlay.apply(&LiveChange::ProjectSaved);
+    let mut objects = vec![indexed(SOUND_GUID, "Footstep")];
+    overlay.apply_to(&mut objects);
+    assert_eq!(objects[0].name, "Footstep");
+    assert_eq!(objects.len(), 1);
+}
+
+#[test]
+fn keeps_the_overlay_when_reconnecting_to_the_same_project() {
+    let renamed = LiveChange::Renamed {
+        guid: SOUND_GUID.to_string(),
+        old_name: "Footstep".to_string(),
+        new_name: "Footstep_Grass".to_string(),
+        path: String::new(),
+    };
+    let mut overlay = None;
+    LiveOverlay::reconnect(&mut overlay, Some("C:\\Projects\\Demo"));
+    overlay.as_mut().unwrap().apply(&renamed);
+
+    // 同一工程（路径写法不同）重新连接时保留未保存的修改
+    LiveOverlay::reconnect(&mut overlay, Some("c:/projects/demo/"));
+    let mut objects = vec![indexed(SOUND_GUID, "Footstep")];
+    overlay.as_ref().unwrap().apply_to(&mut objects);
+    assert_eq!(objects[0].name, "Footstep_Grass");
+
+    // Wwise 打开了另一个工程时从空的叠加层开始
+    LiveOverlay::reconnect(&mut overlay, Some("C:\\Projects\\Other"));
+    let overlay = overlay.unwrap();
+    assert!(overlay.applies_to(Path::new("c:/projects/other")));
+    let mut objects = vec![indexed(SOUND_GUID, "Footstep")];
+    overlay.apply_to(&mut objects);
+    assert_eq!(objects[0].name, "Footstep");
+}
+
+#[test]
+fn shows_results_from_any_tab_in_wwise() {
+    let server = MockServer::start();
+    let mut client = server.client();
+    let actions = [ShowAction::FindInProjectExplorer, ShowAction::Inspect, ShowAction::Schematic];
+
+    // 工程和 Bank 结果中的 GUID 大小写、花括号不一定与 WAAPI 一致
+    let bank_guid = SOUND_GUID.trim_matches(|c| c == '{' || c == '}').to_lowercase();
+    let guid = show_with_client(&mut client, &bank_guid, "", &actions, TIMEOUT).unwrap();
+    assert_eq!(guid, SOUND_GUID);
+
+    let commands = server.state.commands.lock().unwrap().clone();
+    let names: Vec<&str> = commands.iter().map(|(c, _)| c.as_str()).collect();
+    assert_eq!(names, vec!["FindInProjectExplorerSyncGroup1", "Inspect", "ShowSchematicView"]);
+    assert!(commands.iter().all(|(_, objects)| objects == &json!([SOUND_GUID])));
+}
+
+#[test]
+fn falls_back_to_selection_channel_and_short_id() {
+    let server = MockServer::start();
+    server.state.selection_channels.store(true, Ordering::SeqCst);
+    let mut client = server.client();
+
+    // Wwise_IDs.h 的结果没有 GUID，按 ShortID 定位
+    let guid = show_with_client(&mut client, "", "583224111", &[ShowAction::FindInProjectExplorer], TIMEOUT).unwrap();
+    assert_eq!(guid, SOUND_GUID);
+
+    let commands = server.state.commands.lock().unwrap().clone();
+    let names: Vec<&str> = commands.iter().map(|(c, _)| c.as_str()).collect();
+    assert_eq!(names, vec!["FindInProjectExplorerSyncGroup1", "FindInProjectExplorerSelectionChannel1"]);
+}
+
+#[test]
+fn reports_objects_missing_from_the_open_project() {
+    let server = MockServer::start();
+    let mut client = server.client();
+
+    let error = show_with_client(
+        &mut client,
+        "{00000000-0000-0000-0000-000000000000}",
+        "",
+        &[ShowAction::Inspect],
+        TIMEOUT,
+    )
+    .unwrap_err();
+    assert!(error.contains("没有该对象"), "{}", error);
+    assert!(server.state.commands.lock().unwrap().is_empty());
+
+    let error = show_with_client(&mut client, "", "", &[ShowAction::Inspect], TIMEOUT).unwrap_err();
+    assert!(error.contains("无法在 Wwise 中定位"), "{}", error);
+}
@@ -0,0 +1,55 @@
+//! 工程目录遍历测试，使用 `tests/fixtures/walker/`
+//!
+//! 夹具中的 `Generated/` 被夹具自己的 `.gitignore` 忽略，添加到 git 时需要 `git add -f`。
+
+mod common;
+
+use std::fs;
+use common::fixture_path;
+use wid_lib::modules::walker::walk_project_files;
+
+#[test]
+fn skips_ignored_and_cache_directories() {
+    let project = fixture_path("walker/Project");
+    let scan = walk_project_files(&project, "wwu").unwrap();
+    let files: Vec<String> = scan
+        .files
+        .iter()
+        .map(|f| f.strip_prefix(&project).unwrap().to_string_lossy().replace('\\', "/"))
+        .collect();
+
+    // 跳过：.cache、.backup、.gitignore 中的 Generated/、.widignore 中的 Scratch.wwu、.wid.toml 中的 Backup/**
+    // 保留：其他以点开头的目录，大写扩展名，以及带 [ ] 的文件名
+    assert_eq!(
+        files,
+        [
+            ".Shared/Shared.wwu",
+            "Actor-Mixer Hierarchy/Default Work Unit.wwu",
+            "Actor-Mixer Hierarchy/LEGACY.WWU",
+            "Events/Default Work Unit.wwu",
+            "Events/[Draft] Work Unit.wwu",
+        ]
+    );
+
+    // 访问过的文件还包括 .gitignore、.wid.toml、.widignore 和 Notes.txt，不包括被忽略的文件
+    assert_eq!((scan.stats.files_visited, scan.stats.files_matched, scan.stats.errors), (9, 5, 0));
+}
+
+#[test]
+fn skips_git_directories_and_reports_invalid_rules() {
+    let dir = std::env::temp_dir().join(format!("wid-walker-{}", std::process::id()));
+    fs::create_dir_all(dir.join(".git/objects")).unwrap();
+    fs::write(dir.join(".git/objects/Packed.wwu"), "").unwrap();
+    fs::write(dir.join("Default Work Unit.wwu"), "").unwrap();
+
+    let scan = walk_project_files(&dir, "wwu");
+    fs::write(dir.join(".wid.toml"), "ignore = [\"Backup/{a\"]\n").unwrap();
+    let invalid = walk_project_files(&dir, "wwu");
+    fs::remove_dir_all(&dir).unwrap();
+
+    let scan = scan.unwrap();
+    assert_eq!(scan.files.len(), 1);
+    assert_eq!(scan.stats.files_visited, 1);
+    assert!(invalid.unwrap_err().contains("Backup/{a"));
+    assert!(walk_project_files(&dir, "wwu").is_err());
+}
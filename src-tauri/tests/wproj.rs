@@ -0,0 +1,88 @@
+//! .wproj 解析测试，使用 `tests/fixtures/wproj/` 和 `sample_project` / `bank_project` 的 .wproj
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use common::{fixture_path, sample_project};
+use wid_lib::modules::wproj::{find_wproj, parse_wproj};
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+#[test]
+fn resolves_bank_directories_per_platform() {
+    let project = fixture_path("wproj/Project");
+    let settings = parse_wproj(&project.join("Settings.wproj")).unwrap();
+    assert_eq!((settings.name.as_str(), settings.wwise_version.as_deref()), ("Settings", Some("2024.1")));
+
+    // Windows 按名称引用，Android 按 GUID（大小写不同）引用，Switch 没有设置时使用默认目录
+    let platforms: Vec<(&str, String, bool)> = settings
+        .platforms
+        .iter()
+        .map(|p| (p.name.as_str(), p.bank_directory.clone(), p.bank_directory_exists))
+        .collect();
+    assert_eq!(
+        platforms,
+        [
+            ("Windows", path_string(&project.join("Output/Windows")), false),
+            ("Android", path_string(&fixture_path("wproj/Banks/Mobile")), true),
+            ("Switch", path_string(&project.join("GeneratedSoundBanks/Switch")), false),
+        ]
+    );
+    // 默认 Bank 目录为第一个已生成 SoundBank 的平台
+    assert_eq!(settings.default_bank_directory(), Some(platforms[1].1.as_str()));
+    assert_eq!(settings.bank_directory("windows"), Some(platforms[0].1.as_str()));
+    assert_eq!(settings.bank_directory("PS5"), None);
+}
+
+#[test]
+fn reads_languages_and_project_directories() {
+    let project = fixture_path("wproj/Project");
+    let settings = parse_wproj(&project.join("Settings.wproj")).unwrap();
+    assert_eq!(settings.languages, ["English(US)", "French(France)"]);
+    assert_eq!(settings.default_language.as_deref(), Some("French(France)"));
+    assert_eq!(settings.originals_directory, path_string(&project.join("Sources/Originals")));
+    // ValueList 中取第一个非空的值
+    assert_eq!(settings.cache_directory, path_string(&fixture_path("wproj/Cache")));
+    assert_eq!(settings.header_directory, Some(path_string(&fixture_path("wproj/Headers"))));
+}
+
+#[test]
+fn uses_absolute_bank_paths_and_defaults() {
+    let dir = std::env::temp_dir().join(format!("wid-wproj-{}", std::process::id()));
+    let banks = dir.join("Banks");
+    fs::create_dir_all(&banks).unwrap();
+    let wproj = dir.join("Absolute.wproj");
+    let contents = format!(
+        r#"<WwiseDocument Type="Project" SchemaVersion="119"><ProjectInfo><Project Name="Absolute"><PropertyList>
+<Property Name="SoundBankPaths" Type="string"><ValueList><Value Platform="Windows">{}</Value></ValueList></Property>
+</PropertyList><Platforms><Platform Name="Windows"/></Platforms></Project></ProjectInfo></WwiseDocument>"#,
+        banks.display()
+    );
+    fs::write(&wproj, contents).unwrap();
+
+    let settings = parse_wproj(&wproj);
+    fs::remove_dir_all(&dir).unwrap();
+    let settings = settings.unwrap();
+    assert_eq!(settings.platforms[0].bank_directory, path_string(&banks));
+    assert!(settings.platforms[0].bank_directory_exists);
+    assert_eq!(settings.originals_directory, path_string(&dir.join("Originals")));
+    assert_eq!(settings.cache_directory, path_string(&dir.join(".cache")));
+    assert_eq!((settings.default_language, settings.header_directory), (None, None));
+}
+
+#[test]
+fn resolves_the_sample_projects_relative_output() {
+    let sample = find_wproj(Path::new(&sample_project())).unwrap().expect("sample_project 中应有 .wproj");
+    let settings = parse_wproj(&sample).unwrap();
+    let windows = &settings.platforms[0];
+    assert_eq!(windows.bank_directory, path_string(&fixture_path("sample_project/GeneratedSoundBanks/Windows")));
+    // sample_project 没有生成 SoundBank，bank_project 的 .wproj 相同但已生成
+    assert!(!windows.bank_directory_exists);
+    assert_eq!(settings.default_bank_directory(), None);
+
+    let generated = parse_wproj(&fixture_path("bank_project/Project/Sample.wproj")).unwrap();
+    assert_eq!(generated.default_bank_directory(), Some(common::bank_project_banks().as_str()));
+}
@@ -0,0 +1,156 @@
+//! 各 Wwise 版本的工程和 SoundbanksInfo 解析测试
+//!
+//! `tests/fixtures/<版本>/` 中为按各版本结构编写的精简工程和 Bank 元数据，
+//! 四个版本中的对象和 ID 相同，只有文件结构不同。
+
+use std::path::{Path, PathBuf};
+use wid_lib::modules::index::{build_bank_index, build_project_index, read_metadata_file, IndexedObject};
+use wid_lib::modules::schema::{wwise_version_label, MetadataLayout};
+use wid_lib::modules::validate_bank_directory;
+use wid_lib::modules::wproj::parse_wproj;
+
+const EVENT_ID: &str = "1218931779";
+const SOUND_ID: &str = "583224111";
+const MEDIA_ID: &str = "403870132";
+const STREAMED_MEDIA_ID: &str = "96374022";
+
+struct Fixture {
+    dir: &'static str,
+    wwise_version: &'static str,
+    document_schema: u32,
+    metadata_file: &'static str,
+    metadata_schema: u32,
+    soundbank_version: u32,
+    layout: MetadataLayout,
+}
+
+const FIXTURES: &[Fixture] = &[
+    Fixture {
+        dir: "wwise_2019_2",
+        wwise_version: "2019.2",
+        document_schema: 97,
+        metadata_file: "SoundbanksInfo.json",
+        metadata_schema: 11,
+        soundbank_version: 134,
+        layout: MetadataLayout::Legacy,
+    },
+    Fixture {
+        dir: "wwise_2021_1",
+        wwise_version: "2021.1",
+        document_schema: 110,
+        metadata_file: "SoundbanksInfo.xml",
+        metadata_schema: 12,
+        soundbank_version: 135,
+        layout: MetadataLayout::Legacy,
+    },
+    Fixture {
+        dir: "wwise_2023_1",
+        wwise_version: "2023.1",
+        document_schema: 119,
+        metadata_file: "SoundbanksInfo.json",
+        metadata_schema: 16,
+        soundbank_version: 150,
+        layout: MetadataLayout::Current,
+    },
+    Fixture {
+        dir: "wwise_2024_1",
+        wwise_version: "2024.1",
+        document_schema: 123,
+        metadata_file: "Main.json",
+        metadata_schema: 16,
+        soundbank_version: 154,
+        layout: MetadataLayout::Current,
+    },
+];
+
+fn fixture_path(fixture: &Fixture) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(fixture.dir)
+}
+
+fn bank_dir(fixture: &Fixture) -> PathBuf {
+    fixture_path(fixture).join("GeneratedSoundBanks/Windows")
+}
+
+fn find<'a>(objects: &'a [IndexedObject], object_type: &str, name: &str) -> &'a IndexedObject {
+    objects
+        .iter()
+        .find(|o| o.object_type == object_type && o.name == name)
+        .unwrap_or_else(|| panic!("未找到 {} {}", object_type, name))
+}
+
+#[test]
+fn detects_document_schema_version() {
+    for fixture in FIXTURES {
+        let settings = parse_wproj(&fixture_path(fixture).join("Project/Fixture.wproj")).unwrap();
+        assert_eq!(settings.schema_version, Some(fixture.document_schema), "{}", fixture.dir);
+        assert_eq!(settings.wwise_version.as_deref(), Some(fixture.wwise_version), "{}", fixture.dir);
+        assert_eq!(
+            settings.default_bank_directory().map(Path::new),
+            Some(bank_dir(fixture).as_path()),
+            "{}",
+            fixture.dir
+        );
+    }
+}
+
+#[test]
+fn detects_soundbanks_info_schema() {
+    for fixture in FIXTURES {
+        let metadata = read_metadata_file(&bank_dir(fixture).join(fixture.metadata_file), "SoundBanksInfo").unwrap();
+        let schema = metadata.schema.expect(fixture.dir);
+        assert_eq!(schema.schema_version, Some(fixture.metadata_schema), "{}", fixture.dir);
+        assert_eq!(schema.soundbank_version, Some(fixture.soundbank_version), "{}", fixture.dir);
+        assert_eq!(schema.layout, fixture.layout, "{}", fixture.dir);
+
+        // 验证 Bank 目录时只读取文件头，结果与完整解析一致
+        let info = validate_bank_directory(bank_dir(fixture).to_string_lossy().to_string()).unwrap();
+        let header = info.schema.expect(fixture.dir);
+        assert_eq!(header.schema_version, schema.schema_version, "{}", fixture.dir);
+        assert_eq!(header.soundbank_version, schema.soundbank_version, "{}", fixture.dir);
+        assert_eq!(header.layout, fixture.layout, "{}", fixture.dir);
+    }
+}
+
+#[test]
+fn indexes_work_units_of_every_version() {
+    for fixture in FIXTURES {
+        let index = build_project_index(&fixture_path(fixture).join("Project")).unwrap();
+        assert_eq!(index.stats.files_matched, 2, "{}", fixture.dir);
+
+        let event = find(&index.objects, "Event", "Play_Footstep");
+        assert_eq!(event.short_id, EVENT_ID, "{}", fixture.dir);
+        assert_eq!(event.path, "\\Events\\Default Work Unit\\Play_Footstep", "{}", fixture.dir);
+
+        let sound = find(&index.objects, "Sound", "Footstep");
+        assert_eq!(sound.short_id, SOUND_ID, "{}", fixture.dir);
+
+        let source = find(&index.objects, "AudioFileSource", "Footstep");
+        assert_eq!(source.media_ids, [MEDIA_ID], "{}", fixture.dir);
+    }
+}
+
+#[test]
+fn indexes_banks_of_every_version() {
+    for fixture in FIXTURES {
+        let objects = build_bank_index(&bank_dir(fixture)).unwrap();
+
+        let event = find(&objects, "Event", "Play_Footstep");
+        assert_eq!(event.short_id, EVENT_ID, "{}", fixture.dir);
+        assert_eq!(event.banks[0].name, "Main", "{}", fixture.dir);
+
+        // 旧结构的 IncludedMemoryFiles / StreamedFiles 与新结构的 Media 都应识别为媒体
+        let media = find(&objects, "Media", "Footstep.wav");
+        assert_eq!(media.media_ids, [MEDIA_ID], "{}", fixture.dir);
+        assert!(media.short_id.is_empty(), "{}", fixture.dir);
+
+        let streamed = find(&objects, "Media", "Ambience.wav");
+        assert_eq!(streamed.media_ids, [STREAMED_MEDIA_ID], "{}", fixture.dir);
+    }
+}
+
+#[test]
+fn labels_unknown_schema_versions() {
+    assert_eq!(wwise_version_label(110).as_deref(), Some("2021.1"));
+    assert_eq!(wwise_version_label(115).as_deref(), Some("2021.1+"));
+    assert_eq!(wwise_version_label(50), None);
+}
@@ -7,49 +7,84 @@ const config = {
   },
   waapi: {
     host: localStorage.getItem('waapi_host') || '127.0.0.1',
-    port: localStorage.getItem('waapi_port') || '8080'
+    port: localStorage.getItem('waapi_port') || '8080',
+    liveSync: localStorage.getItem('waapi_live_sync') === 'true'
   },
   bank: {
-    dirPath: localStorage.getItem('bank_dir_path') || ''
+    dirPath: localStorage.getItem('bank_dir_path') || '',
+    idsHeaderPath: localStorage.getItem('ids_header_path') || ''
   }
 };
 
-// 配置管理对象
+// 配置管理对象（配置保存在后端的配置文件中，可共享、导入导出并被命令行工具使用）
 const configManager = {
-  // 获取所有已保存的配置
-  getSavedConfigs() {
-    const savedConfigs = localStorage.getItem('saved_configs');
-    return savedConfigs ? JSON.parse(savedConfigs) : {};
+  // 最近一次从后端读取的配置
+  profiles: {},
+  // 启动时迁移的旧配置数量，显示在配置文件路径后面
+  migratedCount: 0,
+
+  // 从后端重新读取配置列表
+  async refresh() {
+    try {
+      const list = await invoke("list_profiles");
+      this.profiles = list.profiles;
+      this.updateConfigSelect();
+      document.querySelector('#profilesPath').textContent =
+        `配置文件: ${list.path}${list.portable ? '（便携模式）' : ''}` +
+        (this.migratedCount ? `，已迁移 ${this.migratedCount} 个旧配置` : '');
+    } catch (error) {
+      showMessage('❌ 读取配置失败: ' + error);
+      console.error("读取配置失败:", error);
+    }
+  },
+
+  // 将旧版本保存在 localStorage 中的配置迁移到配置文件
+  async migrateLocalStorage() {
+    const legacy = localStorage.getItem('saved_configs');
+    if (!legacy) {
+      return;
+    }
+    try {
+      const count = await invoke("import_legacy_profiles", { profiles: JSON.parse(legacy) });
+      localStorage.removeItem('saved_configs');
+      this.migratedCount = count;
+    } catch (error) {
+      console.error("迁移旧配置失败:", error);
+    }
   },
 
   // 保存配置
-  saveConfig(name, configData) {
-    const savedConfigs = this.getSavedConfigs();
-    savedConfigs[name] = {
-      ...configData,
-      savedAt: new Date().toISOString()
-    };
-    localStorage.setItem('saved_configs', JSON.stringify(savedConfigs));
-    this.updateConfigSelect();
+  async saveConfig(name, configData) {
+    await invoke("save_profile", {
+      name,
+      profile: {
+        ...configData,
+        waapi: { host: configData.waapi.host, port: Number(configData.waapi.port) },
+        savedAt: new Date().toISOString()
+      }
+    });
+    await this.refresh();
   },
 
   // 加载配置
-  loadConfig(name) {
-    const savedConfigs = this.getSavedConfigs();
-    if (savedConfigs[name]) {
-      const configData = savedConfigs[name];
+  async loadConfig(name) {
+    try {
+      const configData = await invoke("load_profile", { name });
       
       // 更新全局配置对象
       config.wwise.projPath = configData.wwise?.projPath || '';
       config.waapi.host = configData.waapi?.host || '127.0.0.1';
-      config.waapi.port = configData.waapi?.port || '8080';
+      config.waapi.port = String(configData.waapi?.port || '8080');
       config.bank.dirPath = configData.bank?.dirPath || '';
+      config.bank.idsHeaderPath = configData.bank?.idsHeaderPath || '';
       
       // 保存到localStorage
       saveConfig();
       
       // 更新UI
       this.updateConfigUI();
+      refreshSummaries();
+      updateLiveSync();
       
       // 设置当前选中的配置
       localStorage.setItem('current_config', name);
@@ -57,43 +92,84 @@ const configManager = {
       
       showMessage(`✅ 配置 "${name}" 加载成功`);
       checkConfigAndShowAlert();
+    } catch (error) {
+      showMessage('❌ 加载配置失败: ' + error);
     }
   },
 
   // 删除配置
-  deleteConfig(name) {
+  async deleteConfig(name) {
     if (name === 'default') {
       showMessage('❌ 无法删除默认配置');
       return;
     }
     
-    const savedConfigs = this.getSavedConfigs();
-    if (savedConfigs[name]) {
-      delete savedConfigs[name];
-      localStorage.setItem('saved_configs', JSON.stringify(savedConfigs));
+    try {
+      await invoke("delete_profile", { name });
       
       // 如果删除的是当前配置，切换到默认配置
       const currentConfig = localStorage.getItem('current_config');
       if (currentConfig === name) {
         localStorage.setItem('current_config', 'default');
-        document.querySelector('#configSelect').value = 'default';
       }
       
-      this.updateConfigSelect();
+      await this.refresh();
       showMessage(`✅ 配置 "${name}" 删除成功`);
+    } catch (error) {
+      showMessage('❌ 删除配置失败: ' + error);
+    }
+  },
+
+  // 从文件导入配置
+  async importConfigs() {
+    try {
+      const selected = await window.__TAURI__.dialog.open({
+        directory: false,
+        multiple: false,
+        title: "导入配置",
+        filters: [{ name: "配置文件", extensions: ["toml", "json"] }]
+      });
+      if (!selected) {
+        return;
+      }
+      const count = await invoke("import_profiles", { path: selected });
+      await this.refresh();
+      showMessage(`✅ 已导入 ${count} 个配置`);
+    } catch (error) {
+      showMessage('❌ 导入配置失败: ' + error);
+    }
+  },
+
+  // 导出所有配置到文件
+  async exportConfigs() {
+    try {
+      const selected = await window.__TAURI__.dialog.save({
+        title: "导出配置",
+        defaultPath: "wid-profiles.toml",
+        filters: [
+          { name: "TOML", extensions: ["toml"] },
+          { name: "JSON", extensions: ["json"] }
+        ]
+      });
+      if (!selected) {
+        return;
+      }
+      const count = await invoke("export_profiles", { path: selected });
+      showMessage(`✅ 已导出 ${count} 个配置`);
+    } catch (error) {
+      showMessage('❌ 导出配置失败: ' + error);
     }
   },
 
   // 更新配置选择下拉列表
   updateConfigSelect() {
     const configSelect = document.querySelector('#configSelect');
-    const savedConfigs = this.getSavedConfigs();
     
     // 清空现有选项（除了默认配置）
     configSelect.innerHTML = '<option value="default">默认配置</option>';
     
     // 添加保存的配置
-    Object.keys(savedConfigs).forEach(name => {
+    Object.keys(this.profiles).forEach(name => {
       const option = document.createElement('option');
       option.value = name;
       option.textContent = name;
@@ -102,7 +178,7 @@ const configManager = {
     
     // 恢复当前选中的配置
     const currentConfig = localStorage.getItem('current_config') || 'default';
-    configSelect.value = currentConfig;
+    configSelect.value = this.profiles[currentConfig] ? currentConfig : 'default';
   },
 
   // 更新配置UI
@@ -111,6 +187,7 @@ const configManager = {
     document.querySelector('#waapiHost').value = config.waapi.host;
     document.querySelector('#waapiPort').value = config.waapi.port;
     document.querySelector('#bankDirPath').value = config.bank.dirPath;
+    document.querySelector('#idsHeaderPath').value = config.bank.idsHeaderPath;
   },
 
   // 获取当前配置数据
@@ -135,7 +212,9 @@ function saveConfig() {
   localStorage.setItem('wwise_proj_path', config.wwise.projPath);
   localStorage.setItem('waapi_host', config.waapi.host);
   localStorage.setItem('waapi_port', config.waapi.port);
+  localStorage.setItem('waapi_live_sync', config.waapi.liveSync);
   localStorage.setItem('bank_dir_path', config.bank.dirPath);
+  localStorage.setItem('ids_header_path', config.bank.idsHeaderPath);
 }
 
 // 获取当前激活的标签页
@@ -144,6 +223,7 @@ function getCurrentTab() {
   if (activeTab.id === 'wwise-tab') return 'wwise';
   if (activeTab.id === 'waapi-tab') return 'waapi';
   if (activeTab.id === 'bank-tab') return 'bank';
+  if (activeTab.id === 'resolve-tab') return null; // 批量解析页面有自己的输入区域
   if (activeTab.id === 'config-tab') return null;  // 配置页面不显示搜索区域
   if (activeTab.id === 'help-tab') return null;    // 帮助页面不显示搜索区域
   return null;
@@ -159,6 +239,119 @@ function getResultCount(tab) {
   return document.querySelector(`.resultCount[data-tab="${tab}"]`);
 }
 
+// 应用工程目录的验证结果：自动填写 Bank 目录，应用团队配置（.wid.toml），显示工程概要
+// 路径均已由后端按工程目录解析为绝对路径
+function applyProjectInfo(projectInfo) {
+  if (projectInfo.bank_directory) {
+    config.bank.dirPath = projectInfo.bank_directory;
+  }
+  const team = projectInfo.team_config?.config;
+  if (team?.ids_header) {
+    config.bank.idsHeaderPath = team.ids_header;
+  }
+  if (team?.waapi) {
+    config.waapi.host = team.waapi.host;
+    config.waapi.port = String(team.waapi.port);
+    updateLiveSync();
+  }
+  configManager.updateConfigUI();
+  displayProjectSummary(projectInfo);
+  refreshBankSummary();
+}
+
+// 重新读取当前工程目录并显示概要（不覆盖已保存的设置）
+function refreshProjectSummary() {
+  // 工程可能已更换，工程浏览在下次显示时重新加载
+  document.querySelector('#projectTree').innerHTML = '';
+  if (!config.wwise.projPath) {
+    displayProjectSummary(null);
+    return;
+  }
+  invoke("validate_wwise_directory", { path: config.wwise.projPath })
+    .then(displayProjectSummary)
+    .catch(error => {
+      displayProjectSummary(null);
+      console.warn("工程目录验证失败:", error);
+    });
+}
+
+// 重新读取工程目录和 Bank 目录并显示概要
+function refreshSummaries() {
+  refreshProjectSummary();
+  refreshBankSummary();
+}
+
+// 重新读取当前 Bank 目录并显示元数据版本
+function refreshBankSummary() {
+  if (!config.bank.dirPath) {
+    displayBankSummary(null);
+    return;
+  }
+  invoke("validate_bank_directory", { path: config.bank.dirPath })
+    .then(displayBankSummary)
+    .catch(error => {
+      displayBankSummary(null);
+      console.warn("Bank 目录验证失败:", error);
+    });
+}
+
+// 显示 Bank 元数据的来源、版本和结构
+function displayBankSummary(bankInfo) {
+  const summary = document.querySelector('#bankSummary');
+  if (!bankInfo) {
+    summary.textContent = '';
+    return;
+  }
+
+  const source = bankInfo.soundbanks_info
+    ? 'SoundbanksInfo 汇总文件'
+    : `${bankInfo.bank_file_count} 个单独的 Bank 元数据文件`;
+  const schema = bankInfo.schema;
+  let text = `元数据: ${source}`;
+  if (schema) {
+    const layout = schema.layout === 'Legacy' ? '旧结构（2021.1 及更早）' : '新结构（2022.1 起）';
+    const versions = [
+      schema.schema_version ? `SchemaVersion ${schema.schema_version}` : null,
+      schema.soundbank_version ? `SoundBankVersion ${schema.soundbank_version}` : null
+    ].filter(Boolean).join('，');
+    text += ` · ${versions ? versions + ' · ' : ''}${layout}`;
+  }
+  summary.textContent = text;
+}
+
+// 显示工程概要（平台、语言、输出目录等）
+function displayProjectSummary(projectInfo) {
+  const summary = document.querySelector('#projectSummary');
+  if (!projectInfo) {
+    summary.textContent = '';
+    return;
+  }
+
+  const project = projectInfo.project;
+  const platforms = project.platforms
+    .map(p => `${p.name}${p.bank_directory_exists ? '' : '（未生成）'}: ${p.bank_directory}`)
+    .join('\n  ');
+  const lines = [
+    `工程: ${project.name}` + (project.schema_version
+      ? `（SchemaVersion ${project.schema_version}${project.wwise_version ? `，Wwise ${project.wwise_version}` : ''}）`
+      : ''),
+    `平台:\n  ${platforms || '无'}`,
+    `语言: ${project.languages.join(', ') || '无'}` + (project.default_language ? `（默认 ${project.default_language}）` : ''),
+    `Originals: ${project.originals_directory}`,
+    `Cache: ${project.cache_directory}`
+  ];
+  if (projectInfo.team_config) {
+    lines.push(`团队配置: ${projectInfo.team_config.path}`);
+    const lint = Object.entries(projectInfo.team_config.config.lint)
+      .map(([rule, level]) => `${rule}=${level}`)
+      .join(', ');
+    if (lint) {
+      lines.push(`检查规则: ${lint}`);
+    }
+  }
+  summary.textContent = lines.join('\n');
+}
+
 // 打开文件夹浏览器（Wwise项目）
 async function browseProjPath() {
   try {
@@ -181,14 +374,17 @@ async function browseProjPath() {
     if (selected) {
       // 验证目录
       try {
-        const isValid = await invoke("validate_wwise_directory", { path: selected });
-        if (isValid) {
-          config.wwise.projPath = selected;
-          document.querySelector('#wwiseProjPath').value = selected;
-          saveConfig();
-          checkConfigAndShowAlert();
+        const projectInfo = await invoke("validate_wwise_directory", { path: selected });
+        config.wwise.projPath = selected;
+        document.querySelector('#wwiseProjPath').value = selected;
+        applyProjectInfo(projectInfo);
+        if (projectInfo.team_config) {
+          showMessage("✅ 目录验证成功，已加载团队配置 " + projectInfo.team_config.path);
+        } else {
           showMessage("✅ 目录验证成功！");
         }
+        saveConfig();
+        checkConfigAndShowAlert();
       } catch (validationError) {
         showMessage("❌ " + validationError);
         console.error("目录验证失败:", validationError);
@@ -214,7 +410,7 @@ async function browseBankPath() {
     const selected = await window.__TAURI__.dialog.open({
       directory: true,
       multiple: false,
-      title: "选择 Bank 目录（包含 SoundbanksInfo.xml 或 .json）"
+      title: "选择 Bank 目录（包含 SoundbanksInfo 或单独的 Bank 元数据文件）"
     });
     
     console.log("选择的目录:", selected);
@@ -222,14 +418,13 @@ async function browseBankPath() {
     if (selected) {
       // 验证目录
       try {
-        const isValid = await invoke("validate_bank_directory", { path: selected });
-        if (isValid) {
-          config.bank.dirPath = selected;
-          document.querySelector('#bankDirPath').value = selected;
-          saveConfig();
-          checkConfigAndShowAlert();
-          showMessage("✅ 目录验证成功！");
-        }
+        const bankInfo = await invoke("validate_bank_directory", { path: selected });
+        config.bank.dirPath = selected;
+        document.querySelector('#bankDirPath').value = selected;
+        displayBankSummary(bankInfo);
+        saveConfig();
+        checkConfigAndShowAlert();
+        showMessage("✅ 目录验证成功！");
       } catch (validationError) {
         showMessage("❌ " + validationError);
         console.error("目录验证失败:", validationError);
@@ -241,7 +436,72 @@ async function browseBankPath() {
   }
 }
 
-// 测试WAAPI连接（调用 waapi-query.js 中的函数）
+// 选择 Wwise_IDs.h 文件
+async function browseIdsHeaderPath() {
+  try {
+    const selected = await window.__TAURI__.dialog.open({
+      directory: false,
+      multiple: false,
+      title: "选择 Wwise_IDs.h 文件",
+      filters: [{ name: "C/C++ 头文件", extensions: ["h"] }]
+    });
+    
+    if (selected) {
+      config.bank.idsHeaderPath = selected;
+      document.querySelector('#idsHeaderPath').value = selected;
+      saveConfig();
+    }
+  } catch (error) {
+    console.error("选择文件出错:", error);
+    showMessage("❌ 选择文件失败: " + error);
+  }
+}
+
+// 生成 ID 常量文件
+async function generateConstants() {
+  const source = document.querySelector('#constantsSource').value;
+  const language = document.querySelector('#constantsLanguage').value;
+  const sourcePaths = {
+    wwise: config.wwise.projPath,
+    bank: config.bank.dirPath,
+    header: config.bank.idsHeaderPath
+  };
+  const fileNames = {
+    csharp: 'WwiseIDs.cs',
+    rust: 'wwise_ids.rs',
+    typescript: 'WwiseIDs.ts',
+    lua: 'WwiseIDs.lua'
+  };
+  
+  const sourcePath = sourcePaths[source];
+  if (!sourcePath) {
+    showMessage("请先在配置中设置所选来源的路径");
+    return;
+  }
+  
+  try {
+    const outputPath = await window.__TAURI__.dialog.save({
+      title: "保存 ID 常量文件",
+      defaultPath: fileNames[language]
+    });
+    if (!outputPath) {
+      return;
+    }
+    
+    const count = await invoke("generate_id_constants", {
+      source,
+      sourcePath,
+      language,
+      outputPath
+    });
+    showMessage(`✅ 已生成 ${count} 个常量`);
+  } catch (error) {
+    showMessage("❌ 生成失败: " + error);
+    console.error("生成 ID 常量文件失败:", error);
+  }
+}
+
+// 测试WAAPI连接
 async function testWaapiConnectionHandler() {
   const host = document.querySelector('#waapiHost').value;
   const port = document.querySelector('#waapiPort').value;
@@ -256,7 +516,7 @@ async function testWaapiConnectionHandler() {
   saveConfig();
 
   try {
-    const message = await testWaapiConnection(host, port);
+    const message = await invoke("test_waapi_connection", { host, port: Number(port) });
     showMessage(message);
   } catch (error) {
     showMessage("❌ " + error);
@@ -265,8 +525,8 @@ async function testWaapiConnectionHandler() {
 }
 
 
-// 执行搜索
-async function performSearch(tab) {
+// 执行搜索；`lastSearch` 为实时同步重新执行上一次搜索时的条件，此时保留当前页
+async function performSearch(tab, lastSearch = null) {
   // 使用共享的输入框和复选框
   const idInput = document.querySelector('#sharedIdInput');
   const typeCheckboxes = document.querySelectorAll('#sharedGuidCheckbox, #sharedShortIdCheckbox, #sharedMediaIdCheckbox');
@@ -287,14 +547,17 @@ async function performSearch(tab) {
     return;
   }
   
-  const searchValue = idInput.value.trim();
-  const selectedTypes = [];
+  const searchValue = lastSearch ? lastSearch.searchValue : idInput.value.trim();
+  const matchMode = lastSearch ? lastSearch.matchMode : document.querySelector('#sharedMatchModeSelect').value;
+  const selectedTypes = lastSearch ? [...lastSearch.selectedTypes] : [];
   
-  typeCheckboxes.forEach(checkbox => {
-    if (checkbox.checked) {
-      selectedTypes.push(checkbox.value);
-    }
-  });
+  if (!lastSearch) {
+    typeCheckboxes.forEach(checkbox => {
+      if (checkbox.checked) {
+        selectedTypes.push(checkbox.value);
+      }
+    });
+  }
 
   if (!searchValue) {
     showMessage("请输入查询条件");
@@ -308,34 +571,63 @@ async function performSearch(tab) {
 
   try {
     let results = [];
+    let interpretations = [];
+    let scan = null;
     
     // 根据不同标签页调用相应的API
     if (tab === 'wwise') {
-      results = await invoke("search_wwise_project", { 
+      const response = await invoke("search_wwise_project", { 
         directory: config.wwise.projPath, 
         idString: searchValue, 
-        idTypes: selectedTypes 
+        idTypes: selectedTypes,
+        matchMode
       });
+      results = response.results;
+      interpretations = response.interpretations;
+      scan = response.scan;
     } else if (tab === 'waapi') {
-      // 使用 JavaScript WAAPI 查询（调用 waapi-query.js）
-      results = await searchWithWAAPI(
-        config.waapi.host, 
-        config.waapi.port, 
-        searchValue, 
-        selectedTypes
-      );
-      // WAAPI 结果已经是新格式，不需要转换
+      const response = await invoke("search_waapi", {
+        host: config.waapi.host,
+        port: Number(config.waapi.port),
+        idString: searchValue,
+        idTypes: selectedTypes,
+        matchMode
+      });
+      results = response.results;
+      interpretations = response.interpretations;
     } else if (tab === 'bank') {
-      results = await invoke("search_bank_directory", { 
+      const response = await invoke("search_bank_directory", { 
         directory: config.bank.dirPath, 
         idString: searchValue, 
-        idTypes: selectedTypes 
+        idTypes: selectedTypes,
+        matchMode
       });
+      results = response.results;
+      interpretations = response.interpretations;
+      
+      // 同时搜索 Wwise_IDs.h（如果已配置）
+      if (config.bank.idsHeaderPath) {
+        const headerResponse = await invoke("search_ids_header", {
+          path: config.bank.idsHeaderPath,
+          idString: searchValue,
+          idTypes: selectedTypes,
+          matchMode
+        });
+        results = results.concat(headerResponse.results);
+      }
     }
 
-    // 保存结果并显示第一页
-    paginationData[tab].totalResults = results;
-    paginationData[tab].currentPage = 1;
+    if (!lastSearch) {
+      displayInterpretations(interpretations, scan);
+    }
+
+    // 保存结果和搜索条件，新的搜索显示第一页
+    const data = paginationData[tab];
+    data.totalResults = results;
+    data.lastSearch = { searchValue, matchMode, selectedTypes };
+    data.currentPage = lastSearch
+      ? Math.min(data.currentPage, Math.max(1, Math.ceil(results.length / data.pageSize)))
+      : 1;
     displayResultsWithPagination(tab);
   } catch (error) {
     showMessage("搜索失败: " + error);
@@ -343,6 +635,699 @@ async function performSearch(tab) {
   }
 }
 
+// 在正在运行的 Wwise 中显示结果对象；没有 GUID 的结果（如 Wwise_IDs.h）按 ShortID 定位
+async function showInWwise(item, actions) {
+  if (!config.waapi.host || !config.waapi.port) {
+    showMessage("请先设置 WAAPI 连接信息");
+    return;
+  }
+  try {
+    await invoke("show_in_wwise", {
+      host: config.waapi.host,
+      port: Number(config.waapi.port),
+      guid: item.guid || '',
+      shortId: item.short_id || null,
+      actions
+    });
+  } catch (error) {
+    showMessage("❌ " + error);
+    console.error("在 Wwise 中显示失败:", error);
+  }
+}
+
+// 显示对象在 .wwu 中定义的属性、引用、RTPC、State、子对象和源
+async function showObjectDetails(item) {
+  if (!config.wwise.projPath) {
+    showMessage("请先设置 Wwise 工程路径");
+    return;
+  }
+  try {
+    const details = await invoke("get_object_details", {
+      directory: config.wwise.projPath,
+      guid: item.guid
+    });
+    renderObjectDetails(details);
+    bootstrap.Modal.getOrCreateInstance(document.querySelector('#objectDetailsModal')).show();
+  } catch (error) {
+    showMessage("❌ " + error);
+    console.error("读取对象详情失败:", error);
+  }
+}
+
+function escapeHtml(text) {
+  return String(text ?? '').replace(/[&<>"']/g, c => ({ '&': '&amp;', '<': '&lt;', '>': '&gt;', '"': '&quot;', "'": '&#39;' })[c]);
+}
+
+// 渲染详情中的一个表格，没有内容时不显示
+function detailsSection(title, headers, rows) {
+  if (rows.length === 0) {
+    return '';
+  }
+  const head = headers.map(h => `<th>${h}</th>`).join('');
+  const body = rows.map(row => `<tr>${row.map(cell => `<td>${escapeHtml(cell)}</td>`).join('')}</tr>`).join('');
+  return `
+    <h6 class="mt-3">${title}</h6>
+    <table class="table table-sm table-bordered mb-0">
+      <thead class="table-light"><tr>${head}</tr></thead>
+      <tbody>${body}</tbody>
+    </table>
+  `;
+}
+
+function renderObjectDetails(details) {
+  const object = details.object;
+  const target = ref => ref ? `${ref.target_name}${ref.target_type ? ` (${ref.target_type})` : ''}${ref.resolved ? '' : ' ⚠ 未找到'}` : '';
+
+  document.querySelector('#objectDetailsTitle').textContent = `${object.name} (${object.object_type})`;
+  document.querySelector('#objectDetailsBody').innerHTML = `
+    <div><code>${escapeHtml(object.guid)}</code> ShortID: ${escapeHtml(object.short_id)}</div>
+    <div class="text-muted">${escapeHtml(object.path)}</div>
+    <div class="text-muted">${escapeHtml(details.work_unit_file)}</div>
+    ${details.notes ? `<div class="alert alert-secondary py-1 mt-2 mb-0">${escapeHtml(details.notes)}</div>` : ''}
+    ${detailsSection('属性', ['名称', '类型', '值', '平台值'], details.properties.map(p => [
+      p.name, p.value_type, p.value, p.platform_values.map(v => `${v.platform}: ${v.value}`).join(', ')
+    ]))}
+    ${detailsSection('引用', ['名称', '目标', '路径'], details.references.map(r => [
+      r.name, target(r) + (r.embedded ? ' [自定义]' : ''), r.target_path
+    ]))}
+    ${detailsSection('有效值（考虑继承）', ['名称', '值', '来源', '规则'], details.effective_values.map(v => [
+      v.name,
+      (v.is_default ? '默认值' : v.value) + (v.reference && !v.reference.resolved ? ' ⚠ 未找到' : ''),
+      v.rule === 'additive'
+        ? v.contributions.map(c => `${c.source.name}: ${c.value}`).join(' + ')
+        : `${v.source.name}${v.inherited ? ' (继承)' : ''}`,
+      { own: '不继承', override: v.override_flag, additive: '累加' }[v.rule]
+    ]))}
+    ${detailsSection('RTPC', ['属性', '控制输入', '曲线'], details.rtpcs.map(r => [
+      r.property, target(r.control_input), r.points.map(p => `(${p.x}, ${p.y})`).join(' ')
+    ]))}
+    ${detailsSection('State Group', ['名称', '路径'], details.state_groups.map(g => [target(g), g.target_path]))}
+    ${detailsSection('State 属性', ['State Group', 'State', '属性', '值'], details.state_properties.map(s => [
+      s.state_group, s.state, s.property, s.value
+    ]))}
+    ${detailsSection('子对象', ['名称', '类型', 'GUID', 'ShortID'], details.children.map(c => [
+      c.name, c.object_type, c.guid, c.short_id
+    ]))}
+    ${detailsSection('源', ['名称', '语言', '文件 / 插件', 'MediaID', '使用中'], details.sources.map(s => [
+      s.name, s.language, s.audio_file || s.plugin, s.media_id, s.active ? '✓' : ''
+    ]))}
+  `;
+}
+
+// 显示 Event 的动作；没有工程目录时使用 Bank 目录中 SoundbanksInfo 记录的动作
+async function showEventReport(item) {
+  if (!config.wwise.projPath && !config.bank.dirPath) {
+    showMessage("请先设置 Wwise 工程路径或 Bank 目录");
+    return;
+  }
+  try {
+    const report = await invoke("get_event_report", {
+      directory: config.wwise.projPath || null,
+      bankDirectory: config.bank.dirPath || null,
+      event: item.guid || item.short_id
+    });
+    renderEventReport(report);
+    bootstrap.Modal.getOrCreateInstance(document.querySelector('#objectDetailsModal')).show();
+  } catch (error) {
+    showMessage("❌ " + error);
+    console.error("读取 Event 动作失败:", error);
+  }
+}
+
+function renderEventReport(report) {
+  const seconds = value => value === null || value === undefined ? '' : `${value} s`;
+  const event = report.event;
+  document.querySelector('#objectDetailsTitle').textContent = `${event.name} (Event)`;
+  document.querySelector('#objectDetailsBody').innerHTML = `
+    <div><code>${escapeHtml(event.guid)}</code> ShortID: ${escapeHtml(event.short_id)}</div>
+    <div class="text-muted">${escapeHtml(event.path)}</div>
+    <div class="text-muted">动作来自${report.source === 'project' ? '工程文件' : ' SoundbanksInfo（未找到工程文件，只包含 SoundbanksInfo 记录的动作）'}</div>
+    ${detailsSection('动作', ['类型', '目标', '路径', '范围', '延迟', '淡入淡出', '其他属性'], report.actions.map(a => [
+      a.action_type,
+      a.target ? `${a.target.target_name}${a.target.target_type ? ` (${a.target.target_type})` : ''}${a.target.resolved ? '' : ' ⚠ 未找到'}` : '',
+      a.target?.target_path || '',
+      { GameObject: 'Game Object', Global: '全局' }[a.scope] || '',
+      seconds(a.delay),
+      a.fade_time ? `${seconds(a.fade_time)} ${a.fade_curve || ''}` : '',
+      a.properties.map(p => `${p.name}=${p.value}`).join(', ')
+    ]))}
+    ${detailsSection('所在 SoundBank', ['名称', 'ID', '路径', '语言'], report.banks.map(b => [b.name, b.id, b.path, b.language]))}
+    ${detailsSection('引用的媒体', ['MediaID', '名称'], report.media.map(m => [m.id, m.name]))}
+  `;
+}
+
+// 打开总线路由窗口并加载整个工程的路由
+async function showBusRouting() {
+  if (!config.wwise.projPath) {
+    showMessage("请先设置 Wwise 工程路径");
+    return;
+  }
+  bootstrap.Modal.getOrCreateInstance(document.querySelector('#busRoutingModal')).show();
+  await loadBusRouting();
+}
+
+// 读取路由图，输入了总线时只保留经过该总线的对象
+async function loadBusRouting() {
+  const body = document.querySelector('#busRoutingBody');
+  body.innerHTML = '<div class="text-muted">正在加载…</div>';
+  try {
+    const graph = await invoke("get_bus_routing", {
+      directory: config.wwise.projPath,
+      through: document.querySelector('#routingThroughInput').value.trim() || null
+    });
+    renderBusRouting(graph);
+  } catch (error) {
+    body.innerHTML = `<div class="text-danger">${escapeHtml(error)}</div>`;
+  }
+}
+
+function renderBusRouting(graph) {
+  const nodes = new Map(graph.nodes.map(node => [node.id, node]));
+  const name = id => {
+    const node = nodes.get(id);
+    return node ? `${node.name} (${node.object_type})` : id;
+  };
+  const kinds = { output_bus: '输出总线', user_aux_send: '用户辅助发送', game_aux_send: '游戏辅助发送', parent_bus: '上级总线' };
+  document.querySelector('#busRoutingBody').innerHTML = `
+    <div class="text-muted">共 ${graph.nodes.length} 个对象，${graph.edges.length} 条路由</div>
+    ${detailsSection('路由', ['来源', '类型', '目标', '说明', '来源路径'], graph.edges.map(e => [
+      name(e.source), kinds[e.kind] || e.kind, name(e.target), e.label, nodes.get(e.source)?.path || ''
+    ]))}
+  `;
+}
+
+// 导出路由图，格式根据扩展名推断
+async function exportBusRouting() {
+  if (!config.wwise.projPath) {
+    showMessage("请先设置 Wwise 工程路径");
+    return;
+  }
+  try {
+    const path = await window.__TAURI__.dialog.save({
+      title: "导出总线路由",
+      defaultPath: "wid-routing.dot",
+      filters: [
+        { name: "Graphviz DOT", extensions: ["dot", "gv"] },
+        { name: "Mermaid", extensions: ["mmd"] },
+        { name: "JSON", extensions: ["json"] }
+      ]
+    });
+    if (!path) {
+      return;
+    }
+    const count = await invoke("export_bus_routing", {
+      directory: config.wwise.projPath,
+      through: document.querySelector('#routingThroughInput').value.trim() || null,
+      format: null,
+      path
+    });
+    showMessage(`✅ 已导出 ${count} 条路由`);
+  } catch (error) {
+    showMessage("❌ 导出失败: " + error);
+    console.error("导出总线路由失败:", error);
+  }
+}
+
+function showDependencies() {
+  if (!config.wwise.projPath) {
+    showMessage("请先设置 Wwise 工程路径");
+    return;
+  }
+  bootstrap.Modal.getOrCreateInstance(document.querySelector('#dependencyModal')).show();
+}
+
+// 查询起点沿依赖到达终点的路径，如 Event 如何引用到某个媒体
+async function findDependencyPaths() {
+  const from = document.querySelector('#dependencyFromInput').value.trim();
+  const to = document.querySelector('#dependencyToInput').value.trim();
+  const body = document.querySelector('#dependencyBody');
+  if (!from || !to) {
+    showMessage("请输入起点和终点");
+    return;
+  }
+  body.innerHTML = '<div class="text-muted">正在查询…</div>';
+  try {
+    const paths = await invoke("find_dependency_paths", { directory: config.wwise.projPath, from, to });
+    renderDependencyPaths(paths);
+  } catch (error) {
+    body.innerHTML = `<div class="text-danger">${escapeHtml(error)}</div>`;
+  }
+}
+
+function renderDependencyPaths(paths) {
+  const body = document.querySelector('#dependencyBody');
+  if (paths.length === 0) {
+    body.innerHTML = '<div class="text-muted">起点没有引用到终点</div>';
+    return;
+  }
+  const kinds = {
+    child: '子对象', action: '动作', target: '目标', shareset: 'ShareSet',
+    inclusion: '包含', switch_group: 'Switch Group', media: '媒体'
+  };
+  body.innerHTML = `<div class="text-muted mb-2">共 ${paths.length} 条路径</div>` + paths.map(path => `
+    <div class="border rounded p-2 mb-2">
+      ${path.nodes.map((node, i) => `
+        ${i > 0 ? `<span class="text-muted mx-1">→ ${escapeHtml(kinds[path.edges[i - 1].kind] || path.edges[i - 1].kind)} →</span>` : ''}
+        <span title="${escapeHtml(node.path || node.id)}"><strong>${escapeHtml(node.name || node.id)}</strong> <small class="text-muted">${escapeHtml(node.object_type)}</small></span>
+      `).join('')}
+    </div>
+  `).join('');
+}
+
+// 导出整个工程的依赖图，格式根据扩展名推断
+async function exportDependencyGraph() {
+  try {
+    const path = await window.__TAURI__.dialog.save({
+      title: "导出依赖图",
+      defaultPath: "wid-dependencies.graphml",
+      filters: [
+        { name: "GraphML", extensions: ["graphml"] },
+        { name: "Graphviz DOT", extensions: ["dot", "gv"] },
+        { name: "JSON", extensions: ["json"] },
+        { name: "Mermaid", extensions: ["mmd"] }
+      ]
+    });
+    if (!path) {
+      return;
+    }
+    const count = await invoke("export_dependency_graph", { directory: config.wwise.projPath, format: null, path });
+    showMessage(`✅ 已导出 ${count} 条依赖`);
+  } catch (error) {
+    showMessage("❌ 导出失败: " + error);
+    console.error("导出依赖图失败:", error);
+  }
+}
+
+// 打开 SoundBank 内容窗口并计算所有 SoundBank 的内容
+async function showBankInclusions() {
+  if (!config.wwise.projPath) {
+    showMessage("请先设置 Wwise 工程路径");
+    return;
+  }
+  bootstrap.Modal.getOrCreateInstance(document.querySelector('#bankInclusionModal')).show();
+  await loadBankInclusions();
+}
+
+// 输入了对象时查询它将进入哪些 SoundBank，否则列出所有 SoundBank 的内容并与 SoundbanksInfo 比较
+async function loadBankInclusions() {
+  const object = document.querySelector('#bankInclusionObjectInput').value.trim();
+  const body = document.querySelector('#bankInclusionBody');
+  body.innerHTML = '<div class="text-muted">正在计算…</div>';
+  try {
+    if (object) {
+      const banks = await invoke("find_object_banks", { directory: config.wwise.projPath, object });
+      const kinds = { event: 'Event', structure: '层级结构', media: '媒体' };
+      body.innerHTML = banks.length === 0
+        ? '<div class="text-muted">该对象不会进入任何 SoundBank</div>'
+        : detailsSection('所在 SoundBank', ['SoundBank', '类型', '名称', '包含项'], banks.map(b => [
+          b.bank, kinds[b.kind] || b.kind, b.name, b.via
+        ]));
+      return;
+    }
+    const report = await invoke("get_bank_inclusions", {
+      directory: config.wwise.projPath,
+      bankDirectory: config.bank.dirPath || null
+    });
+    renderBankInclusions(report);
+  } catch (error) {
+    body.innerHTML = `<div class="text-danger">${escapeHtml(error)}</div>`;
+  }
+}
+
+function renderBankInclusions(report) {
+  const differences = report.differences.map(d => {
+    if (!d.generated) {
+      return `<div class="alert alert-warning py-1 mb-1">${escapeHtml(d.bank)}: SoundbanksInfo 中没有该 SoundBank</div>`;
+    }
+    const lists = [['缺少 Event', d.missing_events], ['多出 Event', d.extra_events], ['缺少媒体', d.missing_media], ['多出媒体', d.extra_media]];
+    return lists.filter(([, items]) => items.length > 0).map(([label, items]) =>
+      `<div class="alert alert-warning py-1 mb-1">${escapeHtml(d.bank)} 与 SoundbanksInfo 不一致，${label}: ${escapeHtml(items.join(', '))}</div>`
+    ).join('');
+  }).join('');
+
+  document.querySelector('#bankInclusionBody').innerHTML = differences + report.banks.map(bank => `
+    <h6 class="mt-3 mb-0">${escapeHtml(bank.name)} <small class="text-muted">${escapeHtml(bank.short_id)}</small></h6>
+    ${bank.unresolved.length ? `<div class="text-danger">工程中未找到包含项: ${escapeHtml(bank.unresolved.join(', '))}</div>` : ''}
+    ${detailsSection('Event', ['名称', '路径', '包含项'], bank.events.map(e => [e.name, e.path, e.via]))}
+    ${detailsSection('层级结构', ['名称', '类型', '路径', '包含项'], bank.structures.map(s => [s.name, s.object_type, s.path, s.via]))}
+    ${detailsSection('媒体', ['MediaID', '文件', '语言', '源', '包含项'], bank.media.map(m => [m.id, m.name, m.language, m.source, m.via]))}
+  `).join('');
+}
+
+// 打开媒体内存窗口并统计 Bank 目录中的重复媒体
+async function showMediaReport() {
+  if (!config.bank.dirPath) {
+    showMessage("请先设置 Bank 目录");
+    return;
+  }
+  bootstrap.Modal.getOrCreateInstance(document.querySelector('#mediaReportModal')).show();
+  await loadMediaReport();
+}
+
+async function loadMediaReport() {
+  const budgetMb = parseFloat(document.querySelector('#mediaBudgetInput').value);
+  const body = document.querySelector('#mediaReportBody');
+  body.innerHTML = '<div class="text-muted">正在统计…</div>';
+  try {
+    const report = await invoke("get_media_report", {
+      directory: config.bank.dirPath,
+      budget: budgetMb > 0 ? Math.round(budgetMb * 1024 * 1024) : null
+    });
+    renderMediaReport(report);
+  } catch (error) {
+    body.innerHTML = `<div class="text-danger">${escapeHtml(error)}</div>`;
+  }
+}
+
+function formatBytes(bytes) {
+  if (bytes < 1024) {
+    return `${bytes} B`;
+  }
+  const units = ['KB', 'MB', 'GB'];
+  let value = bytes / 1024;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit++;
+  }
+  return `${value.toFixed(1)} ${units[unit]}`;
+}
+
+function renderMediaReport(report) {
+  document.querySelector('#mediaReportBody').innerHTML = report.platforms.map(platform => {
+    const overBudget = platform.banks.filter(b => b.over_budget).map(b =>
+      `<div class="alert alert-danger py-1 mb-1">${escapeHtml(b.name)} 超出预算: ${formatBytes(b.memory_bytes)}</div>`
+    ).join('');
+    const banks = platform.banks.map(b => [
+      (b.over_budget ? '⚠ ' : '') + b.name, b.language, formatBytes(b.memory_bytes), formatBytes(b.prefetch_bytes),
+      b.media_count, b.streamed_count, b.unknown_sizes || ''
+    ]);
+    const duplicates = platform.duplicates.map(d => [
+      d.id, d.name, d.language, d.banks.join(', '), formatBytes(d.wasted_bytes)
+    ]);
+    return `
+      <h6 class="mt-3 mb-0">${escapeHtml(platform.platform)}
+        <small class="text-muted">${platform.duplicates.length} 个重复媒体，浪费 ${formatBytes(platform.wasted_bytes)}</small>
+      </h6>
+      ${overBudget}
+      ${detailsSection('重复媒体', ['MediaID', '文件', '语言', 'SoundBank', '浪费'], duplicates)}
+      ${detailsSection('SoundBank 内存', ['SoundBank', '语言', '内存', '其中预取', '媒体数', '流播放', '大小未知'], banks)}
+    `;
+  }).join('');
+}
+
+// 显示或隐藏工程浏览，第一次显示时加载
+async function toggleProjectTree() {
+  const column = document.querySelector('#projectTreeColumn');
+  const visible = column.classList.toggle('d-none') === false;
+  document.querySelector('#wwiseResultsColumn').classList.toggle('col-lg-9', visible);
+  if (visible && document.querySelector('#projectTree').children.length === 0) {
+    await loadProjectTree();
+  }
+}
+
+// 读取工程的各分类及顶层工作单元，子对象在展开时加载
+async function loadProjectTree() {
+  const tree = document.querySelector('#projectTree');
+  if (!config.wwise.projPath) {
+    tree.innerHTML = '<li class="text-muted">请先设置 Wwise 工程路径</li>';
+    return;
+  }
+  tree.innerHTML = '<li class="text-muted">正在加载…</li>';
+  try {
+    const roots = await invoke("get_roots", { directory: config.wwise.projPath });
+    tree.innerHTML = '';
+    roots.forEach(category => {
+      const item = document.createElement('li');
+      item.innerHTML = `<strong>${escapeHtml(category.name)}</strong>`;
+      const list = document.createElement('ul');
+      list.className = 'list-unstyled ps-3';
+      category.children.forEach(node => list.appendChild(createTreeItem(node)));
+      item.appendChild(list);
+      tree.appendChild(item);
+    });
+  } catch (error) {
+    tree.innerHTML = `<li class="text-danger">${escapeHtml(error)}</li>`;
+  }
+}
+
+// 树中的一个对象：点击三角展开子对象，点击名称查看详情
+function createTreeItem(node) {
+  const item = document.createElement('li');
+  item.innerHTML = `
+    <span class="treeToggle" role="button" style="display: inline-block; width: 1em;">${node.child_count > 0 ? '▸' : ''}</span>
+    <span class="treeLabel" role="button" title="${escapeHtml(node.path)}">${escapeHtml(node.name || node.object_type)}</span>
+    <small class="text-muted">${escapeHtml(node.object_type)}</small>
+  `;
+
+  const toggle = item.querySelector('.treeToggle');
+  let children = null;
+  toggle.addEventListener('click', async () => {
+    if (node.child_count === 0) {
+      return;
+    }
+    if (children) {
+      const collapsed = children.classList.toggle('d-none');
+      toggle.textContent = collapsed ? '▸' : '▾';
+      return;
+    }
+    children = document.createElement('ul');
+    children.className = 'list-unstyled ps-3';
+    item.appendChild(children);
+    toggle.textContent = '▾';
+    try {
+      const nodes = await invoke("get_children", { directory: config.wwise.projPath, guid: node.guid });
+      nodes.forEach(child => children.appendChild(createTreeItem(child)));
+    } catch (error) {
+      children.innerHTML = `<li class="text-danger">${escapeHtml(error)}</li>`;
+    }
+  });
+  item.querySelector('.treeLabel').addEventListener('click', () => showObjectDetails(node));
+  return item;
+}
+
+// 开始或停止 WAAPI 实时同步
+async function updateLiveSync() {
+  try {
+    if (config.waapi.liveSync && config.waapi.host && config.waapi.port) {
+      await invoke("start_live_sync", { host: config.waapi.host, port: Number(config.waapi.port) });
+      displayLiveStatus({ connected: false, message: '正在连接…' });
+    } else {
+      await invoke("stop_live_sync");
+      displayLiveStatus(null);
+    }
+  } catch (error) {
+    displayLiveStatus({ connected: false, message: String(error) });
+  }
+}
+
+// 显示实时同步的连接状态
+function displayLiveStatus(status) {
+  const element = document.querySelector('#waapiLiveStatus');
+  if (!status) {
+    element.textContent = '';
+    return;
+  }
+  let text = (status.connected ? '🟢 ' : '🔴 ') + status.message;
+  if (status.project_dir) {
+    text += `（${status.project_dir}）`;
+  }
+  element.textContent = text;
+}
+
+// 将 Wwise 中的修改应用到已显示的结果：重命名和删除直接更新，
+// 创建的对象需要重新执行 WAAPI 搜索才知道是否匹配，工程保存后重新执行工程搜索
+function applyLiveChange(change) {
+  for (const tab of ['wwise', 'waapi']) {
+    const data = paginationData[tab];
+    if (change.kind === 'renamed') {
+      data.totalResults.filter(r => r.guid === change.guid).forEach(r => { r.name = change.new_name; });
+    } else if (change.kind === 'deleted') {
+      data.totalResults = data.totalResults.filter(r => r.guid !== change.guid);
+      const totalPages = Math.max(1, Math.ceil(data.totalResults.length / data.pageSize));
+      data.currentPage = Math.min(data.currentPage, totalPages);
+    }
+    if (data.lastSearch) {
+      displayResultsWithPagination(tab);
+    }
+  }
+
+  if (change.kind === 'created' && paginationData.waapi.lastSearch) {
+    performSearch('waapi', paginationData.waapi.lastSearch);
+  }
+  if (change.kind === 'project_saved' && paginationData.wwise.lastSearch) {
+    performSearch('wwise', paginationData.wwise.lastSearch);
+  }
+}
+
+// 显示输入的 ID 被解释成了什么（十六进制、有符号 int32、无连字符 GUID 等），以及工程目录的扫描统计
+function displayInterpretations(interpretations, scan) {
+  const element = document.querySelector('#sharedInterpretation');
+  if (!interpretations || interpretations.length === 0) {
+    element.textContent = '';
+    return;
+  }
+  let text = '输入解析为: ' + interpretations.map(i => i.description).join('；');
+  if (scan) {
+    text += ` · 已扫描 ${scan.files_visited} 个文件，其中 ${scan.files_matched} 个 .wwu 文件`;
+  }
+  element.textContent = text;
+}
+
+// 批量解析结果
+let resolveResults = [];
+
+// 提取并解析文本中的所有 ID
+async function resolveIdsInText() {
+  const text = document.querySelector('#resolveInput').value;
+  if (!text.trim()) {
+    showMessage("请粘贴要解析的文本");
+    return;
+  }
+  if (!config.wwise.projPath && !config.bank.dirPath) {
+    showMessage("请先设置 Wwise 工程目录或 Bank 目录");
+    return;
+  }
+
+  try {
+    resolveResults = await invoke("resolve_ids_in_text", {
+      text,
+      projectDir: config.wwise.projPath || null,
+      bankDir: config.bank.dirPath || null
+    });
+    displayResolveResults();
+  } catch (error) {
+    showMessage("解析失败: " + error);
+    console.error("解析错误:", error);
+  }
+}
+
+// 选择日志文件，注释后另存为新文件
+async function annotateLogFile() {
+  if (!config.wwise.projPath && !config.bank.dirPath) {
+    showMessage("请先设置 Wwise 工程目录或 Bank 目录");
+    return;
+  }
+
+  const format = document.querySelector('#logFormatSelect').value;
+  const patterns = document.querySelector('#logPatternsInput').value
+    .split('\n')
+    .map(p => p.trim())
+    .filter(p => p);
+
+  try {
+    const inputPath = await window.__TAURI__.dialog.open({
+      directory: false,
+      multiple: false,
+      title: "选择要注释的日志文件"
+    });
+    if (!inputPath) {
+      return;
+    }
+
+    const outputPath = await window.__TAURI__.dialog.save({
+      title: "保存注释后的日志",
+      defaultPath: inputPath.replace(/(\.[^.\\/]+)?$/, '.annotated$1')
+    });
+    if (!outputPath) {
+      return;
+    }
+
+    const stats = await invoke("annotate_log_file", {
+      inputPath,
+      outputPath,
+      format,
+      patterns,
+      projectDir: config.wwise.projPath || null,
+      bankDir: config.bank.dirPath || null
+    });
+    showMessage(`✅ 已处理 ${stats.lines} 行，注释 ${stats.annotated_lines} 行，共 ${stats.annotations} 处`);
+  } catch (error) {
+    showMessage("❌ 注释失败: " + error);
+    console.error("注释日志失败:", error);
+  }
+}
+
+// 显示批量解析结果
+function displayResolveResults() {
+  const tableBody = document.querySelector('#resolveTableBody');
+  const resolvedOnly = document.querySelector('#resolvedOnlyCheckbox').checked;
+  const kindNames = {
+    Guid: 'GUID',
+    Decimal: '十进制',
+    Hex: '十六进制',
+    NegativeInt32: '负数 int32'
+  };
+  const sourceNames = { wwise: '工程', bank: 'Bank' };
+
+  const matchedCount = resolveResults.filter(r => r.matches.length > 0).length;
+  document.querySelector('#resolveTokenCount').textContent = resolveResults.length;
+  document.querySelector('#resolveMatchedCount').textContent = matchedCount;
+
+  const rows = resolvedOnly ? resolveResults.filter(r => r.matches.length > 0) : resolveResults;
+  tableBody.innerHTML = '';
+
+  if (rows.length === 0) {
+    tableBody.innerHTML = `
+      <tr>
+        <td colspan="5" class="text-center text-muted">
+          未找到可解析的 ID
+        </td>
+      </tr>
+    `;
+    return;
+  }
+
+  rows.forEach(item => {
+    const matches = item.matches.length === 0
+      ? '<span class="text-muted">未解析</span>'
+      : item.matches.map(m =>
+          `${m.name || '未命名'} <small class="text-muted">(${m.object_type}, ${m.id_type}, ${sourceNames[m.source] || m.source})</small>`
+        ).join('<br>');
+    const row = document.createElement('tr');
+    row.innerHTML = `
+      <td><code>${item.token}</code></td>
+      <td>${kindNames[item.kind] || item.kind}</td>
+      <td><code>${item.value}</code></td>
+      <td>${item.occurrences}</td>
+      <td>${matches}</td>
+    `;
+    tableBody.appendChild(row);
+  });
+}
+
+// 导出当前标签页的全部搜索结果
+async function exportResults(tab) {
+  const results = paginationData[tab].totalResults;
+  if (results.length === 0) {
+    showMessage("没有可导出的结果");
+    return;
+  }
+
+  try {
+    const path = await window.__TAURI__.dialog.save({
+      title: "导出搜索结果",
+      defaultPath: `wid-${tab}-results.csv`,
+      filters: [
+        { name: "CSV", extensions: ["csv"] },
+        { name: "Excel", extensions: ["xlsx"] },
+        { name: "Markdown", extensions: ["md"] },
+        { name: "JSON", extensions: ["json"] }
+      ]
+    });
+    if (!path) {
+      return;
+    }
+
+    // 格式根据扩展名推断
+    const count = await invoke("export_results", { results, format: null, path });
+    showMessage(`✅ 已导出 ${count} 条结果`);
+  } catch (error) {
+    showMessage("❌ 导出失败: " + error);
+    console.error("导出失败:", error);
+  }
+}
+
 // 显示搜索结果（带分页）
 function displayResultsWithPagination(tab) {
   const resultsTableBody = getResultsTableBody(tab);
@@ -360,7 +1345,7 @@ function displayResultsWithPagination(tab) {
   if (totalResults.length === 0) {
     resultsTableBody.innerHTML = `
       <tr>
-        <td colspan="5" class="text-center text-muted">
+        <td colspan="6" class="text-center text-muted">
           未找到匹配的结果
         </td>
       </tr>
@@ -381,12 +1366,23 @@ function displayResultsWithPagination(tab) {
   pageResults.forEach(item => {
     const row = document.createElement('tr');
     row.innerHTML = `
-      <td>${item.name || ''}</td>
+      <td>${item.name || ''}${bankBadges(item.banks)}</td>
       <td>${item.object_type || ''}</td>
       <td><code>${item.guid || ''}</code></td>
       <td>${item.short_id || ''}</td>
       <td>${item.media_id || ''}</td>
+      <td class="text-nowrap">
+        <button class="btn btn-sm btn-outline-secondary py-0 showInWwiseBtn" type="button" data-actions="find,inspect" title="在 Project Explorer 中定位并在 Property Editor 中查看">显示</button>
+        <button class="btn btn-sm btn-outline-secondary py-0 showInWwiseBtn" type="button" data-actions="schematic" title="在 Schematic View 中选中">原理图</button>
+        ${item.guid ? '<button class="btn btn-sm btn-outline-secondary py-0 detailsBtn" type="button" title="查看 .wwu 中定义的属性、引用和源">详情</button>' : ''}
+        ${item.object_type === 'Event' ? '<button class="btn btn-sm btn-outline-secondary py-0 eventReportBtn" type="button" title="列出 Event 的动作、目标和所在的 SoundBank">动作</button>' : ''}
+      </td>
     `;
+    row.querySelectorAll('.showInWwiseBtn').forEach(button => {
+      button.addEventListener('click', () => showInWwise(item, button.dataset.actions.split(',')));
+    });
+    row.querySelector('.detailsBtn')?.addEventListener('click', () => showObjectDetails(item));
+    row.querySelector('.eventReportBtn')?.addEventListener('click', () => showEventReport(item));
     resultsTableBody.appendChild(row);
   });
 
@@ -398,6 +1394,16 @@ function displayResultsWithPagination(tab) {
   renderPagination(tab, currentPage, totalPages);
 }
 
+// Bank 结果所在的 SoundBank，悬停显示 ID、路径和语言
+function bankBadges(banks) {
+  if (!banks || banks.length === 0) {
+    return '';
+  }
+  return `<div>${banks.map(bank => `
+    <span class="badge text-bg-light border" title="${escapeHtml(`ID: ${bank.id}\n${bank.path}\n${bank.language}`)}">📦 ${escapeHtml(bank.name)}</span>
+  `).join('')}</div>`;
+}
+
 // 渲染分页控件
 function renderPagination(tab, currentPage, totalPages) {
   const pagination = document.querySelector(`.pagination[data-tab="${tab}"]`);
@@ -499,9 +1505,10 @@ function clearAll(tab) {
   const pagination = document.querySelector(`.pagination[data-tab="${tab}"]`);
   
   idInput.value = '';
+  displayInterpretations([]);
   resultsTableBody.innerHTML = `
     <tr>
-      <td colspan="5" class="text-center text-muted">
+      <td colspan="6" class="text-center text-muted">
         请输入ID并点击搜索
       </td>
     </tr>
@@ -513,6 +1520,7 @@ function clearAll(tab) {
   // 清空分页数据
   paginationData[tab].totalResults = [];
   paginationData[tab].currentPage = 1;
+  paginationData[tab].lastSearch = null;
   
   idInput.focus();
 }
@@ -558,15 +1566,26 @@ function toggleSearchArea() {
 }
 
 // 初始化
-window.addEventListener("DOMContentLoaded", () => {
-  // 初始化配置管理
-  configManager.updateConfigSelect();
+window.addEventListener("DOMContentLoaded", async () => {
+  // 初始化配置管理（首次运行时迁移旧版本 localStorage 中的配置）
+  await configManager.migrateLocalStorage();
+  await configManager.refresh();
   
   // 初始化配置显示
   document.querySelector('#wwiseProjPath').value = config.wwise.projPath;
   document.querySelector('#waapiHost').value = config.waapi.host;
   document.querySelector('#waapiPort').value = config.waapi.port;
+  document.querySelector('#waapiLiveSync').checked = config.waapi.liveSync;
   document.querySelector('#bankDirPath').value = config.bank.dirPath;
+  document.querySelector('#idsHeaderPath').value = config.bank.idsHeaderPath;
+
+  // 显示已配置工程和 Bank 目录的概要
+  refreshSummaries();
+
+  // WAAPI 实时同步
+  await window.__TAURI__.event.listen('waapi-live-change', (event) => applyLiveChange(event.payload));
+  await window.__TAURI__.event.listen('waapi-live-status', (event) => displayLiveStatus(event.payload));
+  updateLiveSync();
 
   // 初始检查配置
   checkConfigAndShowAlert();
@@ -584,7 +1603,7 @@ window.addEventListener("DOMContentLoaded", () => {
   });
 
   // 配置管理事件监听器
-  document.querySelector('#saveConfigBtn').addEventListener('click', () => {
+  document.querySelector('#saveConfigBtn').addEventListener('click', async () => {
     const configName = document.querySelector('#configName').value.trim();
     if (!configName) {
       showMessage('❌ 请输入配置名称');
@@ -600,7 +1619,12 @@ window.addEventListener("DOMContentLoaded", () => {
     const currentConfigData = configManager.getCurrentConfigData();
     
     // 保存配置
-    configManager.saveConfig(configName, currentConfigData);
+    try {
+      await configManager.saveConfig(configName, currentConfigData);
+    } catch (error) {
+      showMessage('❌ 保存配置失败: ' + error);
+      return;
+    }
     
     // 清空输入框
     document.querySelector('#configName').value = '';
@@ -612,6 +1636,9 @@ window.addEventListener("DOMContentLoaded", () => {
     showMessage(`✅ 配置 "${configName}" 保存成功`);
   });
 
+  document.querySelector('#importConfigBtn').addEventListener('click', () => configManager.importConfigs());
+  document.querySelector('#exportConfigBtn').addEventListener('click', () => configManager.exportConfigs());
+
   document.querySelector('#loadConfigBtn').addEventListener('click', () => {
     const selectedConfig = document.querySelector('#configSelect').value;
     if (selectedConfig === 'default') {
@@ -620,9 +1647,11 @@ window.addEventListener("DOMContentLoaded", () => {
       config.waapi.host = '127.0.0.1';
       config.waapi.port = '8080';
       config.bank.dirPath = '';
+      config.bank.idsHeaderPath = '';
       
       saveConfig();
       configManager.updateConfigUI();
+      refreshSummaries();
       localStorage.setItem('current_config', 'default');
       
       showMessage('✅ 默认配置加载成功');
@@ -651,8 +1680,19 @@ window.addEventListener("DOMContentLoaded", () => {
   });
 
   // 配置输入框监听（自动保存）
-  document.querySelector('#wwiseProjPath').addEventListener('change', (e) => {
+  document.querySelector('#wwiseProjPath').addEventListener('change', async (e) => {
     config.wwise.projPath = e.target.value;
+    // 手动输入的工程目录同样自动定位 Bank 目录并加载团队配置，目录无效时留给搜索时报错
+    try {
+      const projectInfo = await invoke("validate_wwise_directory", { path: config.wwise.projPath });
+      applyProjectInfo(projectInfo);
+      if (projectInfo.team_config) {
+        showMessage("✅ 已加载团队配置 " + projectInfo.team_config.path);
+      }
+    } catch (error) {
+      displayProjectSummary(null);
+      console.warn("工程目录验证失败:", error);
+    }
     saveConfig();
     checkConfigAndShowAlert();
   });
@@ -660,22 +1700,54 @@ window.addEventListener("DOMContentLoaded", () => {
     config.waapi.host = e.target.value;
     saveConfig();
     checkConfigAndShowAlert();
+    updateLiveSync();
   });
   document.querySelector('#waapiPort').addEventListener('change', (e) => {
     config.waapi.port = e.target.value;
     saveConfig();
     checkConfigAndShowAlert();
+    updateLiveSync();
+  });
+  document.querySelector('#waapiLiveSync').addEventListener('change', (e) => {
+    config.waapi.liveSync = e.target.checked;
+    saveConfig();
+    updateLiveSync();
   });
   document.querySelector('#bankDirPath').addEventListener('change', (e) => {
     config.bank.dirPath = e.target.value;
+    refreshBankSummary();
     saveConfig();
     checkConfigAndShowAlert();
   });
+  document.querySelector('#idsHeaderPath').addEventListener('change', (e) => {
+    config.bank.idsHeaderPath = e.target.value;
+    saveConfig();
+  });
 
   // 浏览按钮
   document.querySelector('#wwiseBrowseBtn').addEventListener('click', browseProjPath);
   document.querySelector('#bankBrowseBtn').addEventListener('click', browseBankPath);
   document.querySelector('#waapiTestBtn').addEventListener('click', testWaapiConnectionHandler);
+  document.querySelector('#idsHeaderBrowseBtn').addEventListener('click', browseIdsHeaderPath);
+  document.querySelector('#generateConstantsBtn').addEventListener('click', generateConstants);
+  document.querySelector('#resolveBtn').addEventListener('click', resolveIdsInText);
+  document.querySelector('#resolvedOnlyCheckbox').addEventListener('change', displayResolveResults);
+  document.querySelector('#annotateLogBtn').addEventListener('click', annotateLogFile);
+  document.querySelector('#projectTreeToggleBtn').addEventListener('click', toggleProjectTree);
+  document.querySelector('#projectTreeRefreshBtn').addEventListener('click', loadProjectTree);
+  document.querySelector('#busRoutingBtn').addEventListener('click', showBusRouting);
+  document.querySelector('#routingQueryBtn').addEventListener('click', loadBusRouting);
+  document.querySelector('#routingExportBtn').addEventListener('click', exportBusRouting);
+  document.querySelector('#dependencyBtn').addEventListener('click', showDependencies);
+  document.querySelector('#dependencyQueryBtn').addEventListener('click', findDependencyPaths);
+  document.querySelector('#dependencyExportBtn').addEventListener('click', exportDependencyGraph);
+  document.querySelector('#bankInclusionBtn').addEventListener('click', showBankInclusions);
+  document.querySelector('#bankInclusionQueryBtn').addEventListener('click', loadBankInclusions);
+  document.querySelector('#mediaReportBtn').addEventListener('click', showMediaReport);
+  document.querySelector('#mediaReportQueryBtn').addEventListener('click', loadMediaReport);
+  document.querySelectorAll('.exportBtn').forEach(button => {
+    button.addEventListener('click', () => exportResults(button.dataset.tab));
+  });
 
   // 绑定共享搜索按钮事件
   document.querySelector('#sharedSearchBtn').addEventListener('click', () => {